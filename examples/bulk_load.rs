@@ -0,0 +1,67 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Demonstrates bulk loading with execute_many, which binds many parameter rows to each request,
+// and reports the chunks that failed.
+//
+//     cargo run --example bulk_load . '{"host":"databasename","user":"guest","password":"please"}'
+
+use std::env;
+
+use teradatarustapi::{Connection, TeradataError};
+
+const ROW_COUNT: u32 = 100000;
+const CHUNK_SIZE: usize = 5000;
+
+fn bulk_load(conn: &Connection) -> Result<(), TeradataError> {
+
+	conn.execute("create volatile table vtab (c1 integer, c2 varchar(20)) on commit preserve rows", "null")?;
+
+	// Rows are generated lazily; execute_many takes at most one chunk from the iterator at a time
+	let rows = (1..=ROW_COUNT).map(|n| (n, format!("row {}", n)));
+	let summary = conn.execute_many_with_chunk_size("insert into vtab values (?, ?)", rows, CHUNK_SIZE)?;
+
+	println!("Rows submitted: {}", summary.rows_submitted);
+	println!("Chunks executed: {}", summary.chunks_executed);
+	println!("Activity count: {}", summary.activity_count);
+	for failure in &summary.failures {
+		println!("Chunk {} (rows {} to {}) failed: {}", failure.chunk_index, failure.first_row, failure.first_row + failure.row_count - 1, failure.error);
+	}
+	for failure in &summary.row_failures {
+		println!("Row {} was not submitted: {}", failure.row_index, failure.error);
+	}
+
+	let row = conn.fetch_one("select count(*) from vtab", "null")?;
+	println!("Rows in table: {}", row.get::<i64>(0)?);
+	if !summary.is_success() {
+		println!("Bulk load completed with {} failed chunks and {} rows not submitted", summary.failures.len(), summary.row_failures.len());
+	}
+
+	conn.execute("drop table vtab", "null")?;
+	Ok(())
+
+} // end bulk_load
+
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 3 {
+		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
+		return;
+	}
+
+	if let Err(err) = teradatarustapi::load_driver(&args[1]) {
+		println!("Error from load_driver: {}", err.message());
+		return;
+	}
+
+	let conn = match Connection::open(&args[2]) {
+		Ok(conn) => conn,
+		Err(err) => {
+			println!("Error from Connection::open: {}", err.message());
+			return;
+		}
+	};
+
+	if let Err(err) = bulk_load(&conn) {
+		println!("Error: {}", err.message());
+	}
+} // end main
@@ -0,0 +1,72 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Demonstrates cancelling long-running requests: a query timeout that surfaces as
+// TeradataError::Timeout, and a CancelTimer that cancels a request after a delay.
+//
+//     cargo run --example cancel . '{"host":"databasename","user":"guest","password":"please"}'
+
+use std::env;
+use std::time::{Duration, Instant};
+
+use teradatarustapi::{CancelTimer, Connection, QueryOptions, TeradataError};
+
+// A cross join that runs long enough to be cancelled
+const LONG_QUERY: &str = "select count(*) from sys_calendar.calendar a cross join sys_calendar.calendar b cross join sys_calendar.calendar c";
+
+fn query_timeout(conn: &Connection) {
+	let options = QueryOptions::new().timeout(Duration::from_secs(2));
+	let start = Instant::now();
+	match conn.query_result_sets_with_options(LONG_QUERY, "null", &options) {
+		Ok(_) => println!("Query completed in {:?} before the timeout", start.elapsed()),
+		Err(TeradataError::Timeout(message)) => println!("Query timed out after {:?}: {}", start.elapsed(), message),
+		Err(err) => println!("Query failed: {}", err.message()),
+	}
+}
+
+fn cancel_timer(conn: &Connection) {
+	let timer = CancelTimer::start(conn, Duration::from_secs(2));
+	let start = Instant::now();
+	let result = conn.fetch_all(LONG_QUERY, "null");
+	let fired = timer.disarm();
+	match result {
+		Ok(_) => println!("Query completed in {:?}", start.elapsed()),
+		Err(err) if fired => println!("Query cancelled after {:?}: {}", start.elapsed(), err.message()),
+		Err(err) => println!("Query failed: {}", err.message()),
+	}
+}
+
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 3 {
+		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
+		return;
+	}
+
+	if let Err(err) = teradatarustapi::load_driver(&args[1]) {
+		println!("Error from load_driver: {}", err.message());
+		return;
+	}
+
+	match teradatarustapi::driver_capabilities() {
+		Ok(capabilities) if capabilities.supports_cancel => (),
+		Ok(_) => {
+			println!("The loaded driver library does not support cancelling requests");
+			return;
+		}
+		Err(err) => {
+			println!("Error from driver_capabilities: {}", err.message());
+			return;
+		}
+	}
+
+	let conn = match Connection::open(&args[2]) {
+		Ok(conn) => conn,
+		Err(err) => {
+			println!("Error from Connection::open: {}", err.message());
+			return;
+		}
+	};
+
+	query_timeout(&conn);
+	cancel_timer(&conn);
+} // end main
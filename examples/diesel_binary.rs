@@ -0,0 +1,66 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Round-trips VARBYTE values through the Diesel backend: inserts rows with Binary values, selects
+// them by a Binary value, and checks that the bytes read back are the bytes written.
+//
+//     cargo run --example diesel_binary --features diesel . '{"host":"databasename","user":"guest","password":"please"}'
+
+use std::env;
+
+use diesel::prelude::*;
+use teradatarustapi::diesel::TeradataConnection;
+
+diesel::table! {
+	vtab (id) {
+		id -> Integer,
+		payload -> Binary,
+	}
+}
+
+fn round_trip(conn: &mut TeradataConnection) -> QueryResult<()> {
+
+	diesel::sql_query("create volatile table vtab (id integer, payload varbyte(100)) on commit preserve rows").execute(conn)?;
+
+	// Binary bind values are converted from base64 to bytes, so no to_bytes is needed
+	let payloads: [&[u8]; 3] = [b"\x00\x01\x02", b"\xca\xfe", b""];
+	for (id, payload) in (1..).zip(payloads) {
+		diesel::insert_into(vtab::table).values((vtab::id.eq(id), vtab::payload.eq(payload))).execute(conn)?;
+	}
+
+	let rows: Vec<(i32, Vec<u8>)> = vtab::table.order(vtab::id).load(conn)?;
+	for ((id, payload), expected) in rows.iter().zip(payloads) {
+		println!("Row {}: {:02x?} {}", id, payload, if payload.as_slice() == expected { "matches" } else { "DOES NOT MATCH" });
+	}
+
+	let found: Vec<i32> = vtab::table.filter(vtab::payload.eq(&b"\xca\xfe"[..])).select(vtab::id).load(conn)?;
+	println!("Rows with payload cafe: {:?}", found);
+
+	diesel::sql_query("drop table vtab").execute(conn)?;
+	Ok(())
+
+} // end round_trip
+
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 3 {
+		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
+		return;
+	}
+
+	if let Err(err) = teradatarustapi::load_driver(&args[1]) {
+		println!("Error from load_driver: {}", err.message());
+		return;
+	}
+
+	let mut conn = match TeradataConnection::establish(&args[2]) {
+		Ok(conn) => conn,
+		Err(err) => {
+			println!("Error from TeradataConnection::establish: {}", err);
+			return;
+		}
+	};
+
+	if let Err(err) = round_trip(&mut conn) {
+		println!("Error: {}", err);
+	}
+} // end main
@@ -0,0 +1,81 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Demonstrates multi-statement requests: walks the results of a request one at a time with
+// Rows, then runs labeled statements with MultiStatementRequest and looks results up by label.
+//
+//     cargo run --example multi_statement . '{"host":"databasename","user":"guest","password":"please"}'
+
+use std::env;
+
+use teradatarustapi::{Connection, MultiStatementRequest, TeradataError};
+
+fn walk_results(conn: &Connection) -> Result<(), TeradataError> {
+
+	let mut rows = conn.query("select 1, 'one' ; select current_date, current_time ; select count(*) from dbc.dbcinfo", "null")?;
+	loop {
+		let metadata = rows.metadata()?.clone();
+		println!();
+		println!("Result {}: {} ({})", rows.result_index(), metadata.activity_name, metadata.activity_type);
+		let names: Vec<String> = rows.columns()?.iter().map(|column| column.name.clone()).collect();
+		while let Some(values) = rows.fetch_values()? {
+			for (name, value) in names.iter().zip(&values) {
+				println!("  {} = {:?}", name, value);
+			}
+		}
+		if !rows.next_result()? {
+			break;
+		}
+	}
+	Ok(())
+
+} // end walk_results
+
+fn labeled_statements(conn: &Connection) -> Result<(), TeradataError> {
+
+	let results = MultiStatementRequest::new()
+		.statement("create", "create volatile table vtab (c1 integer) on commit preserve rows")
+		.statement("insert", "insert into vtab values (1)")
+		.statement_with_params("insert_param", "insert into vtab values (?)", (2,))?
+		.statement("select", "select c1 from vtab order by 1")
+		.execute(conn)?;
+
+	println!();
+	for (label, result_set) in results.iter() {
+		println!("{}: {} activity count {}", label, result_set.activity_name, result_set.activity_count);
+	}
+
+	if let Some(result_set) = results.get("select") {
+		for row in result_set.iter_rows() {
+			println!("  c1 = {}", row?.get::<i32>(0)?);
+		}
+	}
+
+	conn.execute("drop table vtab", "null")?;
+	Ok(())
+
+} // end labeled_statements
+
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 3 {
+		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
+		return;
+	}
+
+	if let Err(err) = teradatarustapi::load_driver(&args[1]) {
+		println!("Error from load_driver: {}", err.message());
+		return;
+	}
+
+	let conn = match Connection::open(&args[2]) {
+		Ok(conn) => conn,
+		Err(err) => {
+			println!("Error from Connection::open: {}", err.message());
+			return;
+		}
+	};
+
+	if let Err(err) = walk_results(&conn).and_then(|_| labeled_statements(&conn)) {
+		println!("Error: {}", err.message());
+	}
+} // end main
@@ -0,0 +1,83 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Round-trips VARBYTE values through the sqlx driver: inserts rows with Vec<u8> bind values,
+// selects them by a byte value, and checks that the bytes read back are the bytes written.
+//
+//     cargo run --example sqlx_binary --features sqlx . '{"host":"databasename","user":"guest","password":"please"}'
+
+use std::env;
+
+use sqlx_core::connection::ConnectOptions;
+use sqlx_core::error::Error;
+use sqlx_core::query::query;
+use sqlx_core::row::Row;
+use teradatarustapi::sqlx::{Teradata, TeradataConnectOptions, TeradataConnection};
+use teradatarustapi::ConnectParams;
+
+async fn round_trip(conn: &mut TeradataConnection) -> Result<(), Error> {
+
+	query::<Teradata>("create volatile table vtab (id integer, payload varbyte(100)) on commit preserve rows").execute(&mut *conn).await?;
+
+	// Byte values are bound as base64 strings and converted to bytes, so no to_bytes is needed
+	let payloads: [&[u8]; 3] = [b"\x00\x01\x02", b"\xca\xfe", b""];
+	for (id, payload) in (1..).zip(payloads) {
+		query::<Teradata>("insert into vtab (id, payload) values (?, ?)").bind(id).bind(payload.to_vec()).execute(&mut *conn).await?;
+	}
+
+	let rows = query::<Teradata>("select id, payload from vtab order by id").fetch_all(&mut *conn).await?;
+	for (row, expected) in rows.iter().zip(payloads) {
+		let id: i32 = row.try_get("id")?;
+		let payload: Vec<u8> = row.try_get("payload")?;
+		println!("Row {}: {:02x?} {}", id, payload, if payload.as_slice() == expected { "matches" } else { "DOES NOT MATCH" });
+	}
+
+	let found = query::<Teradata>("select id from vtab where payload = ?").bind(b"\xca\xfe".to_vec()).fetch_all(&mut *conn).await?;
+	let ids = found.iter().map(|row| row.try_get::<i32, _>(0)).collect::<Result<Vec<_>, _>>()?;
+	println!("Rows with payload cafe: {:?}", ids);
+
+	query::<Teradata>("drop table vtab").execute(&mut *conn).await?;
+	Ok(())
+
+} // end round_trip
+
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 3 {
+		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
+		return;
+	}
+
+	if let Err(err) = teradatarustapi::load_driver(&args[1]) {
+		println!("Error from load_driver: {}", err.message());
+		return;
+	}
+
+	let params = match ConnectParams::from_json(&args[2]) {
+		Ok(params) => params,
+		Err(err) => {
+			println!("Error from ConnectParams::from_json: {}", err);
+			return;
+		}
+	};
+
+	let runtime = match tokio::runtime::Builder::new_current_thread().build() {
+		Ok(runtime) => runtime,
+		Err(err) => {
+			println!("Error from tokio::runtime::Builder::build: {}", err);
+			return;
+		}
+	};
+
+	runtime.block_on(async {
+		let mut conn = match TeradataConnectOptions::new(params).connect().await {
+			Ok(conn) => conn,
+			Err(err) => {
+				println!("Error from TeradataConnectOptions::connect: {}", err);
+				return;
+			}
+		};
+		if let Err(err) = round_trip(&mut conn).await {
+			println!("Error: {}", err);
+		}
+	});
+} // end main
@@ -0,0 +1,72 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Demonstrates transactions with the Transaction guard: rolls back one insert, commits another,
+// and shows that a transaction dropped without commit is rolled back.
+//
+//     cargo run --example transactions . '{"host":"databasename","user":"guest","password":"please"}'
+
+use std::env;
+
+use teradatarustapi::{Connection, TeradataError};
+
+fn row_count(conn: &Connection) -> Result<i64, TeradataError> {
+	let row = conn.fetch_one("select count(*) from vtab", "null")?;
+	row.get::<i64>(0)
+}
+
+fn insert_then_fail(conn: &Connection) -> Result<(), TeradataError> {
+	let tx = conn.begin()?;
+	tx.execute("insert into vtab values (?, ?)", &[(3, "dropped")])?;
+	tx.execute("insert into no_such_table values (1)", "null")?; // fails, so tx is dropped
+	tx.commit()
+}
+
+fn transactions(conn: &Connection) -> Result<(), TeradataError> {
+
+	conn.execute("create volatile table vtab (c1 integer, c2 varchar(10)) on commit preserve rows", "null")?;
+
+	let tx = conn.begin()?;
+	tx.execute("insert into vtab values (?, ?)", &[(1, "rolled")])?;
+	println!("Rows before rollback: {}", row_count(&tx)?);
+	tx.rollback()?;
+	println!("Rows after rollback: {}", row_count(conn)?);
+
+	let tx = conn.begin()?;
+	tx.execute("insert into vtab values (?, ?)", &[(2, "committed")])?;
+	tx.commit()?;
+	println!("Rows after commit: {}", row_count(conn)?);
+
+	if let Err(err) = insert_then_fail(conn) {
+		println!("Transaction failed: {}", err.message());
+	}
+	println!("Rows after failed transaction: {}", row_count(conn)?);
+
+	conn.execute("drop table vtab", "null")?;
+	Ok(())
+
+} // end transactions
+
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 3 {
+		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
+		return;
+	}
+
+	if let Err(err) = teradatarustapi::load_driver(&args[1]) {
+		println!("Error from load_driver: {}", err.message());
+		return;
+	}
+
+	let conn = match Connection::open(&args[2]) {
+		Ok(conn) => conn,
+		Err(err) => {
+			println!("Error from Connection::open: {}", err.message());
+			return;
+		}
+	};
+
+	if let Err(err) = transactions(&conn) {
+		println!("Error: {}", err.message());
+	}
+} // end main
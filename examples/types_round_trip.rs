@@ -1,130 +1,60 @@
 // Copyright 2025 by Teradata Corporation. All Rights Reserved.
 
+// Round-trips every supported Teradata data type: creates a volatile table with a column of each
+// type, inserts a row of bind values, and prints the decoded Value of each column along with
+// its type.
+//
+//     cargo run --example types_round_trip . '{"host":"databasename","user":"guest","password":"please"}'
+
 use std::env;
 
-fn execute_request(u_log: u64, conn_handle: u64, request_text: &str, bind_values: &str) {
+use teradatarustapi::{Bytes, Connection, Param, TeradataError, Value};
 
-	println!();
-	println!("request_text: {}", request_text);
-	println!("bind_values:  {}", bind_values);
+fn round_trip(conn: &Connection) -> Result<(), TeradataError> {
 
-	let rows_handle = match teradatarustapi::rustgo_create_rows_wrapper(u_log, conn_handle, request_text, bind_values) {
-		Ok(handle) => handle,
-		Err(err) => {
-			println!("Error from rustgo_create_rows_wrapper: {}", err);
-			return;
-		}
-	};
+	conn.execute(CREATE_TABLE, "null")?; // null means no bind values
 
-	for result_num in 1.. {
-		match teradatarustapi::rustgo_result_metadata_wrapper(u_log, rows_handle) {
-			Ok((activity_count, activity_type, activity_name, column_metadata)) => {
-				println!("Result {} activity_count:  {}", result_num, activity_count);
-				println!("Result {} activity_type:   {}", result_num, activity_type);
-				println!("Result {} activity_name:   {}", result_num, activity_name);
-				println!("Result {} column_metadata: {}", result_num, column_metadata);
-			}
-			Err(err) => {
-				println!("Error from rustgo_result_metadata_wrapper: {}", err);
-				return;
-			}
-		}
+	// Bind values are a JSON array of rows. BYTE, VARBYTE, and BLOB values are bound as base64
+	// encoded strings, and converted with to_bytes in the request text.
+	conn.execute(INSERT_ROW, INSERT_BIND_VALUES)?;
 
-		for row_num in 1.. {
-			match teradatarustapi::rustgo_fetch_row_wrapper(u_log, rows_handle) {
-				Ok(Some(row)) => {
-					println!("Result {} row {}: {}", result_num, row_num, row);
-				}
-				Ok(None) => {
-					// No more rows to fetch
-					break;
-				}
-				Err(err) => {
-					println!("Error from rustgo_fetch_row_wrapper: {}", err);
-					break;
-				}
-			}
-		} // end for row_num
+	// Param converts Rust values to bind values, such as NULL for None
+	conn.execute("insert into vtab (c1, c3, c10, c13) values (?, ?, ?, ?)", &[[
+		Param::from(-128i8),
+		Param::from(Some(-2147483648i32)),
+		Param::from(None::<String>),
+		Param::from(teradatarustapi::Date { year: 1999, month: 12, day: 31 }),
+	]])?;
 
-		// Advance to next result
-		match teradatarustapi::go_next_result_wrapper(u_log, rows_handle) {
-			Ok(true) => { // another result available
-				continue;
-			}
-			Ok(false) => { // no more results
-				break;
-			}
-			Err(err) => {
-				println!("Error from go_next_result_wrapper: {}", err);
-				break;
-			}
-		}
-	} // end for result_num
+	// Requests are prepared to learn which parameters are binary, and their base64 bind values
+	// are converted to bytes, so byte values wrapped in Bytes are bound to plain parameter markers
+	conn.execute("insert into vtab (c1, c12, c36) values (?, ?, ?)", &[(1, Bytes(b"xyz".to_vec()), Bytes(vec![0xca_u8, 0xfe]))])?;
 
-	if let Err(err) = teradatarustapi::go_close_rows_wrapper(u_log, rows_handle) {
-		println!("Error from go_close_rows_wrapper: {}", err);
+	let mut rows = conn.query(SELECT_ROWS, "null")?;
+	let columns = rows.columns()?.to_vec();
+	let mut row_num = 0;
+	while let Some(values) = rows.fetch_values()? {
+		row_num += 1;
+		println!();
+		println!("Row {}", row_num);
+		for (column, value) in columns.iter().zip(&values) {
+			println!("  {:<26} {:<36} {}", column.name, column.type_name, describe(value));
+		}
 	}
-} // end execute_request
 
-fn main() {
-	let args: Vec<String> = env::args().collect();
-	if args.len() < 3 {
-		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
-		return;
-	}
+	conn.execute("drop table vtab", "null")?;
+	Ok(())
 
-	let lib_dir = &args[1];
-	let connect_params_json = &args[2];
-	println!("lib_dir: {}", lib_dir);
-	println!("connect_params_json: {}", connect_params_json);
+} // end round_trip
 
-	if let Err(err) = teradatarustapi::load_driver(lib_dir) {
-		println!("Error from load_driver: {}", err);
-		return;
+fn describe(value: &Value) -> String {
+	match value {
+		Value::Bytes(bytes) => format!("Bytes({:02x?})", bytes),
+		value => format!("{:?}", value),
 	}
+} // end describe
 
-	let (u_log, conn_handle) = match teradatarustapi::create_connection(connect_params_json) {
-		Ok((u_log, conn_handle)) => (u_log, conn_handle),
-		Err(err) => {
-			println!("Error from create_connection: {}", err);
-			return;
-		}
-	};
-	println!("conn_handle: {}", conn_handle);
-
-	// show client attributes
-	execute_request(u_log, conn_handle, "select * from DBC.SessionInfoV where SessionNo = session", "null"); // null means no bind values
-
-	// show session attributes
-	execute_request(u_log, conn_handle, "help session", "null"); // null means no bind values
-
-	execute_request(u_log, conn_handle, "create volatile table vtab (c1 integer, c2 varchar(100)) on commit preserve rows", "null");
-	// demonstrate single row of bind values
-	execute_request(u_log, conn_handle, "insert into vtab values (?, ?)", r#"[[123,"hello"]]"#);
-	// demonstrate two rows of bind values
-	execute_request(u_log, conn_handle, "insert into vtab values (?, ?)", r#"[[456,"world"],[789,"foobar"]]"#);
-	// demonstrate how to insert NULL bind value using JSON null
-	execute_request(u_log, conn_handle, "insert into vtab values (?, ?)", "[[999,null]]");
-	execute_request(u_log, conn_handle, "select * from vtab order by 1", "null");
-	// Result 1 row 1: [123,"hello"]
-	// Result 1 row 2: [456,"world"]
-	// Result 1 row 3: [789,"foobar"]
-	// Result 1 row 4: [999,null]
-	execute_request(u_log, conn_handle, "drop table vtab", "null");
-
-	// demonstrate multi-statement request
-	execute_request(u_log, conn_handle, "select session ; select * from DBC.DBCInfo order by 1 ; select current_timestamp ; select * from DBC.DBCInfo order by 1 desc", "null");
-
-	// demonstrate how result set BYTE and VARBYTE values are returned as base64 encoded strings
-	execute_request(u_log, conn_handle, "select to_bytes('ABCD', 'ascii') as byte_val, from_bytes(byte_val, 'base64m') as display_byte_val_as_base64", "null");
-	// Result 1 row 1: ["QUJDRA==","QUJDRA=="]
-
-	// demonstrate how the to_bytes function must be used to create VARBYTE values from base64 encoded bind values
-	execute_request(u_log, conn_handle, "select to_bytes(?, 'base64m') as bound_byte_val, from_bytes(bound_byte_val, 'ascii') as display_byte_val_as_varchar", r#"[["QUJDRA=="]]"#);
-	// Result 1 row 1: ["QUJDRA==","ABCD"]
-
-	// demonstrate all supported Teradata data types
-	execute_request(u_log, conn_handle, r#"create volatile table vtab (
+const CREATE_TABLE: &str = r#"create volatile table vtab (
 		c1 byteint,
 		c2 smallint,
 		c3 integer,
@@ -163,9 +93,9 @@ fn main() {
 		c36 blob,
 		c37 clob,
 		c38 xml,
-		c39 json) on commit preserve rows"#, "null");
-	execute_request(u_log, conn_handle,
-		r#"insert into vtab (
+		c39 json) on commit preserve rows"#;
+
+const INSERT_ROW: &str = r#"insert into vtab (
 			c1,  -- byteint
 			c2,  -- smallint
 			c3,  -- integer
@@ -246,8 +176,9 @@ fn main() {
 			?, -- c37 clob
 			createxml(?), -- c38 xml - must use createxml function to convert string bind value to XML
 			? -- c39 json
-		)"#,
-		r#"[[
+		)"#;
+
+const INSERT_BIND_VALUES: &str = r#"[[
 			127,
 			32767,
 			2147483647,
@@ -287,9 +218,9 @@ fn main() {
 			"ClobValue",
 			"<foo>bar</foo>",
 			"[1,2,3]"
-		]]"#);
-	execute_request(u_log, conn_handle,
-		r#"select
+		]]"#;
+
+const SELECT_ROWS: &str = r#"select
 			c1, -- byteint                             JSON number     127
 			c2, -- smallint                            JSON number     32767
 			c3, -- integer                             JSON number     2147483647
@@ -333,9 +264,29 @@ fn main() {
 			c38, -- xml                                JSON string     "<foo>bar</foo>"
 			c39 -- json                                JSON string     "[1,2,3]"
 		from vtab
-		order by 1"#, "null");
+		order by 1"#;
+
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 3 {
+		println!("Parameters: SharedLibraryDir ConnectParamsJSON");
+		return;
+	}
+
+	if let Err(err) = teradatarustapi::load_driver(&args[1]) {
+		println!("Error from load_driver: {}", err.message());
+		return;
+	}
+
+	let conn = match Connection::open(&args[2]) {
+		Ok(conn) => conn,
+		Err(err) => {
+			println!("Error from Connection::open: {}", err.message());
+			return;
+		}
+	};
 
-	if let Err(err) = teradatarustapi::go_close_connection_wrapper(u_log, conn_handle) {
-		println!("Error from go_close_connection_wrapper: {}", err);
+	if let Err(err) = round_trip(&conn) {
+		println!("Error: {}", err.message());
 	}
 } // end main
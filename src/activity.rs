@@ -0,0 +1,110 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Broad classification of the kind of SQL statement that produced a result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActivityCategory {
+	Query,   // SELECT, HELP, SHOW, and other statements that return rows
+	Dml,     // INSERT, UPDATE, DELETE, MERGE
+	Ddl,     // CREATE, ALTER, DROP, RENAME, REPLACE, GRANT, REVOKE, COMMENT, COLLECT STATISTICS
+	Call,    // stored procedure calls and macro executions
+	Utility, // transaction control, session settings, and everything else
+}
+
+// The SQL statement that produced a result, for branching on the statement without matching on
+// activity type codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActivityKind {
+	Select,
+	Insert,
+	Update,           // UPDATE, including the atomic upsert form
+	Delete,
+	MergeInto,
+	Ddl,              // CREATE, ALTER, DROP, RENAME, REPLACE, GRANT, REVOKE, COMMENT, COLLECT STATISTICS
+	Call,             // stored procedure call
+	ExecMacro,
+	Help,
+	Show,
+	BeginTransaction,
+	EndTransaction,
+	Commit,
+	Rollback,         // ROLLBACK and ABORT
+	Database,         // DATABASE statement
+	Set,              // SET SESSION, SET QUERY_BAND, SET ROLE, SET TIME ZONE
+	Unknown(u16),     // the activity type code of an activity not listed above
+}
+
+// Leading words of activity names for each kind, compared without regard to case, in the order
+// they are checked
+const KIND_PREFIXES: &[(&str, ActivityKind)] = &[
+	("select", ActivityKind::Select),
+	("insert", ActivityKind::Insert),
+	("update", ActivityKind::Update),
+	("upsert", ActivityKind::Update),
+	("delete database", ActivityKind::Ddl),
+	("delete user", ActivityKind::Ddl),
+	("delete", ActivityKind::Delete),
+	("merge", ActivityKind::MergeInto),
+	("call", ActivityKind::Call),
+	("exec", ActivityKind::ExecMacro),
+	("help", ActivityKind::Help),
+	("show", ActivityKind::Show),
+	("begin transaction", ActivityKind::BeginTransaction),
+	("end transaction", ActivityKind::EndTransaction),
+	("commit", ActivityKind::Commit),
+	("rollback", ActivityKind::Rollback),
+	("abort", ActivityKind::Rollback),
+	("database", ActivityKind::Database),
+	("set ", ActivityKind::Set),
+];
+
+// Activity type of a result, as returned by rustgo_result_metadata_wrapper
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActivityType {
+	pub code: u16,
+	pub name: String, // activity name, such as "Select" or "Create Table"
+}
+
+// Leading words of activity names for each category, compared without regard to case
+const QUERY_PREFIXES: &[&str] = &["select", "help", "show", "echo", "comment returning", "explain"];
+const DML_PREFIXES: &[&str] = &["insert", "update", "delete", "merge", "upsert"];
+const DDL_PREFIXES: &[&str] = &[
+	"create", "alter", "modify", "drop", "rename", "replace", "grant", "revoke", "give",
+	"comment", "collect", "diagnostic", "initiate index analysis",
+];
+const CALL_PREFIXES: &[&str] = &["call", "exec", "execute"];
+
+impl ActivityType {
+	pub fn new(code: u16, name: &str) -> Self {
+		ActivityType { code, name: name.to_string() }
+	}
+
+	// Identifies the statement from its activity name, or Unknown with the activity type code
+	pub fn kind(&self) -> ActivityKind {
+		let name = self.name.trim().to_lowercase();
+		if let Some((_, kind)) = KIND_PREFIXES.iter().find(|(prefix, _)| name.starts_with(prefix)) {
+			return *kind;
+		}
+		if !name.starts_with("comment returning") && DDL_PREFIXES.iter().any(|p| name.starts_with(p)) {
+			return ActivityKind::Ddl;
+		}
+		ActivityKind::Unknown(self.code)
+	}
+
+	// Classifies the activity from its activity name
+	pub fn category(&self) -> ActivityCategory {
+		let name = self.name.trim().to_lowercase();
+		let matches = |prefixes: &[&str]| prefixes.iter().any(|p| name.starts_with(p));
+		// Query prefixes are checked before DDL prefixes so that "Comment Returning" is a query
+		if matches(QUERY_PREFIXES) {
+			ActivityCategory::Query
+		} else if matches(DML_PREFIXES) {
+			ActivityCategory::Dml
+		} else if matches(DDL_PREFIXES) {
+			ActivityCategory::Ddl
+		} else if matches(CALL_PREFIXES) {
+			ActivityCategory::Call
+		} else {
+			ActivityCategory::Utility
+		}
+	}
+}
@@ -0,0 +1,377 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Builders for common administration statements, for infrastructure-as-code tooling. Names are
+// quoted as identifiers and privileges are chosen from a fixed list, so no builder input can
+// change the structure of a statement. Each builder renders its statement with to_sql for dry
+// runs, and executes it with execute.
+
+use crate::error::TeradataError;
+use crate::execute_simple_request;
+use crate::sql::{quote_identifier, quote_literal, quote_qualified_name};
+
+// An administration statement that can be rendered without being executed
+pub trait AdminStatement {
+	// Returns the statement text, or an error if a required part is missing
+	fn to_sql(&self) -> Result<String, TeradataError>;
+
+	fn execute(&self, u_log: u64, conn_handle: u64) -> Result<(), TeradataError> {
+		execute_simple_request(u_log, conn_handle, &self.to_sql()?)
+	}
+}
+
+fn require_name(what: &str, name: &str) -> Result<(), TeradataError> {
+	if name.trim().is_empty() {
+		return Err(TeradataError::InvalidArgument(format!("{} name is empty", what)));
+	}
+	Ok(())
+}
+
+// Space, session, and default settings shared by CREATE USER and MODIFY PROFILE
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct SpaceSettings {
+	spool_bytes: Option<u64>,
+	temporary_bytes: Option<u64>,
+	default_database: Option<String>,
+	account: Option<String>,
+}
+
+impl SpaceSettings {
+	fn render(&self, clauses: &mut Vec<String>) {
+		if let Some(bytes) = self.spool_bytes {
+			clauses.push(format!("SPOOL = {} BYTES", bytes));
+		}
+		if let Some(bytes) = self.temporary_bytes {
+			clauses.push(format!("TEMPORARY = {} BYTES", bytes));
+		}
+		if let Some(database) = &self.default_database {
+			clauses.push(format!("DEFAULT DATABASE = {}", quote_identifier(database)));
+		}
+		if let Some(account) = &self.account {
+			clauses.push(format!("ACCOUNT = {}", quote_literal(account)));
+		}
+	}
+}
+
+// CREATE USER name FROM owner AS PERMANENT = n BYTES, PASSWORD = "...", ...
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CreateUser {
+	name: String,
+	owner: Option<String>,
+	perm_bytes: u64,
+	password: Option<String>,
+	settings: SpaceSettings,
+	profile: Option<String>,
+	default_role: Option<String>,
+}
+
+impl CreateUser {
+	pub fn new(name: &str, perm_bytes: u64) -> Self {
+		CreateUser { name: name.to_string(), perm_bytes, ..Default::default() }
+	}
+
+	// The database or user whose space the new user is created from
+	pub fn owner(mut self, owner: &str) -> Self {
+		self.owner = Some(owner.to_string());
+		self
+	}
+
+	pub fn password(mut self, password: &str) -> Self {
+		self.password = Some(password.to_string());
+		self
+	}
+
+	pub fn spool(mut self, bytes: u64) -> Self {
+		self.settings.spool_bytes = Some(bytes);
+		self
+	}
+
+	pub fn temporary(mut self, bytes: u64) -> Self {
+		self.settings.temporary_bytes = Some(bytes);
+		self
+	}
+
+	pub fn default_database(mut self, database: &str) -> Self {
+		self.settings.default_database = Some(database.to_string());
+		self
+	}
+
+	pub fn account(mut self, account: &str) -> Self {
+		self.settings.account = Some(account.to_string());
+		self
+	}
+
+	pub fn profile(mut self, profile: &str) -> Self {
+		self.profile = Some(profile.to_string());
+		self
+	}
+
+	pub fn default_role(mut self, role: &str) -> Self {
+		self.default_role = Some(role.to_string());
+		self
+	}
+}
+
+impl AdminStatement for CreateUser {
+	fn to_sql(&self) -> Result<String, TeradataError> {
+		require_name("User", &self.name)?;
+		let password = match &self.password {
+			Some(password) if !password.is_empty() => password,
+			_ => return Err(TeradataError::InvalidArgument(format!("CREATE USER {} requires a password", self.name))),
+		};
+
+		let mut clauses = vec![format!("PERMANENT = {} BYTES", self.perm_bytes), format!("PASSWORD = {}", quote_identifier(password))];
+		self.settings.render(&mut clauses);
+		if let Some(profile) = &self.profile {
+			clauses.push(format!("PROFILE = {}", quote_identifier(profile)));
+		}
+		if let Some(role) = &self.default_role {
+			clauses.push(format!("DEFAULT ROLE = {}", quote_identifier(role)));
+		}
+
+		let from = match &self.owner {
+			Some(owner) => format!(" FROM {}", quote_identifier(owner)),
+			None => String::new(),
+		};
+		Ok(format!("CREATE USER {}{} AS {}", quote_identifier(&self.name), from, clauses.join(", ")))
+	}
+}
+
+// MODIFY PROFILE name AS SPOOL = n BYTES, ...
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModifyProfile {
+	name: String,
+	settings: SpaceSettings,
+	password_expire_days: Option<u32>,
+}
+
+impl ModifyProfile {
+	pub fn new(name: &str) -> Self {
+		ModifyProfile { name: name.to_string(), ..Default::default() }
+	}
+
+	pub fn spool(mut self, bytes: u64) -> Self {
+		self.settings.spool_bytes = Some(bytes);
+		self
+	}
+
+	pub fn temporary(mut self, bytes: u64) -> Self {
+		self.settings.temporary_bytes = Some(bytes);
+		self
+	}
+
+	pub fn default_database(mut self, database: &str) -> Self {
+		self.settings.default_database = Some(database.to_string());
+		self
+	}
+
+	pub fn account(mut self, account: &str) -> Self {
+		self.settings.account = Some(account.to_string());
+		self
+	}
+
+	// Number of days after which passwords of users with the profile expire; 0 never expires
+	pub fn password_expire_days(mut self, days: u32) -> Self {
+		self.password_expire_days = Some(days);
+		self
+	}
+}
+
+impl AdminStatement for ModifyProfile {
+	fn to_sql(&self) -> Result<String, TeradataError> {
+		require_name("Profile", &self.name)?;
+		let mut clauses = Vec::new();
+		self.settings.render(&mut clauses);
+		if let Some(days) = self.password_expire_days {
+			clauses.push(format!("PASSWORD = (EXPIRE = {})", days));
+		}
+		if clauses.is_empty() {
+			return Err(TeradataError::InvalidArgument(format!("MODIFY PROFILE {} has nothing to modify", self.name)));
+		}
+		Ok(format!("MODIFY PROFILE {} AS {}", quote_identifier(&self.name), clauses.join(", ")))
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Privilege {
+	All,
+	Select,
+	Insert,
+	Update,
+	Delete,
+	Execute,
+	ExecuteProcedure,
+	ExecuteFunction,
+	CreateTable,
+	CreateView,
+	CreateMacro,
+	CreateProcedure,
+	CreateDatabase,
+	CreateUser,
+	DropTable,
+	DropView,
+	DropMacro,
+	DropProcedure,
+	DropDatabase,
+	DropUser,
+	Index,
+	References,
+	Show,
+	Statistics,
+}
+
+impl Privilege {
+	pub fn as_sql(&self) -> &'static str {
+		match self {
+			Privilege::All => "ALL",
+			Privilege::Select => "SELECT",
+			Privilege::Insert => "INSERT",
+			Privilege::Update => "UPDATE",
+			Privilege::Delete => "DELETE",
+			Privilege::Execute => "EXECUTE",
+			Privilege::ExecuteProcedure => "EXECUTE PROCEDURE",
+			Privilege::ExecuteFunction => "EXECUTE FUNCTION",
+			Privilege::CreateTable => "CREATE TABLE",
+			Privilege::CreateView => "CREATE VIEW",
+			Privilege::CreateMacro => "CREATE MACRO",
+			Privilege::CreateProcedure => "CREATE PROCEDURE",
+			Privilege::CreateDatabase => "CREATE DATABASE",
+			Privilege::CreateUser => "CREATE USER",
+			Privilege::DropTable => "DROP TABLE",
+			Privilege::DropView => "DROP VIEW",
+			Privilege::DropMacro => "DROP MACRO",
+			Privilege::DropProcedure => "DROP PROCEDURE",
+			Privilege::DropDatabase => "DROP DATABASE",
+			Privilege::DropUser => "DROP USER",
+			Privilege::Index => "INDEX",
+			Privilege::References => "REFERENCES",
+			Privilege::Show => "SHOW",
+			Privilege::Statistics => "STATISTICS",
+		}
+	}
+}
+
+// The user, role, or PUBLIC that receives or loses privileges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Grantee {
+	Name(String), // a user or role
+	Public,
+}
+
+impl Grantee {
+	fn to_sql(&self) -> Result<String, TeradataError> {
+		match self {
+			Grantee::Name(name) => {
+				require_name("Grantee", name)?;
+				Ok(quote_identifier(name))
+			}
+			Grantee::Public => Ok("PUBLIC".to_string()),
+		}
+	}
+}
+
+impl From<&str> for Grantee {
+	fn from(name: &str) -> Self {
+		Grantee::Name(name.to_string())
+	}
+}
+
+// Privileges on an object, or a role, and the grantees, shared by Grant and Revoke
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Authorization {
+	privileges: Vec<Privilege>,
+	object: Option<String>,
+	role: Option<String>,
+	grantees: Vec<Grantee>,
+}
+
+impl Authorization {
+	// Renders "privileges ON object" or the role name
+	fn subject(&self, statement: &str) -> Result<String, TeradataError> {
+		match (&self.role, &self.object) {
+			(Some(role), None) if self.privileges.is_empty() => {
+				require_name("Role", role)?;
+				Ok(quote_identifier(role))
+			}
+			(None, Some(object)) if !self.privileges.is_empty() => {
+				require_name("Object", object)?;
+				let privileges = self.privileges.iter().map(Privilege::as_sql).collect::<Vec<_>>().join(", ");
+				Ok(format!("{} ON {}", privileges, quote_qualified_name(object)))
+			}
+			_ => Err(TeradataError::InvalidArgument(format!("{} requires either privileges and an object, or a role", statement))),
+		}
+	}
+
+	fn grantees(&self, statement: &str) -> Result<String, TeradataError> {
+		if self.grantees.is_empty() {
+			return Err(TeradataError::InvalidArgument(format!("{} requires at least one grantee", statement)));
+		}
+		Ok(self.grantees.iter().map(Grantee::to_sql).collect::<Result<Vec<_>, _>>()?.join(", "))
+	}
+}
+
+// GRANT privileges ON object TO grantees, or GRANT role TO grantees
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grant {
+	authorization: Authorization,
+	with_grant_option: bool,
+}
+
+impl Grant {
+	// Grants privileges on a database or database object, such as "db" or "db.tab"
+	pub fn privileges(privileges: &[Privilege], object: &str) -> Self {
+		Grant { authorization: Authorization { privileges: privileges.to_vec(), object: Some(object.to_string()), ..Default::default() }, with_grant_option: false }
+	}
+
+	// Grants a role
+	pub fn role(role: &str) -> Self {
+		Grant { authorization: Authorization { role: Some(role.to_string()), ..Default::default() }, with_grant_option: false }
+	}
+
+	pub fn to<G: Into<Grantee>>(mut self, grantee: G) -> Self {
+		self.authorization.grantees.push(grantee.into());
+		self
+	}
+
+	// Lets the grantees grant the privileges to others; WITH ADMIN OPTION for a role
+	pub fn with_grant_option(mut self) -> Self {
+		self.with_grant_option = true;
+		self
+	}
+}
+
+impl AdminStatement for Grant {
+	fn to_sql(&self) -> Result<String, TeradataError> {
+		let mut sql = format!("GRANT {} TO {}", self.authorization.subject("GRANT")?, self.authorization.grantees("GRANT")?);
+		if self.with_grant_option {
+			sql.push_str(if self.authorization.role.is_some() { " WITH ADMIN OPTION" } else { " WITH GRANT OPTION" });
+		}
+		Ok(sql)
+	}
+}
+
+// REVOKE privileges ON object FROM grantees, or REVOKE role FROM grantees
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Revoke {
+	authorization: Authorization,
+}
+
+impl Revoke {
+	pub fn privileges(privileges: &[Privilege], object: &str) -> Self {
+		Revoke { authorization: Authorization { privileges: privileges.to_vec(), object: Some(object.to_string()), ..Default::default() } }
+	}
+
+	pub fn role(role: &str) -> Self {
+		Revoke { authorization: Authorization { role: Some(role.to_string()), ..Default::default() } }
+	}
+
+	pub fn from<G: Into<Grantee>>(mut self, grantee: G) -> Self {
+		self.authorization.grantees.push(grantee.into());
+		self
+	}
+}
+
+impl AdminStatement for Revoke {
+	fn to_sql(&self) -> Result<String, TeradataError> {
+		Ok(format!("REVOKE {} FROM {}", self.authorization.subject("REVOKE")?, self.authorization.grantees("REVOKE")?))
+	}
+}
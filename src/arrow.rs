@@ -0,0 +1,70 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Conversion of result column descriptions to an Arrow schema, for applications that build
+// their own Arrow pipelines from fetched rows. The conversion does not depend on fetching.
+
+use std::collections::HashMap;
+
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+
+use crate::result::{parse_column_info, ColumnInfo};
+
+// Field metadata key recording the database type name of each column
+pub const TYPE_NAME_METADATA_KEY: &str = "teradata.type_name";
+
+// Largest DECIMAL precision that Decimal128 can represent
+const MAX_DECIMAL128_PRECISION: u64 = 38;
+
+// Returns the Arrow data type for a column:
+//   BYTEINT, SMALLINT, INTEGER, BIGINT          -> Int8, Int16, Int32, Int64
+//   DECIMAL and NUMBER up to 38 digits          -> Decimal128(precision, scale)
+//   NUMBER with more digits or floating scale   -> Utf8, preserving every digit
+//   FLOAT, REAL, DOUBLE PRECISION               -> Float64
+//   CHAR, VARCHAR, LONG VARCHAR                 -> Utf8
+//   CLOB, JSON, XML                             -> LargeUtf8
+//   BYTE, VARBYTE                               -> Binary
+//   BLOB                                        -> LargeBinary
+//   DATE                                        -> Date32
+//   TIME                                        -> Time64(Microsecond)
+//   TIMESTAMP                                   -> Timestamp(Microsecond, None)
+//   TIMESTAMP WITH TIME ZONE                    -> Timestamp(Microsecond, "+00:00"), normalized to UTC
+//   TIME WITH TIME ZONE, INTERVAL, PERIOD, other -> Utf8, in the string form returned by the driver
+pub fn arrow_data_type(column: &ColumnInfo) -> DataType {
+	let type_name = column.type_name.trim().to_uppercase();
+	match type_name.as_str() {
+		"BYTEINT" => DataType::Int8,
+		"SMALLINT" => DataType::Int16,
+		"INTEGER" => DataType::Int32,
+		"BIGINT" => DataType::Int64,
+		"DECIMAL" | "NUMBER" if column.precision > 0 && column.precision <= MAX_DECIMAL128_PRECISION && column.scale <= column.precision => {
+			DataType::Decimal128(column.precision as u8, column.scale as i8)
+		}
+		"FLOAT" | "REAL" | "DOUBLE PRECISION" => DataType::Float64,
+		"CHAR" | "VARCHAR" | "LONG VARCHAR" => DataType::Utf8,
+		"CLOB" | "JSON" | "XML" => DataType::LargeUtf8,
+		"BYTE" | "VARBYTE" => DataType::Binary,
+		"BLOB" => DataType::LargeBinary,
+		"DATE" => DataType::Date32,
+		"TIME" => DataType::Time64(TimeUnit::Microsecond),
+		"TIMESTAMP" => DataType::Timestamp(TimeUnit::Microsecond, None),
+		"TIMESTAMP WITH TIME ZONE" => DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into())),
+		_ => DataType::Utf8,
+	}
+}
+
+// Returns the Arrow field for a column, recording the database type name in the field metadata
+pub fn arrow_field(column: &ColumnInfo) -> Field {
+	Field::new(&column.name, arrow_data_type(column), column.nullable)
+		.with_metadata(HashMap::from([(TYPE_NAME_METADATA_KEY.to_string(), column.type_name.clone())]))
+}
+
+// Returns the Arrow schema for result columns
+pub fn arrow_schema(columns: &[ColumnInfo]) -> Schema {
+	Schema::new(columns.iter().map(arrow_field).collect::<Vec<Field>>())
+}
+
+// Returns the Arrow schema for a column metadata JSON object, such as from
+// rustgo_result_metadata_wrapper
+pub fn arrow_schema_from_metadata(column_metadata: &str) -> Result<Schema, String> {
+	Ok(arrow_schema(&parse_column_info(column_metadata)?))
+}
@@ -0,0 +1,285 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Async API for tokio applications. The driver calls block, so each method runs them on the
+// tokio blocking thread pool and returns a future, and the methods must be called from within a
+// tokio runtime. Dropping a future does not interrupt a driver call that has started; the call
+// completes on the blocking thread pool and its result is discarded.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::blocking::run_blocking;
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::limiter::WorkloadPermit;
+use crate::params::{bind_json, BindValues};
+use crate::raw::{go_close_rows_wrapper, go_next_result_wrapper, rustgo_create_rows_wrapper, rustgo_fetch_row_wrapper, rustgo_result_metadata_wrapper};
+use crate::result::ResultMetadata;
+use crate::row::Row;
+
+// A Connection whose methods run the driver calls on the tokio blocking thread pool. The
+// connection is shared as an Arc<Connection>, so that a connection borrowed from the bb8 or
+// deadpool managers of this crate can be used through from_shared.
+#[derive(Debug)]
+pub struct AsyncConnection {
+	conn: Arc<Connection>,
+}
+
+impl AsyncConnection {
+	// Connects using a connection parameter JSON string, the same as Connection::open
+	pub async fn open(connect_params_json: &str) -> Result<AsyncConnection, TeradataError> {
+		let connect_params_json = connect_params_json.to_string();
+		run_blocking(move || Connection::open(&connect_params_json)).await.map(AsyncConnection::from)
+	}
+
+	// Connects with an overall deadline, the same as Connection::open_with_timeout
+	pub async fn open_with_timeout(connect_params_json: &str, timeout: Duration) -> Result<AsyncConnection, TeradataError> {
+		let connect_params_json = connect_params_json.to_string();
+		run_blocking(move || Connection::open_with_timeout(&connect_params_json, timeout)).await.map(AsyncConnection::from)
+	}
+
+	pub fn from_shared(conn: Arc<Connection>) -> AsyncConnection {
+		AsyncConnection { conn }
+	}
+
+	pub fn connection(&self) -> &Arc<Connection> {
+		&self.conn
+	}
+
+	// Runs a closure with the connection on the tokio blocking thread pool, for the Connection
+	// methods that have no async counterpart
+	pub async fn run<T, F>(&self, f: F) -> Result<T, TeradataError>
+	where
+		T: Send + 'static,
+		F: FnOnce(&Connection) -> Result<T, TeradataError> + Send + 'static,
+	{
+		let conn = Arc::clone(&self.conn);
+		run_blocking(move || f(&conn)).await
+	}
+
+	// Executes a request without fetching rows, the same as Connection::execute
+	pub async fn execute<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<u64, TeradataError> {
+		let request_text = request_text.to_string();
+		let (bind_values, bytes) = bind_json(bind_values).map_err(TeradataError::InvalidArgument)?;
+		self.run(move |conn| conn.execute_json(&request_text, &bind_values, bytes)).await
+	}
+
+	// Executes a request and returns a cursor over its results, the same as Connection::query
+	pub async fn query<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<AsyncRows, TeradataError> {
+		let (bind_values, bytes) = bind_json(bind_values).map_err(TeradataError::InvalidArgument)?;
+		self.query_json(request_text.to_string(), bind_values, bytes).await
+	}
+
+	// Same as query with a JSON bind values string, converting the parameter markers of binary
+	// parameters from base64 if bytes reports that the bind values hold byte values
+	pub(crate) async fn query_json(&self, request_text: String, bind_values: String, bytes: bool) -> Result<AsyncRows, TeradataError> {
+		let conn = Arc::clone(&self.conn);
+		let (rows_handle, permit) = self.run(move |conn| {
+			let request_text = conn.bound_request_text(&request_text, bytes)?;
+			let permit = conn.before_request()?;
+			match rustgo_create_rows_wrapper(conn.u_log(), conn.conn_handle(), &request_text, &bind_values) {
+				Ok(rows_handle) => Ok((rows_handle, permit)),
+				Err(err) => Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err)),
+			}
+		}).await?;
+		Ok(AsyncRows { cursor: Arc::new(Cursor { conn, rows_handle, _permit: permit, closed: false }), metadata: None, result_index: 0 })
+	}
+
+	// Executes a request and fetches every row of its first result
+	pub async fn fetch_all<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<Vec<Row>, TeradataError> {
+		self.query(request_text, bind_values).await?.fetch_all().await
+	}
+
+	// Executes a request and fetches the only row of its first result, the same as
+	// Connection::fetch_one
+	pub async fn fetch_one<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<Row, TeradataError> {
+		self.query(request_text, bind_values).await?.fetch_one().await
+	}
+
+	// Executes a request and fetches the only row of its first result, or None, the same as
+	// Connection::fetch_optional
+	pub async fn fetch_optional<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<Option<Row>, TeradataError> {
+		self.query(request_text, bind_values).await?.fetch_optional().await
+	}
+
+	pub async fn commit(&self) -> Result<(), TeradataError> {
+		self.run(|conn| conn.commit()).await
+	}
+
+	pub async fn rollback(&self) -> Result<(), TeradataError> {
+		self.run(|conn| conn.rollback()).await
+	}
+
+	pub async fn set_autocommit(&self, autocommit: bool) -> Result<(), TeradataError> {
+		self.run(move |conn| conn.set_autocommit(autocommit)).await
+	}
+
+	pub async fn autocommit(&self) -> Result<bool, TeradataError> {
+		self.run(|conn| conn.autocommit()).await
+	}
+
+	pub async fn set_database(&self, database: &str) -> Result<(), TeradataError> {
+		let database = database.to_string();
+		self.run(move |conn| conn.set_database(&database)).await
+	}
+
+	// Closes the connection, returning any error that dropping it would ignore. A connection
+	// shared with from_shared remains open until its other owners drop it.
+	pub async fn close(self) -> Result<(), TeradataError> {
+		match Arc::try_unwrap(self.conn) {
+			Ok(conn) => run_blocking(move || conn.close()).await,
+			Err(_) => Ok(()),
+		}
+	}
+}
+
+impl From<Connection> for AsyncConnection {
+	fn from(conn: Connection) -> Self {
+		AsyncConnection { conn: Arc::new(conn) }
+	}
+}
+
+// The rows_handle of an AsyncRows, shared with the driver calls in progress so that it is closed
+// only after they complete
+#[derive(Debug)]
+struct Cursor {
+	conn: Arc<Connection>,
+	rows_handle: u64,
+	_permit: Option<WorkloadPermit>, // workload permit of the request, released when the cursor is dropped
+	closed: bool,
+}
+
+impl Cursor {
+	// Fetches up to max_rows rows of the current result
+	fn fetch_rows(&self, max_rows: usize) -> Result<Vec<Row>, TeradataError> {
+		let mut rows = Vec::new();
+		while rows.len() < max_rows {
+			match rustgo_fetch_row_wrapper(self.conn.u_log(), self.rows_handle) {
+				Ok(Some(json)) => rows.push(Row::from_json(json)?),
+				Ok(None) => break,
+				Err(err) => {
+					return Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err));
+				}
+			}
+		}
+		Ok(rows)
+	}
+}
+
+impl Drop for Cursor {
+	fn drop(&mut self) {
+		if !self.closed {
+			let _ = go_close_rows_wrapper(self.conn.u_log(), self.rows_handle);
+		}
+	}
+}
+
+// An open result cursor returned by AsyncConnection::query, the async counterpart of Rows. It
+// keeps the connection open and closes its rows_handle when dropped.
+#[derive(Debug)]
+pub struct AsyncRows {
+	cursor: Arc<Cursor>,
+	metadata: Option<ResultMetadata>, // metadata of the current result, once retrieved
+	result_index: usize,              // zero-based index of the current result
+}
+
+impl AsyncRows {
+	async fn run<T, F>(&self, f: F) -> Result<T, TeradataError>
+	where
+		T: Send + 'static,
+		F: FnOnce(&Cursor) -> Result<T, TeradataError> + Send + 'static,
+	{
+		let cursor = Arc::clone(&self.cursor);
+		run_blocking(move || f(&cursor)).await
+	}
+
+	pub fn connection(&self) -> &Arc<Connection> {
+		&self.cursor.conn
+	}
+
+	pub fn rows_handle(&self) -> u64 {
+		self.cursor.rows_handle
+	}
+
+	// Zero-based index of the current result among the results of the request
+	pub fn result_index(&self) -> usize {
+		self.result_index
+	}
+
+	// Returns the metadata of the current result, retrieving it from the driver only once per result
+	pub async fn metadata(&mut self) -> Result<&ResultMetadata, TeradataError> {
+		if self.metadata.is_none() {
+			let metadata = self.run(|cursor| match rustgo_result_metadata_wrapper(cursor.conn.u_log(), cursor.rows_handle) {
+				Ok((activity_count, activity_type, activity_name, column_metadata)) => Ok(ResultMetadata { activity_count, activity_type, activity_name, column_metadata }),
+				Err(err) => Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err)),
+			}).await?;
+			self.metadata = Some(metadata);
+		}
+		Ok(self.metadata.as_ref().unwrap())
+	}
+
+	// Fetches the next row of the current result, or None when there are no more rows
+	pub async fn fetch(&mut self) -> Result<Option<Row>, TeradataError> {
+		Ok(self.fetch_many(1).await?.pop())
+	}
+
+	// Fetches up to max_rows rows of the current result with a single hop to the blocking thread
+	// pool. Fewer rows are returned only at the end of the result.
+	pub async fn fetch_many(&mut self, max_rows: usize) -> Result<Vec<Row>, TeradataError> {
+		self.run(move |cursor| cursor.fetch_rows(max_rows)).await
+	}
+
+	// Fetches every remaining row of the current result
+	pub async fn fetch_all(&mut self) -> Result<Vec<Row>, TeradataError> {
+		self.fetch_many(usize::MAX).await
+	}
+
+	// Fetches the only row of the current result. Returns TeradataError::RowCount if the result
+	// has no rows or more than one row.
+	pub async fn fetch_one(&mut self) -> Result<Row, TeradataError> {
+		match self.fetch_optional().await? {
+			Some(row) => Ok(row),
+			None => Err(TeradataError::RowCount("Expected exactly one row, but the result has no rows".to_string())),
+		}
+	}
+
+	// Fetches the only row of the current result, or None if the result has no rows. Returns
+	// TeradataError::RowCount if the result has more than one row.
+	pub async fn fetch_optional(&mut self) -> Result<Option<Row>, TeradataError> {
+		let mut rows = self.fetch_many(2).await?;
+		if rows.len() > 1 {
+			return Err(TeradataError::RowCount("Expected at most one row, but the result has more than one row".to_string()));
+		}
+		Ok(rows.pop())
+	}
+
+	// Advances to the next result, skipping any unfetched rows of the current result without
+	// fetching them. Returns false when there are no more results.
+	pub async fn next_result(&mut self) -> Result<bool, TeradataError> {
+		self.metadata = None;
+		let avail = self.run(|cursor| match go_next_result_wrapper(cursor.conn.u_log(), cursor.rows_handle) {
+			Ok(avail) => Ok(avail),
+			Err(err) => Err(TeradataError::from_driver("go_next_result_wrapper", err)),
+		}).await?;
+		if avail {
+			self.result_index += 1;
+		}
+		Ok(avail)
+	}
+
+	// Closes the cursor on the blocking thread pool, returning any error that dropping the
+	// AsyncRows would ignore
+	pub async fn close(self) -> Result<(), TeradataError> {
+		match Arc::try_unwrap(self.cursor) {
+			Ok(mut cursor) => run_blocking(move || {
+				cursor.closed = true;
+				match go_close_rows_wrapper(cursor.conn.u_log(), cursor.rows_handle) {
+					Ok(()) => Ok(()),
+					Err(err) => Err(TeradataError::from_driver("go_close_rows_wrapper", err)),
+				}
+			}).await,
+			// A driver call of a dropped future is still in progress; the cursor is closed when it completes
+			Err(_) => Ok(()),
+		}
+	}
+}
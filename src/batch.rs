@@ -0,0 +1,206 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::params::{serializing_bytes, ToParams};
+use crate::sql::count_parameters;
+use crate::statement::binary_request_text;
+use crate::raw::{go_close_rows_wrapper, go_next_result_wrapper, rustgo_create_rows_wrapper, rustgo_result_metadata_wrapper};
+
+// Default number of parameter rows bound to each request submitted by execute_many
+pub const DEFAULT_BATCH_CHUNK_SIZE: usize = 10000;
+
+// A chunk of parameter rows that could not be executed. The rows of a chunk are consecutive, so
+// the chunk held rows first_row through first_row + row_count - 1.
+#[derive(Debug, Clone)]
+pub struct ChunkFailure {
+	pub chunk_index: usize, // zero-based index of the chunk among the chunks executed
+	pub first_row: u64,     // zero-based index of the first row in the chunk
+	pub row_count: u64,     // number of rows in the chunk
+	pub error: TeradataError,
+}
+
+// A parameter row that was not submitted because it could not be converted to bind values or
+// has the wrong number of values
+#[derive(Debug, Clone)]
+pub struct RowFailure {
+	pub row_index: u64, // zero-based index of the row among the rows taken from the iterator
+	pub error: TeradataError,
+}
+
+// Outcome of execute_many
+#[derive(Debug, Clone, Default)]
+pub struct BatchSummary {
+	pub activity_count: u64, // cumulative activity count of the chunks that succeeded
+	pub rows_submitted: u64, // number of rows taken from the iterator
+	pub chunks_executed: usize,
+	pub failures: Vec<ChunkFailure>,
+	pub row_failures: Vec<RowFailure>,
+}
+
+impl BatchSummary {
+	pub fn is_success(&self) -> bool {
+		self.failures.is_empty() && self.row_failures.is_empty()
+	}
+}
+
+fn execute_chunk(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	chunk: &[Value],
+) -> Result<u64, TeradataError> {
+
+	let bind_values = match serde_json::to_string(chunk) {
+		Ok(s) => s,
+		Err(err) => {
+			return Err(TeradataError::Conversion(format!("Could not serialize bind values: {}", err)));
+		}
+	};
+
+	let rows_handle = match rustgo_create_rows_wrapper(u_log, conn_handle, request_text, &bind_values) {
+		Ok(handle) => handle,
+		Err(err) => {
+			return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
+		}
+	};
+
+	// Sum the activity count of every result produced by the request
+	let mut activity_count: u64 = 0;
+	let mut result = Ok(());
+	loop {
+		match rustgo_result_metadata_wrapper(u_log, rows_handle) {
+			Ok((count, _, _, _)) => activity_count += count,
+			Err(err) => {
+				result = Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err));
+				break;
+			}
+		}
+		match go_next_result_wrapper(u_log, rows_handle) {
+			Ok(true) => continue,
+			Ok(false) => break,
+			Err(err) => {
+				result = Err(TeradataError::from_driver("go_next_result_wrapper", err));
+				break;
+			}
+		}
+	}
+
+	if let Err(err) = go_close_rows_wrapper(u_log, rows_handle) && result.is_ok() {
+		result = Err(TeradataError::from_driver("go_close_rows_wrapper", err));
+	}
+
+	result.map(|_| activity_count)
+
+} // end execute_chunk
+
+pub fn execute_many<I>(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	rows: I,
+	chunk_size: usize,
+) -> Result<BatchSummary, TeradataError>
+where
+	I: IntoIterator,
+	I::Item: ToParams,
+{
+
+	execute_many_binding(u_log, conn_handle, request_text, rows, chunk_size, || binary_request_text(u_log, conn_handle, request_text))
+
+} // end execute_many
+
+// Same as execute_many, calling binary_text for the request text that converts the parameter
+// markers of binary parameters from base64, once the first chunk that holds byte values is
+// executed
+pub(crate) fn execute_many_binding<I, B>(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	rows: I,
+	chunk_size: usize,
+	mut binary_text: B,
+) -> Result<BatchSummary, TeradataError>
+where
+	I: IntoIterator,
+	I::Item: ToParams,
+	B: FnMut() -> Result<String, TeradataError>,
+{
+
+	if chunk_size == 0 {
+		return Err(TeradataError::InvalidArgument("chunk_size must be greater than zero".to_string()));
+	}
+
+	let marker_count = count_parameters(request_text);
+	let mut summary = BatchSummary::default();
+	let mut chunk: Vec<Value> = Vec::with_capacity(chunk_size);
+	let mut chunk_first_row: u64 = 0;
+	let mut chunk_bytes = false;
+	let mut bound_text: Option<Result<String, TeradataError>> = None;
+
+	let mut flush = |chunk: &mut Vec<Value>, first_row: u64, bytes: bool, summary: &mut BatchSummary| {
+		let chunk_index = summary.chunks_executed;
+		summary.chunks_executed += 1;
+		let text = if !bytes {
+			Ok(request_text)
+		} else {
+			match bound_text.get_or_insert_with(&mut binary_text) {
+				Ok(text) => Ok(text.as_str()),
+				Err(err) => Err(err.clone()),
+			}
+		};
+		match text.and_then(|text| execute_chunk(u_log, conn_handle, text, chunk)) {
+			Ok(count) => summary.activity_count += count,
+			Err(error) => summary.failures.push(ChunkFailure {
+				chunk_index,
+				first_row,
+				row_count: chunk.len() as u64,
+				error,
+			}),
+		}
+		chunk.clear();
+	};
+
+	for row in rows {
+		let row_index = summary.rows_submitted;
+		summary.rows_submitted += 1;
+
+		let (value, bytes) = serializing_bytes(|| row.to_params());
+		let value = value.map_err(TeradataError::Conversion).and_then(|values| match values.len() {
+			n if n != marker_count => Err(TeradataError::InvalidArgument(format!("Bind row has {} values but the request text has {} parameter markers", n, marker_count))),
+			_ => Ok(Value::Array(values)),
+		});
+
+		match value {
+			Ok(value) => {
+				if chunk.is_empty() {
+					chunk_first_row = row_index;
+					chunk_bytes = false;
+				}
+				chunk_bytes |= bytes;
+				chunk.push(value);
+			}
+			Err(error) => {
+				// The rows before the bad row are submitted as a chunk of their own, so that every
+				// chunk holds consecutive rows
+				if !chunk.is_empty() {
+					flush(&mut chunk, chunk_first_row, chunk_bytes, &mut summary);
+				}
+				summary.row_failures.push(RowFailure { row_index, error });
+				continue;
+			}
+		}
+
+		if chunk.len() == chunk_size {
+			flush(&mut chunk, chunk_first_row, chunk_bytes, &mut summary);
+		}
+	}
+
+	if !chunk.is_empty() {
+		flush(&mut chunk, chunk_first_row, chunk_bytes, &mut summary);
+	}
+
+	Ok(summary)
+
+} // end execute_many_binding
@@ -0,0 +1,62 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Connection manager for the bb8 async connection pool, so that tokio services can pool
+// connections. The driver calls block, so the manager runs them on the tokio blocking thread
+// pool, and connections are pooled as Arc<Connection> so that an application can do the same
+// with the connections it borrows.
+
+use std::sync::Arc;
+
+use crate::blocking::run_blocking;
+use crate::connect_params::ReloadableConnectParams;
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::pool::NATIVE_SQL_VALIDATION;
+
+// Opens connections for a bb8::Pool<TeradataConnectionManager>. Connections are opened with the
+// current connection parameters, so replacing the parameters of a ReloadableConnectParams
+// affects the connections that the pool opens afterward.
+#[derive(Debug, Clone)]
+pub struct TeradataConnectionManager {
+	params: ReloadableConnectParams,
+	validation_query: Option<String>, // executed by is_valid instead of NATIVE_SQL_VALIDATION
+}
+
+impl TeradataConnectionManager {
+	pub fn new<P: Into<ReloadableConnectParams>>(params: P) -> Self {
+		TeradataConnectionManager { params: params.into(), validation_query: None }
+	}
+
+	// Validates connections with a request to the database, such as "select 1", which also
+	// detects a database that no longer responds, at the cost of a round trip to the database
+	pub fn validation_query(mut self, request_text: &str) -> Self {
+		self.validation_query = Some(request_text.to_string());
+		self
+	}
+
+	pub fn params(&self) -> &ReloadableConnectParams {
+		&self.params
+	}
+}
+
+impl ::bb8::ManageConnection for TeradataConnectionManager {
+	type Connection = Arc<Connection>;
+	type Error = TeradataError;
+
+	async fn connect(&self) -> Result<Arc<Connection>, TeradataError> {
+		let (params, _) = self.params.current();
+		let params_json = params.to_json();
+		run_blocking(move || Connection::open(&params_json).map(Arc::new)).await
+	}
+
+	async fn is_valid(&self, conn: &mut Arc<Connection>) -> Result<(), TeradataError> {
+		let validated = Arc::clone(conn);
+		let request_text = self.validation_query.clone().unwrap_or_else(|| NATIVE_SQL_VALIDATION.to_string());
+		run_blocking(move || validated.execute(&request_text, "null").map(|_| ())).await
+	}
+
+	// A broken connection cannot be detected without a request, which is_valid performs
+	fn has_broken(&self, _conn: &mut Arc<Connection>) -> bool {
+		false
+	}
+}
@@ -8,7 +8,7 @@ fn execute_request(u_log: u64, conn_handle: u64, request_text: &str, bind_values
 	println!("request_text: {}", request_text);
 	println!("bind_values:  {}", bind_values);
 
-	let rows_handle = match teradatarustapi::rustgo_create_rows_wrapper(u_log, conn_handle, request_text, bind_values) {
+	let rows_handle = match teradatarustapi::raw::rustgo_create_rows_wrapper(u_log, conn_handle, request_text, bind_values) {
 		Ok(handle) => handle,
 		Err(err) => {
 			println!("Error from rustgo_create_rows_wrapper: {}", err);
@@ -17,7 +17,7 @@ fn execute_request(u_log: u64, conn_handle: u64, request_text: &str, bind_values
 	};
 
 	for result_num in 1.. {
-		match teradatarustapi::rustgo_result_metadata_wrapper(u_log, rows_handle) {
+		match teradatarustapi::raw::rustgo_result_metadata_wrapper(u_log, rows_handle) {
 			Ok((activity_count, activity_type, activity_name, column_metadata)) => {
 				println!("Result {} activity_count:  {}", result_num, activity_count);
 				println!("Result {} activity_type:   {}", result_num, activity_type);
@@ -31,7 +31,7 @@ fn execute_request(u_log: u64, conn_handle: u64, request_text: &str, bind_values
 		}
 
 		for row_num in 1.. {
-			match teradatarustapi::rustgo_fetch_row_wrapper(u_log, rows_handle) {
+			match teradatarustapi::raw::rustgo_fetch_row_wrapper(u_log, rows_handle) {
 				Ok(Some(row)) => {
 					println!("Result {} row {}: {}", result_num, row_num, row);
 				}
@@ -47,7 +47,7 @@ fn execute_request(u_log: u64, conn_handle: u64, request_text: &str, bind_values
 		} // end for row_num
 
 		// Advance to next result
-		match teradatarustapi::go_next_result_wrapper(u_log, rows_handle) {
+		match teradatarustapi::raw::go_next_result_wrapper(u_log, rows_handle) {
 			Ok(true) => { // another result available
 				continue;
 			}
@@ -61,7 +61,7 @@ fn execute_request(u_log: u64, conn_handle: u64, request_text: &str, bind_values
 		}
 	} // end for result_num
 
-	if let Err(err) = teradatarustapi::go_close_rows_wrapper(u_log, rows_handle) {
+	if let Err(err) = teradatarustapi::raw::go_close_rows_wrapper(u_log, rows_handle) {
 		println!("Error from go_close_rows_wrapper: {}", err);
 	}
 } // end execute_request
@@ -83,7 +83,7 @@ fn main() {
 		return;
 	}
 
-	let (u_log, conn_handle) = match teradatarustapi::create_connection(connect_params_json) {
+	let (u_log, conn_handle) = match teradatarustapi::raw::create_connection(connect_params_json) {
 		Ok((u_log, conn_handle)) => (u_log, conn_handle),
 		Err(err) => {
 			println!("Error from create_connection: {}", err);
@@ -104,7 +104,7 @@ fn main() {
 		execute_request(u_log, conn_handle, request_text, bind_values);
 	}
 
-	if let Err(err) = teradatarustapi::go_close_connection_wrapper(u_log, conn_handle) {
+	if let Err(err) = teradatarustapi::raw::go_close_connection_wrapper(u_log, conn_handle) {
 		println!("Error from go_close_connection_wrapper: {}", err);
 	}
 } // end main
@@ -0,0 +1,19 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::panic;
+
+use crate::error::TeradataError;
+
+// Runs a blocking driver call on the tokio blocking thread pool, so that it does not stall the
+// async worker threads. A panic in the call is resumed in the caller.
+pub(crate) async fn run_blocking<T, F>(f: F) -> Result<T, TeradataError>
+where
+	T: Send + 'static,
+	F: FnOnce() -> Result<T, TeradataError> + Send + 'static,
+{
+	match tokio::task::spawn_blocking(f).await {
+		Ok(result) => result,
+		Err(err) if err.is_panic() => panic::resume_unwind(err.into_panic()),
+		Err(err) => Err(TeradataError::Ffi(format!("The blocking driver call was cancelled: {}", err))),
+	}
+}
@@ -0,0 +1,178 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::result::ResultSet;
+
+#[derive(Debug, Clone)]
+pub struct ResultCacheOptions {
+	pub ttl: Duration,        // entries older than this are refetched
+	pub max_entries: usize,   // least recently used entries are evicted beyond this count
+	pub max_bytes: usize,     // least recently used entries are evicted beyond this much row JSON
+}
+
+impl Default for ResultCacheOptions {
+	fn default() -> Self {
+		ResultCacheOptions { ttl: Duration::from_secs(300), max_entries: 1000, max_bytes: 64 * 1024 * 1024 }
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResultCacheKey {
+	pub request_text: String,
+	pub bind_values: String,
+}
+
+struct CacheEntry {
+	results: Arc<Vec<ResultSet>>,
+	bytes: usize,
+	created: Instant,
+	last_used: Instant,
+}
+
+#[derive(Default)]
+struct CacheState {
+	entries: HashMap<ResultCacheKey, CacheEntry>,
+	bytes: usize,
+	hits: u64,
+	misses: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResultCacheStats {
+	pub entries: usize,
+	pub bytes: usize,
+	pub hits: u64,
+	pub misses: u64,
+}
+
+// Client-side cache of query results for read-mostly lookup queries. A ResultCache can be
+// shared by several connections; results are only as fresh as the configured TTL, so
+// applications must invalidate entries after modifying the underlying tables.
+pub struct ResultCache {
+	options: ResultCacheOptions,
+	state: Mutex<CacheState>,
+}
+
+fn result_bytes(results: &[ResultSet]) -> usize {
+	results.iter().map(|r| r.column_metadata.len() + r.activity_name.len() + r.rows.iter().map(|row| row.len()).sum::<usize>()).sum()
+}
+
+impl CacheState {
+	fn remove(&mut self, key: &ResultCacheKey) {
+		if let Some(entry) = self.entries.remove(key) {
+			self.bytes -= entry.bytes;
+		}
+	}
+
+	fn evict(&mut self, options: &ResultCacheOptions) {
+		while self.entries.len() > options.max_entries || self.bytes > options.max_bytes {
+			let oldest = self.entries.iter().min_by_key(|(_, e)| e.last_used).map(|(k, _)| k.clone());
+			match oldest {
+				Some(key) => self.remove(&key),
+				None => break,
+			}
+		}
+	}
+}
+
+impl ResultCache {
+	pub fn new(options: ResultCacheOptions) -> Self {
+		ResultCache { options, state: Mutex::new(CacheState::default()) }
+	}
+
+	pub fn options(&self) -> &ResultCacheOptions {
+		&self.options
+	}
+
+	// Returns the cached results for the request, executing the request if there is no fresh entry
+	pub fn get_or_fetch(
+		&self,
+		conn: &Connection,
+		request_text: &str,
+		bind_values: &str,
+	) -> Result<Arc<Vec<ResultSet>>, TeradataError> {
+
+		let key = ResultCacheKey { request_text: request_text.to_string(), bind_values: bind_values.to_string() };
+
+		if let Some(results) = self.get(&key) {
+			return Ok(results);
+		}
+
+		// The request is executed without holding the lock so that other lookups are not blocked
+		let results = Arc::new(conn.query_result_sets(request_text, bind_values)?);
+		self.insert(key, Arc::clone(&results));
+		Ok(results)
+
+	} // end get_or_fetch
+
+	pub fn get(&self, key: &ResultCacheKey) -> Option<Arc<Vec<ResultSet>>> {
+		let mut state = self.state.lock().unwrap();
+		let now = Instant::now();
+		let fresh = match state.entries.get_mut(key) {
+			Some(entry) if now.duration_since(entry.created) < self.options.ttl => {
+				entry.last_used = now;
+				Some(Arc::clone(&entry.results))
+			}
+			Some(_) => {
+				state.remove(key);
+				None
+			}
+			None => None,
+		};
+		if fresh.is_some() {
+			state.hits += 1;
+		} else {
+			state.misses += 1;
+		}
+		fresh
+	}
+
+	pub fn insert(&self, key: ResultCacheKey, results: Arc<Vec<ResultSet>>) {
+		let bytes = result_bytes(&results);
+		if bytes > self.options.max_bytes || self.options.max_entries == 0 {
+			return;
+		}
+		let mut state = self.state.lock().unwrap();
+		state.remove(&key);
+		let now = Instant::now();
+		state.bytes += bytes;
+		state.entries.insert(key, CacheEntry { results, bytes, created: now, last_used: now });
+		state.evict(&self.options);
+	}
+
+	// Removes every entry for the request text, regardless of bind values
+	pub fn invalidate(&self, request_text: &str) {
+		self.invalidate_where(|key| key.request_text == request_text);
+	}
+
+	// Removes every entry whose request text mentions the table name, ignoring case
+	pub fn invalidate_table(&self, table_name: &str) {
+		let table_name = table_name.to_lowercase();
+		self.invalidate_where(|key| key.request_text.to_lowercase().contains(&table_name));
+	}
+
+	// Removes every entry for which the predicate returns true
+	pub fn invalidate_where<F: Fn(&ResultCacheKey) -> bool>(&self, predicate: F) {
+		let mut state = self.state.lock().unwrap();
+		let keys: Vec<ResultCacheKey> = state.entries.keys().filter(|k| predicate(k)).cloned().collect();
+		for key in keys {
+			state.remove(&key);
+		}
+	}
+
+	pub fn clear(&self) {
+		let mut state = self.state.lock().unwrap();
+		state.entries.clear();
+		state.bytes = 0;
+	}
+
+	pub fn stats(&self) -> ResultCacheStats {
+		let state = self.state.lock().unwrap();
+		ResultCacheStats { entries: state.entries.len(), bytes: state.bytes, hits: state.hits, misses: state.misses }
+	}
+}
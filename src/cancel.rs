@@ -0,0 +1,85 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::connection::Connection;
+use crate::raw::go_cancel_request_wrapper;
+
+// Extra time allowed for the driver's own request timeout to take effect before the
+// client-side cancel is issued
+pub const CANCEL_GRACE: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+struct TimerState {
+	disarmed: bool,
+	fired: bool,
+}
+
+// Cancels the request in progress on a connection when the deadline passes, unless the
+// timer is dropped or disarmed first. Hold a CancelTimer for the lifetime of a request,
+// including the fetch loop.
+pub struct CancelTimer {
+	state: Arc<(Mutex<TimerState>, Condvar)>,
+	thread: Option<thread::JoinHandle<()>>,
+}
+
+impl CancelTimer {
+	pub fn start(conn: &Connection, timeout: Duration) -> CancelTimer {
+		Self::start_at(conn, Instant::now() + timeout)
+	}
+
+	pub fn start_at(conn: &Connection, deadline: Instant) -> CancelTimer {
+		Self::start_for_handle(conn.u_log(), conn.conn_handle(), deadline)
+	}
+
+	// Same as start_at, for the handle-based functions that cancel their own requests
+	pub(crate) fn start_for_handle(u_log: u64, conn_handle: u64, deadline: Instant) -> CancelTimer {
+		let state = Arc::new((Mutex::new(TimerState::default()), Condvar::new()));
+		let thread_state = Arc::clone(&state);
+		let thread = thread::spawn(move || {
+			let (lock, cvar) = &*thread_state;
+			let mut guard = lock.lock().unwrap();
+			while !guard.disarmed {
+				let now = Instant::now();
+				if now >= deadline {
+					guard.fired = true;
+					drop(guard);
+					let _ = go_cancel_request_wrapper(u_log, conn_handle);
+					return;
+				}
+				guard = cvar.wait_timeout(guard, deadline - now).unwrap().0;
+			}
+		});
+		CancelTimer { state, thread: Some(thread) }
+	}
+
+	// Whether the timer cancelled the request
+	pub fn fired(&self) -> bool {
+		self.state.0.lock().unwrap().fired
+	}
+
+	// Stops the timer and returns whether it cancelled the request
+	pub fn disarm(mut self) -> bool {
+		self.stop();
+		self.fired()
+	}
+
+	fn stop(&mut self) {
+		{
+			let (lock, cvar) = &*self.state;
+			lock.lock().unwrap().disarmed = true;
+			cvar.notify_all();
+		}
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+impl Drop for CancelTimer {
+	fn drop(&mut self) {
+		self.stop();
+	}
+}
@@ -0,0 +1,28 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::error::TeradataError;
+use crate::raw::{go_cancel_request_symbol, go_combine_json_symbol, GOSIDE_LIBRARY};
+
+// Optional functions exported by the loaded driver library. load_driver requires only the
+// functions that every request needs, so that one version of this crate can use a range of
+// driver library versions; the functions that older libraries lack are resolved on first use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+	pub supports_cancel: bool,       // goCancelRequest, which CancelTimer and request deadlines use to cancel a request
+	pub supports_combine_json: bool, // goCombineJSON, for go_combine_json_wrapper
+}
+
+// Returns the optional functions that the loaded driver library exports. Without goCancelRequest,
+// a request deadline cannot cancel the request, and only the driver's request_timeout applies.
+pub fn driver_capabilities() -> Result<Capabilities, TeradataError> {
+
+	if GOSIDE_LIBRARY.get().is_none() {
+		return Err(TeradataError::DriverLoad("The driver library is not loaded; call load_driver first".to_string()));
+	}
+
+	Ok(Capabilities {
+		supports_cancel: go_cancel_request_symbol().is_some(),
+		supports_combine_json: go_combine_json_symbol().is_some(),
+	})
+
+} // end driver_capabilities
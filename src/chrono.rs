@@ -0,0 +1,233 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Conversions between the temporal types of this crate and chrono, so that DATE, TIME, and
+// TIMESTAMP column values can be decoded into chrono types with FromSql, and chrono values can be
+// bound as Params. Teradata time zones are whole minutes, and fractional seconds have at most six
+// digits, so nanoseconds are truncated to microseconds when binding.
+
+use ::chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+
+use crate::from_sql::{inexact, mismatch, Coercion, FromSql};
+use crate::temporal::{Date, Time, Timestamp};
+use crate::value::Value;
+
+fn naive_date(year: i32, month: u8, day: u8) -> Result<NaiveDate, String> {
+	NaiveDate::from_ymd_opt(year, month.into(), day.into()).ok_or_else(|| format!("{:04}-{:02}-{:02} is not a valid date", year, month, day))
+}
+
+fn naive_time(hour: u8, minute: u8, second: u8, microsecond: u32) -> Result<NaiveTime, String> {
+	NaiveTime::from_hms_micro_opt(hour.into(), minute.into(), second.into(), microsecond)
+		.ok_or_else(|| format!("{:02}:{:02}:{:02}.{:06} is not a valid time", hour, minute, second, microsecond))
+}
+
+fn fixed_offset(offset_minutes: i16) -> Result<FixedOffset, String> {
+	FixedOffset::east_opt(i32::from(offset_minutes) * 60).ok_or_else(|| format!("{} minutes is not a valid time zone offset", offset_minutes))
+}
+
+// Microseconds of a chrono time; a leap second, which Teradata cannot represent, becomes the last
+// microsecond of the preceding second
+fn microsecond(time: &NaiveTime) -> u32 {
+	(time.nanosecond() / 1000).min(999_999)
+}
+
+fn time_fields(time: &NaiveTime, offset_minutes: Option<i16>) -> Time {
+	let microsecond = microsecond(time);
+	Time {
+		hour: time.hour() as u8,
+		minute: time.minute() as u8,
+		second: time.second() as u8,
+		microsecond,
+		fraction_digits: if microsecond == 0 { 0 } else { 6 },
+		offset_minutes,
+	}
+}
+
+fn timestamp_fields(date: &NaiveDate, time: &NaiveTime, offset_minutes: Option<i16>) -> Timestamp {
+	let time = time_fields(time, offset_minutes);
+	Timestamp {
+		year: date.year(),
+		month: date.month() as u8,
+		day: date.day() as u8,
+		hour: time.hour,
+		minute: time.minute,
+		second: time.second,
+		microsecond: time.microsecond,
+		fraction_digits: time.fraction_digits,
+		offset_minutes,
+	}
+}
+
+impl TryFrom<Date> for NaiveDate {
+	type Error = String;
+
+	fn try_from(date: Date) -> Result<Self, Self::Error> {
+		naive_date(date.year, date.month, date.day)
+	}
+}
+
+impl From<NaiveDate> for Date {
+	fn from(date: NaiveDate) -> Self {
+		Date { year: date.year(), month: date.month() as u8, day: date.day() as u8 }
+	}
+}
+
+// The clock time; the time zone of a TIME WITH TIME ZONE value is dropped
+impl TryFrom<Time> for NaiveTime {
+	type Error = String;
+
+	fn try_from(time: Time) -> Result<Self, Self::Error> {
+		naive_time(time.hour, time.minute, time.second, time.microsecond)
+	}
+}
+
+impl From<NaiveTime> for Time {
+	fn from(time: NaiveTime) -> Self {
+		time_fields(&time, None)
+	}
+}
+
+// The clock time and time zone of a TIME WITH TIME ZONE value
+impl TryFrom<Time> for (NaiveTime, FixedOffset) {
+	type Error = String;
+
+	fn try_from(time: Time) -> Result<Self, Self::Error> {
+		match time.offset_minutes {
+			Some(offset_minutes) => Ok((NaiveTime::try_from(time)?, fixed_offset(offset_minutes)?)),
+			None => Err(format!("TIME value {} has no time zone; use NaiveTime", time)),
+		}
+	}
+}
+
+// A TIME WITH TIME ZONE value. An offset that is not a whole number of minutes is truncated.
+impl From<(NaiveTime, FixedOffset)> for Time {
+	fn from((time, offset): (NaiveTime, FixedOffset)) -> Self {
+		time_fields(&time, Some((offset.local_minus_utc() / 60) as i16))
+	}
+}
+
+// The local date and time; the time zone of a TIMESTAMP WITH TIME ZONE value is dropped
+impl TryFrom<Timestamp> for NaiveDateTime {
+	type Error = String;
+
+	fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+		let date = naive_date(timestamp.year, timestamp.month, timestamp.day)?;
+		Ok(date.and_time(naive_time(timestamp.hour, timestamp.minute, timestamp.second, timestamp.microsecond)?))
+	}
+}
+
+impl From<NaiveDateTime> for Timestamp {
+	fn from(timestamp: NaiveDateTime) -> Self {
+		timestamp_fields(&timestamp.date(), &timestamp.time(), None)
+	}
+}
+
+impl TryFrom<Timestamp> for DateTime<FixedOffset> {
+	type Error = String;
+
+	fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+		let offset = match timestamp.offset_minutes {
+			Some(offset_minutes) => fixed_offset(offset_minutes)?,
+			None => return Err(format!("TIMESTAMP value {} has no time zone; use NaiveDateTime", timestamp)),
+		};
+		NaiveDateTime::try_from(timestamp)?
+			.and_local_timezone(offset)
+			.single()
+			.ok_or_else(|| format!("TIMESTAMP value {} is out of range", timestamp))
+	}
+}
+
+// A TIMESTAMP WITH TIME ZONE value. A value whose offset is not a whole number of minutes is
+// bound as the same instant in UTC.
+impl From<DateTime<FixedOffset>> for Timestamp {
+	fn from(timestamp: DateTime<FixedOffset>) -> Self {
+		let offset_seconds = timestamp.offset().local_minus_utc();
+		if offset_seconds % 60 != 0 {
+			return Timestamp::from(timestamp.with_timezone(&Utc));
+		}
+		let local = timestamp.naive_local();
+		timestamp_fields(&local.date(), &local.time(), Some((offset_seconds / 60) as i16))
+	}
+}
+
+impl TryFrom<Timestamp> for DateTime<Utc> {
+	type Error = String;
+
+	fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+		DateTime::<FixedOffset>::try_from(timestamp).map(|timestamp| timestamp.with_timezone(&Utc))
+	}
+}
+
+// A TIMESTAMP WITH TIME ZONE value with the time zone +00:00
+impl From<DateTime<Utc>> for Timestamp {
+	fn from(timestamp: DateTime<Utc>) -> Self {
+		let utc = timestamp.naive_utc();
+		timestamp_fields(&utc.date(), &utc.time(), Some(0))
+	}
+}
+
+impl FromSql for NaiveDate {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		Date::from_sql_with(value, coercion)?.try_into()
+	}
+}
+
+// Lenient coercion drops the time zone of a TIME WITH TIME ZONE value, and strict coercion
+// returns an error for it
+impl FromSql for NaiveTime {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		match value {
+			Value::Time(time) if coercion == Coercion::Strict && time.offset_minutes.is_some() => inexact(&value, "NaiveTime"),
+			value => Time::from_sql_with(value, coercion)?.try_into(),
+		}
+	}
+}
+
+impl FromSql for (NaiveTime, FixedOffset) {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Time(time) => time.try_into(),
+			value => mismatch(&value, "(NaiveTime, FixedOffset)"),
+		}
+	}
+}
+
+// Lenient coercion drops the time zone of a TIMESTAMP WITH TIME ZONE value, and strict coercion
+// returns an error for it
+impl FromSql for NaiveDateTime {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		match value {
+			Value::Timestamp(timestamp) if coercion == Coercion::Strict && timestamp.offset_minutes.is_some() => inexact(&value, "NaiveDateTime"),
+			value => Timestamp::from_sql(value)?.try_into(),
+		}
+	}
+}
+
+impl FromSql for DateTime<FixedOffset> {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Timestamp(timestamp) => timestamp.try_into(),
+			value => mismatch(&value, "DateTime<FixedOffset>"),
+		}
+	}
+}
+
+impl FromSql for DateTime<Utc> {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Timestamp(timestamp) => timestamp.try_into(),
+			value => mismatch(&value, "DateTime<Utc>"),
+		}
+	}
+}
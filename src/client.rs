@@ -0,0 +1,109 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::connect::create_connection;
+use crate::error::TeradataError;
+use crate::raw::go_close_connection_wrapper;
+use crate::sql::{quote_literal, validate_query_band_value};
+use crate::execute_simple_request;
+
+// Application-supplied client attributes for workload attribution. The attributes are
+// recorded in the session query band, which is shown in the QueryBand column of
+// DBC.SessionInfoV and recorded in DBQL, alongside the driver's own client attributes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientAttributes {
+	pub application_name: Option<String>,
+	pub application_version: Option<String>,
+	pub correlation_id: Option<String>,
+	pub extra: Vec<(String, String)>, // additional query band name and value pairs
+}
+
+impl ClientAttributes {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn application_name(mut self, name: &str) -> Self {
+		self.application_name = Some(name.to_string());
+		self
+	}
+
+	pub fn application_version(mut self, version: &str) -> Self {
+		self.application_version = Some(version.to_string());
+		self
+	}
+
+	pub fn correlation_id(mut self, id: &str) -> Self {
+		self.correlation_id = Some(id.to_string());
+		self
+	}
+
+	pub fn attribute(mut self, name: &str, value: &str) -> Self {
+		self.extra.push((name.to_string(), value.to_string()));
+		self
+	}
+
+	// Returns the query band name and value pairs for these attributes
+	pub fn pairs(&self) -> Vec<(&str, &str)> {
+		let mut pairs = Vec::new();
+		if let Some(name) = &self.application_name {
+			pairs.push(("ApplicationName", name.as_str()));
+		}
+		if let Some(version) = &self.application_version {
+			pairs.push(("ApplicationVersion", version.as_str()));
+		}
+		if let Some(id) = &self.correlation_id {
+			pairs.push(("CorrelationId", id.as_str()));
+		}
+		pairs.extend(self.extra.iter().map(|(n, v)| (n.as_str(), v.as_str())));
+		pairs
+	}
+
+	// Returns the query band string, such as "ApplicationName=billing;ApplicationVersion=2.1;"
+	pub fn query_band(&self) -> Result<String, TeradataError> {
+		let mut query_band = String::new();
+		for (name, value) in self.pairs() {
+			validate_query_band_value("Client attribute name", name)?;
+			validate_query_band_value(&format!("Client attribute {}", name), value)?;
+			query_band.push_str(&format!("{}={};", name.trim(), value.trim()));
+		}
+		Ok(query_band)
+	}
+}
+
+// Records client attributes in the session query band, replacing earlier values of the same
+// attributes and preserving other session query band pairs
+pub fn set_client_attributes(
+	u_log: u64,
+	conn_handle: u64,
+	attributes: &ClientAttributes,
+) -> Result<(), TeradataError> {
+
+	let query_band = attributes.query_band()?;
+	if query_band.is_empty() {
+		return Ok(());
+	}
+
+	execute_simple_request(u_log, conn_handle, &format!("set query_band = {} update for session", quote_literal(&query_band)))
+
+} // end set_client_attributes
+
+// Same as create_connection, and records the client attributes for the new session. The
+// connection is closed if the attributes cannot be recorded.
+pub fn create_connection_with_client_attributes(
+	connect_params_json: &str,
+	attributes: &ClientAttributes,
+) -> Result<(u64, u64), TeradataError> {
+
+	// Validate before connecting so that invalid attributes do not cost a logon
+	attributes.query_band()?;
+
+	let (u_log, conn_handle) = create_connection(connect_params_json)?;
+
+	if let Err(err) = set_client_attributes(u_log, conn_handle, attributes) {
+		let _ = go_close_connection_wrapper(u_log, conn_handle);
+		return Err(err.map_message(|message| format!("Could not set client attributes: {}", message)));
+	}
+
+	Ok((u_log, conn_handle))
+
+} // end create_connection_with_client_attributes
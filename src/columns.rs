@@ -0,0 +1,111 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::result::fetch_result_sets;
+use crate::sql::quote_qualified_name;
+
+// Server character set of a character column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharacterSet {
+	Latin,
+	Unicode,
+	KanjiSjis,
+	Graphic,
+	Kanji1,
+}
+
+impl CharacterSet {
+	// Maps the Char Type code reported by HELP COLUMN
+	fn from_char_type(code: i64) -> Option<CharacterSet> {
+		match code {
+			1 => Some(CharacterSet::Latin),
+			2 => Some(CharacterSet::Unicode),
+			3 => Some(CharacterSet::KanjiSjis),
+			4 => Some(CharacterSet::Graphic),
+			5 => Some(CharacterSet::Kanji1),
+			_ => None,
+		}
+	}
+
+	pub fn name(&self) -> &'static str {
+		match self {
+			CharacterSet::Latin => "LATIN",
+			CharacterSet::Unicode => "UNICODE",
+			CharacterSet::KanjiSjis => "KANJISJIS",
+			CharacterSet::Graphic => "GRAPHIC",
+			CharacterSet::Kanji1 => "KANJI1",
+		}
+	}
+}
+
+// Character set, case, and FORMAT attributes of a table or view column. Export tools can use
+// the FORMAT string to render values the way BTEQ does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnFormat {
+	pub name: String,
+	pub type_code: String,                   // data type code reported by HELP COLUMN, such as "CV" or "I"
+	pub character_set: Option<CharacterSet>, // None for non-character columns
+	pub case_specific: Option<bool>,         // None for non-character columns
+	pub uppercase: bool,                     // whether the column has the UPPERCASE attribute
+	pub format: Option<String>,              // FORMAT string, such as "X(100)" or "-(10)9"
+}
+
+fn value_str(row: &[Value], index: Option<usize>) -> Option<String> {
+	match index.and_then(|i| row.get(i)) {
+		Some(Value::String(s)) if !s.trim().is_empty() => Some(s.trim().to_string()),
+		Some(Value::Number(n)) => Some(n.to_string()),
+		_ => None,
+	}
+}
+
+// Returns the character set, case, and FORMAT attributes of every column of a table or view,
+// such as "db.tab", using HELP COLUMN
+pub fn column_formats(
+	u_log: u64,
+	conn_handle: u64,
+	table_name: &str,
+) -> Result<Vec<ColumnFormat>, TeradataError> {
+
+	let request_text = format!("help column {}.*", quote_qualified_name(table_name));
+	let mut columns = Vec::new();
+
+	for result in fetch_result_sets(u_log, conn_handle, &request_text, "null")? {
+		let name_index = match result.column_index("Column Name")? {
+			Some(index) => index,
+			None => continue,
+		};
+		let type_index = result.column_index("Type")?;
+		let format_index = result.column_index("Format")?;
+		let char_type_index = result.column_index("Char Type")?;
+		let uppercase_index = result.column_index("Uppercase")?;
+
+		for row in &result.rows {
+			let row: Vec<Value> = match serde_json::from_str(row) {
+				Ok(row) => row,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse HELP COLUMN row JSON: {}", err)));
+				}
+			};
+			let name = match value_str(&row, Some(name_index)) {
+				Some(name) => name,
+				None => continue,
+			};
+			let character_set = value_str(&row, char_type_index).and_then(|s| s.parse().ok()).and_then(CharacterSet::from_char_type);
+			// Uppercase is N (neither), U (UPPERCASE), C (CASESPECIFIC), or B (both)
+			let case_flag = value_str(&row, uppercase_index).unwrap_or_default().to_uppercase();
+			columns.push(ColumnFormat {
+				name,
+				type_code: value_str(&row, type_index).unwrap_or_default(),
+				case_specific: character_set.map(|_| case_flag == "C" || case_flag == "B"),
+				uppercase: case_flag == "U" || case_flag == "B",
+				character_set,
+				format: value_str(&row, format_index),
+			});
+		}
+	}
+
+	Ok(columns)
+
+} // end column_formats
@@ -0,0 +1,243 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::cmp::Ordering;
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::result::ColumnInfo;
+use crate::row::Row;
+use crate::row_hash::{canonical_number, RowHashOptions, RowHasher};
+use crate::rows::Rows;
+use crate::sql::{quote_identifier, quote_qualified_name};
+use crate::value::Value;
+
+// Default number of keys recorded for each kind of difference
+pub const DEFAULT_MAX_REPORTED_ROWS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareOptions {
+	pub max_reported_rows: usize, // keys recorded for each kind of difference; every difference is counted
+	pub hash: RowHashOptions,     // normalization of the compared values; its columns field is ignored
+}
+
+impl Default for CompareOptions {
+	fn default() -> Self {
+		CompareOptions { max_reported_rows: DEFAULT_MAX_REPORTED_ROWS, hash: RowHashOptions::default() }
+	}
+}
+
+// Outcome of compare_tables. Side A is the reference: a missing row exists only on side A and
+// an extra row exists only on side B.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableComparison {
+	pub rows_a: u64,
+	pub rows_b: u64,
+	pub matching_count: u64,
+	pub missing_count: u64,
+	pub extra_count: u64,
+	pub different_count: u64,       // rows with the same key and different non-key values
+	pub missing: Vec<Vec<Value>>,   // key values of the first missing rows
+	pub extra: Vec<Vec<Value>>,     // key values of the first extra rows
+	pub different: Vec<Vec<Value>>, // key values of the first different rows
+}
+
+impl TableComparison {
+	pub fn is_identical(&self) -> bool {
+		self.missing_count == 0 && self.extra_count == 0 && self.different_count == 0
+	}
+}
+
+// Compares decimal digits by value
+fn compare_numbers(a: &str, b: &str) -> Ordering {
+	let (a, b) = (canonical_number(a), canonical_number(b));
+	let (a_negative, b_negative) = (a.starts_with('-'), b.starts_with('-'));
+	if a_negative != b_negative {
+		return if a_negative { Ordering::Less } else { Ordering::Greater };
+	}
+	let (a_whole, a_fraction) = a.trim_start_matches('-').split_once('.').unwrap_or((a.trim_start_matches('-'), ""));
+	let (b_whole, b_fraction) = b.trim_start_matches('-').split_once('.').unwrap_or((b.trim_start_matches('-'), ""));
+	let magnitude = a_whole.len().cmp(&b_whole.len()).then_with(|| a_whole.cmp(b_whole)).then_with(|| a_fraction.cmp(b_fraction));
+	if a_negative { magnitude.reverse() } else { magnitude }
+}
+
+fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+	match (a, b) {
+		(Value::Null, Value::Null) => Some(Ordering::Equal),
+		(Value::Null, _) => Some(Ordering::Less),
+		(_, Value::Null) => Some(Ordering::Greater),
+		(Value::Integer(x), Value::Integer(y)) => Some(x.cmp(y)),
+		(Value::Integer(_) | Value::Decimal(_), Value::Integer(_) | Value::Decimal(_)) => {
+			let text = |v: &Value| match v {
+				Value::Integer(n) => n.to_string(),
+				Value::Decimal(s) => s.clone(),
+				_ => unreachable!(),
+			};
+			Some(compare_numbers(&text(a), &text(b)))
+		}
+		(Value::Float(x), Value::Float(y)) => x.partial_cmp(y),
+		(Value::Varchar(x), Value::Varchar(y)) => Some(x.trim_end_matches(' ').cmp(y.trim_end_matches(' '))),
+		(Value::Bytes(x), Value::Bytes(y)) => Some(x.cmp(y)),
+		(Value::Date(x), Value::Date(y)) => Some(x.cmp(y)),
+		(Value::Time(x), Value::Time(y)) => Some(x.format("%H:%M:%S.%f").cmp(&y.format("%H:%M:%S.%f"))),
+		(Value::Timestamp(x), Value::Timestamp(y)) => Some(x.format("%Y-%m-%d %H:%M:%S.%f").cmp(&y.format("%Y-%m-%d %H:%M:%S.%f"))),
+		_ => None,
+	}
+}
+
+fn compare_keys(a: &[Value], b: &[Value]) -> Result<Ordering, TeradataError> {
+	for (x, y) in a.iter().zip(b) {
+		match compare_values(x, y) {
+			Some(Ordering::Equal) => continue,
+			Some(ordering) => return Ok(ordering),
+			None => return Err(TeradataError::InvalidArgument(format!("Key values {:?} and {:?} cannot be compared", x, y))),
+		}
+	}
+	Ok(Ordering::Equal)
+}
+
+// One side of a comparison: a cursor over the rows in key order
+struct Side<'conn> {
+	name: &'static str,
+	rows: Rows<'conn>,
+	columns: Vec<ColumnInfo>,
+	key_indexes: Vec<usize>,
+	hasher: RowHasher,
+	previous_key: Option<Vec<Value>>,
+	count: u64,
+}
+
+impl<'conn> Side<'conn> {
+	fn open(name: &'static str, conn: &'conn Connection, request_text: &str, key_columns: &[&str], hash: &RowHashOptions) -> Result<Side<'conn>, TeradataError> {
+		let mut rows = conn.query(request_text, "null")?;
+		let columns = rows.columns()?.to_vec();
+		let mut key_indexes = Vec::with_capacity(key_columns.len());
+		for key in key_columns {
+			match columns.iter().position(|column| column.name.eq_ignore_ascii_case(key)) {
+				Some(index) => key_indexes.push(index),
+				None => {
+					return Err(TeradataError::InvalidArgument(format!("Side {} has no key column {}", name, key)));
+				}
+			}
+		}
+		let value_columns: Vec<&str> = columns.iter().enumerate().filter(|(index, _)| !key_indexes.contains(index)).map(|(_, column)| column.name.as_str()).collect();
+		let hasher = RowHasher::new(&columns, hash.clone().columns(&value_columns))?;
+		Ok(Side { name, rows, columns, key_indexes, hasher, previous_key: None, count: 0 })
+	}
+
+	// Fetches the next row and returns its key values and the hash of its other values
+	fn next(&mut self) -> Result<Option<(Vec<Value>, u64)>, TeradataError> {
+		let row: Row = match self.rows.fetch()? {
+			Some(row) => row,
+			None => return Ok(None),
+		};
+		let key = self.key_indexes.iter().map(|&index| row.get_value(index, &self.columns[index])).collect::<Result<Vec<Value>, String>>().map_err(TeradataError::Conversion)?;
+		if let Some(previous) = &self.previous_key && compare_keys(previous, &key)? != Ordering::Less {
+			return Err(TeradataError::InvalidArgument(format!(
+				"Rows of side {} are not unique and ascending by key at {:?}; the key must be unique, and ordered the same by the database and by value",
+				self.name, key
+			)));
+		}
+		let hash = self.hasher.hash_row(&row)?;
+		self.previous_key = Some(key.clone());
+		self.count += 1;
+		Ok(Some((key, hash)))
+	}
+}
+
+fn record(keys: &mut Vec<Vec<Value>>, count: &mut u64, key: Vec<Value>, max: usize) {
+	*count += 1;
+	if keys.len() < max {
+		keys.push(key);
+	}
+}
+
+// Same as compare_tables, with options
+pub fn compare_tables_with_options(
+	conn_a: &Connection,
+	conn_b: &Connection,
+	source: &str,
+	key_columns: &[&str],
+	options: &CompareOptions,
+) -> Result<TableComparison, TeradataError> {
+
+	if key_columns.is_empty() {
+		return Err(TeradataError::InvalidArgument("At least one key column is required".to_string()));
+	}
+
+	let source = source.trim();
+	let first_word = source.split_whitespace().next().unwrap_or_default().to_ascii_lowercase();
+	let from = if matches!(first_word.as_str(), "select" | "sel" | "with" | "(") {
+		format!("({}) as compared_rows", source.trim_end_matches(';'))
+	} else {
+		quote_qualified_name(source)
+	};
+	let order_by = key_columns.iter().map(|key| quote_identifier(key)).collect::<Vec<_>>().join(", ");
+	let request_text = format!("select * from {} order by {}", from, order_by);
+
+	let mut a = Side::open("A", conn_a, &request_text, key_columns, &options.hash)?;
+	let mut b = Side::open("B", conn_b, &request_text, key_columns, &options.hash)?;
+	let names = |side: &Side| side.columns.iter().map(|column| column.name.to_ascii_uppercase()).collect::<Vec<_>>();
+	if names(&a) != names(&b) {
+		return Err(TeradataError::InvalidArgument(format!("Sides have different columns: {:?} and {:?}", names(&a), names(&b))));
+	}
+
+	let max = options.max_reported_rows;
+	let mut result = TableComparison::default();
+	let mut next_a = a.next()?;
+	let mut next_b = b.next()?;
+	loop {
+		match (next_a.take(), next_b.take()) {
+			(None, None) => break,
+			(Some((key, _)), None) => {
+				record(&mut result.missing, &mut result.missing_count, key, max);
+				next_a = a.next()?;
+			}
+			(None, Some((key, _))) => {
+				record(&mut result.extra, &mut result.extra_count, key, max);
+				next_b = b.next()?;
+			}
+			(Some((key_a, hash_a)), Some((key_b, hash_b))) => match compare_keys(&key_a, &key_b)? {
+				Ordering::Less => {
+					record(&mut result.missing, &mut result.missing_count, key_a, max);
+					next_a = a.next()?;
+					next_b = Some((key_b, hash_b));
+				}
+				Ordering::Greater => {
+					record(&mut result.extra, &mut result.extra_count, key_b, max);
+					next_a = Some((key_a, hash_a));
+					next_b = b.next()?;
+				}
+				Ordering::Equal => {
+					if hash_a == hash_b {
+						result.matching_count += 1;
+					} else {
+						record(&mut result.different, &mut result.different_count, key_a, max);
+					}
+					next_a = a.next()?;
+					next_b = b.next()?;
+				}
+			},
+		}
+	}
+
+	result.rows_a = a.count;
+	result.rows_b = b.count;
+	Ok(result)
+
+} // end compare_tables_with_options
+
+// Compares the rows of a table, or of a query, on two connections, such as the source and
+// target systems of a migration. Both sides are fetched in key order and merged one row at a
+// time, so memory use does not grow with the number of rows. Rows are matched by the key
+// columns, which must be unique, and the other column values are compared by their RowHasher
+// hash.
+pub fn compare_tables(
+	conn_a: &Connection,
+	conn_b: &Connection,
+	source: &str,
+	key_columns: &[&str],
+) -> Result<TableComparison, TeradataError> {
+
+	compare_tables_with_options(conn_a, conn_b, source, key_columns, &CompareOptions::default())
+
+} // end compare_tables
@@ -0,0 +1,193 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::backtrace::Backtrace;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::connect_params::{combine_connect_params, parse_connect_params};
+use crate::error::TeradataError;
+use crate::logging::SharedLog;
+use crate::options::whole_seconds;
+use crate::query_band;
+use crate::raw::{go_close_connection_wrapper, go_create_connection_wrapper, go_parse_params_wrapper};
+
+// Prefix of the error message returned when a deadline passes before an operation completes
+pub const TIMEOUT_ERROR_PREFIX: &str = "Timeout:";
+
+// Whether an error message reports an exceeded timeout or deadline rather than a failure
+pub fn is_timeout_error(message: &str) -> bool {
+	message.starts_with(TIMEOUT_ERROR_PREFIX) || message.starts_with("Request deadline exceeded")
+}
+
+// Same as create_connection, with an overall deadline covering COP name resolution, TLS
+// negotiation, and logon. The deadline is also passed to the driver as the logon_timeout
+// connection parameter, and caps the connect_timeout for each TCP connection attempt. If the
+// driver has not returned when the deadline passes, TeradataError::Timeout is returned without
+// waiting further, and a connection that the driver establishes afterward is closed in the
+// background.
+pub fn create_connection_with_timeout(
+	connect_params_json: &str,
+	timeout: Duration,
+) -> Result<(u64, u64), TeradataError> {
+
+	create_session_with_timeout(connect_params_json, timeout, None)
+
+} // end create_connection_with_timeout
+
+// Same as create_connection_with_timeout, using the log bitmask of a SharedLog instead of parsing
+// the connection parameters, the same as create_connection_with_log
+pub fn create_connection_with_log_and_timeout(
+	connect_params_json: &str,
+	shared_log: &SharedLog,
+	timeout: Duration,
+) -> Result<(u64, u64), TeradataError> {
+
+	create_session_with_timeout(connect_params_json, timeout, Some(*shared_log))
+
+} // end create_connection_with_log_and_timeout
+
+fn create_session_with_timeout(
+	connect_params_json: &str,
+	timeout: Duration,
+	shared_log: Option<SharedLog>,
+) -> Result<(u64, u64), TeradataError> {
+
+	let deadline = Instant::now() + timeout;
+
+	let connect_timeout_ms = timeout.as_millis().max(1) as u64;
+	let configured_ms = parse_connect_params(connect_params_json).map_err(TeradataError::InvalidArgument)?.into_iter()
+		.find(|(name, _)| name == "connect_timeout")
+		.and_then(|(_, value)| value.parse::<u64>().ok())
+		.filter(|&ms| ms > 0);
+	let limits = serde_json::json!({
+		"connect_timeout": configured_ms.map_or(connect_timeout_ms, |ms| ms.min(connect_timeout_ms)).to_string(),
+		"logon_timeout": whole_seconds(timeout).max(1).to_string(),
+	});
+	let connect_params_json = combine_connect_params(connect_params_json, &limits.to_string()).map_err(TeradataError::InvalidArgument)?;
+
+	let (sender, receiver) = mpsc::channel();
+	let spawned = thread::Builder::new().name("teradata-connect".to_string()).spawn(move || {
+		let result = create_session(&connect_params_json, shared_log.as_ref());
+		if let Err(mpsc::SendError(Ok((u_log, conn_handle)))) = sender.send(result) {
+			// The caller gave up waiting, so nobody owns this connection
+			let _ = go_close_connection_wrapper(u_log, conn_handle);
+		}
+	});
+	if let Err(err) = spawned {
+		return Err(TeradataError::Ffi(format!("Could not start connection thread: {}", err)));
+	}
+
+	match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+		Ok(result) => result,
+		Err(mpsc::RecvTimeoutError::Timeout) => Err(TeradataError::Timeout(format!("{} connection was not established within {:?}", TIMEOUT_ERROR_PREFIX, timeout))),
+		Err(mpsc::RecvTimeoutError::Disconnected) => Err(TeradataError::Ffi("Connection thread ended without a result".to_string())),
+	}
+
+} // end create_session_with_timeout
+
+pub fn create_connection(
+	connect_params_json: &str,
+) -> Result<(u64, u64), TeradataError> {
+
+	create_session(connect_params_json, None)
+
+} // end create_connection
+
+// Same as create_connection, using the log bitmask of a SharedLog instead of parsing the
+// connection parameters with go_parse_params_wrapper. The returned u_log is the SharedLog's.
+pub fn create_connection_with_log(
+	connect_params_json: &str,
+	shared_log: &SharedLog,
+) -> Result<(u64, u64), TeradataError> {
+
+	create_session(connect_params_json, Some(shared_log))
+
+} // end create_connection_with_log
+
+// Connects, parsing the connection parameters for the log bitmask unless a SharedLog is given
+pub(crate) fn create_session(
+	connect_params_json: &str,
+	shared_log: Option<&SharedLog>,
+) -> Result<(u64, u64), TeradataError> {
+
+	// Backtrace::capture() captures a backtrace of the current OS thread according to the environment variable RUST_BACKTRACE
+	// If RUST_BACKTRACE is not set, then Backtrace::capture() returns a disabled backtrace
+	// Backtrace::force_capture() always forcibly captures a backtrace regardless of the RUST_BACKTRACE setting
+	let stack_trace = Backtrace::force_capture();
+	let stack_trace_str = format!("{}", stack_trace);
+
+	// Regular expression to trim leading number and colon if present
+	let re = regex::Regex::new(r"^\d+:\s*").unwrap();
+
+	let mut abbrev_stack_trace_str = String::new();
+	for line in stack_trace_str.lines() {
+		// Trim leading and trailing whitespace
+		let trimmed_line = line.trim();
+		let trimmed_line = re.replace(trimmed_line, "").to_string();
+
+		// Replace all backslashes with forward slashes
+		let trimmed_line = trimmed_line.replace("\\", "/");
+
+		if trimmed_line == "std::rt::lang_start_internal" {
+			break;
+		}
+		if trimmed_line.starts_with("std::") || trimmed_line.starts_with("core::") {
+			continue;
+		}
+
+		// Trim "at " prefix if present
+		let trimmed_line = if let Some(stripped) = trimmed_line.strip_prefix("at ") {
+			stripped.to_string()
+		} else {
+			trimmed_line
+		};
+
+		// Skip if trimmed_line contains /library/std/src/ or /library/core/src/
+		if trimmed_line.contains("/library/std/src/") || trimmed_line.contains("/library/core/src/") {
+			continue;
+		}
+
+		if !abbrev_stack_trace_str.is_empty() {
+			abbrev_stack_trace_str.insert(0, ' ');
+		}
+
+		abbrev_stack_trace_str.insert_str(0, &trimmed_line);
+	}
+
+	let mut map = HashMap::new();
+	map.insert("client_kind", "U");
+	map.insert("client_stack", &abbrev_stack_trace_str);
+	let json_str = serde_json::to_string(&map).unwrap();
+
+	// Validate and merge in Rust so that malformed parameters are reported before any call into the driver
+	let combined_json = combine_connect_params(connect_params_json, json_str.as_str()).map_err(TeradataError::InvalidArgument)?;
+
+	// Call go_parse_params_wrapper with the result from go_combine_json_wrapper, unless the log bitmask is shared
+	let u_log = match shared_log {
+		Some(shared_log) => shared_log.u_log(),
+		None => match go_parse_params_wrapper(combined_json.as_str()) {
+			Ok(u_log) => u_log,
+			Err(err) => {
+				return Err(TeradataError::from_driver("go_parse_params_wrapper", err));
+			}
+		},
+	};
+
+	let version_str = ""; // omit to use GoSQL Driver version
+	let conn_handle = match go_create_connection_wrapper(u_log, version_str, combined_json.as_str()) {
+		Ok(handle) => handle,
+		Err(err) => {
+			return Err(TeradataError::from_driver("go_create_connection_wrapper", err));
+		}
+	};
+
+	if let Err(err) = query_band::on_connect(u_log, conn_handle) {
+		let _ = go_close_connection_wrapper(u_log, conn_handle);
+		return Err(err.map_message(|message| format!("Could not set the automatic query band: {}", message)));
+	}
+
+	Ok((u_log, conn_handle))
+
+} // end create_session
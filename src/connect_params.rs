@@ -0,0 +1,265 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde_json::{Map, Value};
+
+use crate::options::whole_seconds;
+
+// Typed builder for the connection parameter JSON string passed to create_connection.
+// Parameters without a typed setter can be specified with set().
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectParams {
+	params: Map<String, Value>,
+}
+
+impl ConnectParams {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	// Starts from an existing connection parameter JSON string
+	pub fn from_json(connect_params_json: &str) -> Result<Self, String> {
+		let params = parse_connect_params(connect_params_json)?;
+		Ok(ConnectParams { params: params.into_iter().map(|(name, value)| (name, Value::String(value))).collect() })
+	}
+
+	// Sets a connection parameter to a string value
+	pub fn set(mut self, name: &str, value: &str) -> Self {
+		self.params.insert(name.to_string(), Value::String(value.to_string()));
+		self
+	}
+
+	pub fn get(&self, name: &str) -> Option<&str> {
+		self.params.get(name).and_then(|v| v.as_str())
+	}
+
+	pub fn remove(mut self, name: &str) -> Self {
+		self.params.remove(name);
+		self
+	}
+
+	pub fn host(self, host: &str) -> Self {
+		self.set("host", host)
+	}
+
+	pub fn user(self, user: &str) -> Self {
+		self.set("user", user)
+	}
+
+	pub fn password(self, password: &str) -> Self {
+		self.set("password", password)
+	}
+
+	pub fn database(self, database: &str) -> Self {
+		self.set("database", database)
+	}
+
+	// Database partition, such as "DBC/SQL" (the default) or "MONITOR"
+	pub fn partition(self, partition: &str) -> Self {
+		self.set("partition", partition)
+	}
+
+	pub fn logmech(self, logmech: &str) -> Self {
+		self.set("logmech", logmech)
+	}
+
+	// Redrive level; level 2 or higher enables Recoverable Network Protocol and Redrive
+	// when the database is configured for them. See Session Reconnect in README.md.
+	pub fn redrive(self, level: u8) -> Self {
+		self.set("redrive", &level.to_string())
+	}
+
+	// Number of Session Reconnect attempts
+	pub fn reconnect_count(self, count: u32) -> Self {
+		self.set("reconnect_count", &count.to_string())
+	}
+
+	// Seconds between Session Reconnect attempts
+	pub fn reconnect_interval(self, seconds: u32) -> Self {
+		self.set("reconnect_interval", &seconds.to_string())
+	}
+
+	// Timeout for establishing each TCP socket connection, in milliseconds; zero means no timeout
+	pub fn connect_timeout(self, timeout: Duration) -> Self {
+		self.set("connect_timeout", &timeout.as_millis().to_string())
+	}
+
+	// Timeout for logging on, rounded up to whole seconds; zero means no timeout
+	pub fn logon_timeout(self, timeout: Duration) -> Self {
+		self.set("logon_timeout", &whole_seconds(timeout).to_string())
+	}
+
+	// Default timeout for executing each SQL request, rounded up to whole seconds.
+	// Individual requests can override it with QueryOptions::timeout.
+	pub fn request_timeout(self, timeout: Duration) -> Self {
+		self.set("request_timeout", &whole_seconds(timeout).to_string())
+	}
+
+	// Controls LOB support for the connection; when false, BLOB and CLOB columns cannot be fetched
+	pub fn lob_support(self, enabled: bool) -> Self {
+		self.set("lob_support", if enabled { "true" } else { "false" })
+	}
+
+	// Returns the connection parameter JSON string for create_connection
+	pub fn to_json(&self) -> String {
+		Value::Object(self.params.clone()).to_string()
+	}
+}
+
+// Connection parameters whose values must be quoted integers, such as "8"
+const QUOTED_INTEGER_PARAMS: &[&str] = &[
+	"browser_tab_timeout", "browser_timeout", "concurrent_interval", "concurrent_limit", "connect_failure_ttl",
+	"connect_function", "connect_timeout", "dbs_port", "error_query_count", "error_query_interval",
+	"gateway_deadline", "https_port", "https_retry", "log", "logon_sequence_number", "logon_timeout",
+	"max_message_body", "oauth_level", "oidc_cache_size", "oidc_metadata_cache", "oidc_refresh_percent",
+	"request_timeout", "sessions",
+];
+
+// Connection parameters whose values must be quoted booleans, "true" or "false"
+const QUOTED_BOOLEAN_PARAMS: &[&str] = &[
+	"column_name", "cop", "coplast", "encryptdata", "fake_result_sets", "govern", "lob_support",
+	"local_catalog", "manage_error_tables", "oidc_refresh", "runstartup", "sip_support", "sp_spl",
+	"sslcrl", "sslocsp", "teradata_values",
+];
+
+// JSON object that rejects duplicate names and keeps the names in their original order
+struct StrictObject(Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for StrictObject {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct StrictObjectVisitor;
+
+		impl<'de> Visitor<'de> for StrictObjectVisitor {
+			type Value = StrictObject;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				write!(f, "a JSON object of connection parameters")
+			}
+
+			fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<StrictObject, A::Error> {
+				let mut entries: Vec<(String, Value)> = Vec::new();
+				while let Some((name, value)) = map.next_entry::<String, Value>()? {
+					if entries.iter().any(|(n, _)| *n == name) {
+						return Err(de::Error::custom(format!("duplicate connection parameter \"{}\"", name)));
+					}
+					entries.push((name, value));
+				}
+				Ok(StrictObject(entries))
+			}
+		}
+
+		deserializer.deserialize_map(StrictObjectVisitor)
+	}
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+	match value {
+		Value::Null => "null",
+		Value::Bool(_) => "boolean",
+		Value::Number(_) => "number",
+		Value::String(_) => "string",
+		Value::Array(_) => "array",
+		Value::Object(_) => "object",
+	}
+}
+
+// Parses a connection parameter JSON string, reporting invalid JSON, duplicate parameter
+// names, and values that are not strings or do not match the parameter's quoted type
+pub(crate) fn parse_connect_params(connect_params_json: &str) -> Result<Vec<(String, String)>, String> {
+	let StrictObject(entries) = match serde_json::from_str::<StrictObject>(connect_params_json) {
+		Ok(object) => object,
+		Err(err) => {
+			return Err(format!("Invalid connection parameters JSON: {}", err));
+		}
+	};
+
+	let mut params = Vec::with_capacity(entries.len());
+	for (name, value) in entries {
+		let value = match value {
+			Value::String(s) => s,
+			other => {
+				return Err(format!("Connection parameter \"{}\" must be a JSON string, such as \"{}\", not a JSON {}", name, other, json_type_name(&other)));
+			}
+		};
+		if QUOTED_INTEGER_PARAMS.contains(&name.as_str()) && value.trim().parse::<i64>().is_err() {
+			return Err(format!("Connection parameter \"{}\" must be a quoted integer, not \"{}\"", name, value));
+		}
+		if QUOTED_BOOLEAN_PARAMS.contains(&name.as_str()) && !value.eq_ignore_ascii_case("true") && !value.eq_ignore_ascii_case("false") {
+			return Err(format!("Connection parameter \"{}\" must be a quoted boolean, \"true\" or \"false\", not \"{}\"", name, value));
+		}
+		params.push((name, value));
+	}
+	Ok(params)
+}
+
+// Merges two connection parameter JSON strings after validating both. Parameters in
+// json2 replace parameters of the same name in json1.
+pub fn combine_connect_params(json1: &str, json2: &str) -> Result<String, String> {
+	let mut params = parse_connect_params(json1)?;
+	for (name, value) in parse_connect_params(json2)? {
+		match params.iter_mut().find(|(n, _)| *n == name) {
+			Some(param) => param.1 = value,
+			None => params.push((name, value)),
+		}
+	}
+
+	let members: Vec<String> = params.iter().map(|(name, value)| format!("{}:{}", Value::String(name.clone()), Value::String(value.clone()))).collect();
+	Ok(format!("{{{}}}", members.join(",")))
+}
+
+// Connection parameters that can be replaced while connections are in use, such as to rotate a
+// password or change the host list without restarting a service. Each replacement starts a new
+// generation; connections record the generation they were established with, so that idle
+// connections of an older generation can be closed while connections in use finish their work.
+// Cloning shares the parameters.
+#[derive(Debug, Clone)]
+pub struct ReloadableConnectParams {
+	current: Arc<RwLock<(Arc<ConnectParams>, u64)>>,
+}
+
+impl ReloadableConnectParams {
+	pub fn new(params: ConnectParams) -> Self {
+		ReloadableConnectParams { current: Arc::new(RwLock::new((Arc::new(params), 1))) }
+	}
+
+	// Returns the current parameters and their generation, for establishing a new connection
+	pub fn current(&self) -> (Arc<ConnectParams>, u64) {
+		let current = self.current.read().unwrap();
+		(Arc::clone(&current.0), current.1)
+	}
+
+	pub fn generation(&self) -> u64 {
+		self.current.read().unwrap().1
+	}
+
+	// Whether a connection established with the generation uses the current parameters
+	pub fn is_current(&self, generation: u64) -> bool {
+		self.generation() == generation
+	}
+
+	// Atomically replaces the parameters and returns the new generation. Connections established
+	// afterward use the new parameters; existing connections are unaffected.
+	pub fn replace(&self, params: ConnectParams) -> u64 {
+		let mut current = self.current.write().unwrap();
+		*current = (Arc::new(params), current.1 + 1);
+		current.1
+	}
+
+	// Atomically applies a change to the current parameters, such as a new password, and
+	// returns the new generation
+	pub fn update<F: FnOnce(ConnectParams) -> ConnectParams>(&self, f: F) -> u64 {
+		let mut current = self.current.write().unwrap();
+		*current = (Arc::new(f((*current.0).clone())), current.1 + 1);
+		current.1
+	}
+}
+
+impl From<ConnectParams> for ReloadableConnectParams {
+	fn from(params: ConnectParams) -> Self {
+		ReloadableConnectParams::new(params)
+	}
+}
@@ -0,0 +1,538 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::batch::{execute_many_binding, BatchSummary, DEFAULT_BATCH_CHUNK_SIZE};
+use crate::client::{create_connection_with_client_attributes, set_client_attributes, ClientAttributes};
+use crate::columns::{column_formats, ColumnFormat};
+use crate::connect::{create_connection, create_connection_with_log, create_connection_with_log_and_timeout, create_connection_with_timeout};
+use crate::connect_params::ConnectParams;
+use crate::correlation::set_correlation_id;
+use crate::ddl::{execute_ddl, get_warnings, DdlResult};
+use crate::error::TeradataError;
+use crate::explain::{explain, ExplainPlan};
+use crate::help::{help_column, help_index, help_session, help_table, HelpColumn, HelpIndex, HelpSession};
+use crate::introspect::{native_sql, show_qualified};
+use crate::limiter::{RequestWorkload, WorkloadLimiter, WorkloadPermit};
+use crate::logging::{driver_log_level, set_driver_log_level, LogLevel, SharedLog};
+use crate::macros::exec_macro;
+use crate::options::QueryOptions;
+use crate::params::{bind_json, BindValues, ToParams};
+use crate::profile::{connect_with_profile, SessionProfile};
+use crate::proxy::{clear_proxy_user, set_proxy_user};
+use crate::query_band;
+use crate::raw::{go_close_connection_wrapper, go_close_rows_wrapper, go_next_result_wrapper, rustgo_create_rows_wrapper, rustgo_result_metadata_wrapper};
+use crate::redrive::{connection_endpoint, execute_detecting_redrive, redrive_status, ConnectionEndpoint, RedriveStatus};
+use crate::result::{fetch_result_sets, fetch_result_sets_with_options, fetch_selected_result_sets, prefetch_metadata, ResultMetadata, ResultSet};
+use crate::retry::{execute_with_retry, Idempotency, RetryPolicy};
+use crate::row::Row;
+use crate::rows::Rows;
+use crate::session::{current_database, server_time, session_info, set_database, set_time_zone, time_zone, SessionInfo, SessionTimeZone, TimeZone};
+use crate::settings::{restore_settings, snapshot_settings, SessionSettings};
+use crate::sql::count_parameters;
+use crate::statement::{PreparedRequest, Statement};
+use crate::statement_cache::{StatementCache, StatementCacheStats, DEFAULT_STATEMENT_CACHE_CAPACITY};
+use crate::temporal::Timestamp;
+use crate::transaction::{autocommit, commit, rollback, set_autocommit, Transaction};
+use crate::udt::{describe_udt, UdtType};
+use crate::version::driver_version;
+
+// An open connection that owns its (u_log, conn_handle) pair and closes the connection when
+// dropped. The handles remain available for the free functions of this crate.
+#[derive(Debug)]
+pub struct Connection {
+	u_log: AtomicU64,                         // changed by set_driver_log_level
+	conn_handle: u64,
+	statement_cache: Mutex<StatementCache>,   // requests prepared by prepare, by request text
+	database_changed: AtomicBool,             // set_database was called since restore_settings
+	in_transaction: AtomicBool,               // a Transaction guard is active
+	workload: Mutex<Option<RequestWorkload>>, // limiter from which every request takes a permit
+	closed: bool,
+}
+
+impl Connection {
+	// Connects using a connection parameter JSON string, the same as create_connection
+	pub fn open(connect_params_json: &str) -> Result<Connection, TeradataError> {
+		let (u_log, conn_handle) = create_connection(connect_params_json)?;
+		Ok(Connection::from_handles(u_log, conn_handle))
+	}
+
+	// Connects with an overall deadline, the same as create_connection_with_timeout
+	pub fn open_with_timeout(connect_params_json: &str, timeout: Duration) -> Result<Connection, TeradataError> {
+		let (u_log, conn_handle) = create_connection_with_timeout(connect_params_json, timeout)?;
+		Ok(Connection::from_handles(u_log, conn_handle))
+	}
+
+	// Connects using the log bitmask of a SharedLog, the same as create_connection_with_log
+	pub fn open_with_log(connect_params_json: &str, shared_log: &SharedLog) -> Result<Connection, TeradataError> {
+		let (u_log, conn_handle) = create_connection_with_log(connect_params_json, shared_log)?;
+		Ok(Connection::from_handles(u_log, conn_handle))
+	}
+
+	// Connects with an overall deadline using the log bitmask of a SharedLog, the same as
+	// create_connection_with_log_and_timeout
+	pub fn open_with_log_and_timeout(connect_params_json: &str, shared_log: &SharedLog, timeout: Duration) -> Result<Connection, TeradataError> {
+		let (u_log, conn_handle) = create_connection_with_log_and_timeout(connect_params_json, shared_log, timeout)?;
+		Ok(Connection::from_handles(u_log, conn_handle))
+	}
+
+	// Connects and records client attributes for the new session, the same as
+	// create_connection_with_client_attributes
+	pub fn open_with_client_attributes(connect_params_json: &str, attributes: &ClientAttributes) -> Result<Connection, TeradataError> {
+		let (u_log, conn_handle) = create_connection_with_client_attributes(connect_params_json, attributes)?;
+		Ok(Connection::from_handles(u_log, conn_handle))
+	}
+
+	// Connects with a session profile in effect, the same as connect_with_profile
+	pub fn open_with_profile(params: &ConnectParams, profile: &SessionProfile) -> Result<Connection, TeradataError> {
+		let (u_log, conn_handle) = connect_with_profile(params, profile)?;
+		Ok(Connection::from_handles(u_log, conn_handle))
+	}
+
+	// Takes ownership of handles returned by create_connection; the connection is closed when
+	// the Connection is dropped
+	pub fn from_handles(u_log: u64, conn_handle: u64) -> Connection {
+		Connection { u_log: AtomicU64::new(u_log), conn_handle, statement_cache: Mutex::new(StatementCache::new(DEFAULT_STATEMENT_CACHE_CAPACITY)), database_changed: AtomicBool::new(false), in_transaction: AtomicBool::new(false), workload: Mutex::new(None), closed: false }
+	}
+
+	pub fn u_log(&self) -> u64 {
+		self.u_log.load(Ordering::Relaxed)
+	}
+
+	pub fn conn_handle(&self) -> u64 {
+		self.conn_handle
+	}
+
+	// Gives up ownership of the handles without closing the connection
+	pub fn into_handles(mut self) -> (u64, u64) {
+		self.closed = true;
+		(self.u_log(), self.conn_handle)
+	}
+
+	// Executes a request without fetching rows and returns the sum of the activity counts of
+	// its results, such as the number of rows inserted or updated. The bind values are a JSON
+	// bind values string, such as "null", or rows of ToParams values, such as a
+	// Vec<(i32, String)> or a slice of structs with #[derive(ToParams)]. A request that binds
+	// byte values is prepared through the statement cache, which converts the parameter markers
+	// of its binary parameters from base64, and fails if it cannot be prepared.
+	pub fn execute<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<u64, TeradataError> {
+		let (bind_values, bytes) = bind_json(bind_values).map_err(TeradataError::InvalidArgument)?;
+		self.execute_json(request_text, &bind_values, bytes)
+	}
+
+	// Executes a request with a JSON bind values string, converting the parameter markers of
+	// binary parameters from base64 if bytes reports that the bind values hold byte values
+	pub(crate) fn execute_json(&self, request_text: &str, bind_values: &str, bytes: bool) -> Result<u64, TeradataError> {
+		self.execute_bound(&self.bound_request_text(request_text, bytes)?, bind_values)
+	}
+
+	// Executes request text that needs no conversion of its bind values, such as the text of a
+	// prepared request, and returns the sum of the activity counts of its results
+	pub(crate) fn execute_bound(&self, request_text: &str, bind_values: &str) -> Result<u64, TeradataError> {
+		let _permit = self.before_request()?;
+		let rows_handle = match rustgo_create_rows_wrapper(self.u_log(), self.conn_handle, request_text, bind_values) {
+			Ok(handle) => handle,
+			Err(err) => {
+				return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
+			}
+		};
+
+		let mut activity_count = 0;
+		let result = loop {
+			match rustgo_result_metadata_wrapper(self.u_log(), rows_handle) {
+				Ok((count, _, _, _)) => activity_count += count,
+				Err(err) => break Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err)),
+			}
+			match go_next_result_wrapper(self.u_log(), rows_handle) {
+				Ok(true) => continue,
+				Ok(false) => break Ok(activity_count),
+				Err(err) => break Err(TeradataError::from_driver("go_next_result_wrapper", err)),
+			}
+		};
+
+		if let Err(err) = go_close_rows_wrapper(self.u_log(), rows_handle) && result.is_ok() {
+			return Err(TeradataError::from_driver("go_close_rows_wrapper", err));
+		}
+
+		result
+	}
+
+	// Executes a request for every row of bind values produced by an iterator, in chunks of
+	// DEFAULT_BATCH_CHUNK_SIZE rows. A failed chunk does not stop the remaining chunks; the
+	// BatchSummary reports the failed chunks and the rows that could not be converted.
+	pub fn execute_many<I>(&self, request_text: &str, rows: I) -> Result<BatchSummary, TeradataError>
+	where
+		I: IntoIterator,
+		I::Item: ToParams,
+	{
+		self.execute_many_with_chunk_size(request_text, rows, DEFAULT_BATCH_CHUNK_SIZE)
+	}
+
+	// Same as execute_many, binding chunk_size rows to each request
+	pub fn execute_many_with_chunk_size<I>(&self, request_text: &str, rows: I, chunk_size: usize) -> Result<BatchSummary, TeradataError>
+	where
+		I: IntoIterator,
+		I::Item: ToParams,
+	{
+		let _permit = self.before_request()?;
+		execute_many_binding(self.u_log(), self.conn_handle, request_text, rows, chunk_size, || self.bound_request_text(request_text, true))
+	}
+
+	// Executes a request and returns a cursor over its results, which is closed when dropped.
+	// The bind values are the same as for execute, and the request is prepared the same way.
+	pub fn query<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<Rows<'_>, TeradataError> {
+		let (bind_values, bytes) = bind_json(bind_values).map_err(TeradataError::InvalidArgument)?;
+		Rows::open_json(self, request_text, &bind_values, bytes)
+	}
+
+	// Executes a request and fetches every result set into memory
+	pub fn query_result_sets(&self, request_text: &str, bind_values: &str) -> Result<Vec<ResultSet>, TeradataError> {
+		let _permit = self.before_request()?;
+		fetch_result_sets(self.u_log(), self.conn_handle, request_text, bind_values)
+	}
+
+	// Executes a request and fetches every row of its first result
+	pub fn fetch_all<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<Vec<Row>, TeradataError> {
+		self.query(request_text, bind_values)?.fetch_all()
+	}
+
+	// Executes a request and fetches the only row of its first result. Returns
+	// TeradataError::RowCount if the result has no rows or more than one row.
+	pub fn fetch_one<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<Row, TeradataError> {
+		self.query(request_text, bind_values)?.fetch_one()
+	}
+
+	// Executes a request and fetches the only row of its first result, or None if the result
+	// has no rows. Returns TeradataError::RowCount if the result has more than one row.
+	pub fn fetch_optional<P: BindValues + ?Sized>(&self, request_text: &str, bind_values: &P) -> Result<Option<Row>, TeradataError> {
+		self.query(request_text, bind_values)?.fetch_optional()
+	}
+
+	// Same as query_result_sets, with a timeout, deadline, LOB mode, or correlation ID for this request
+	pub fn query_result_sets_with_options(&self, request_text: &str, bind_values: &str, options: &QueryOptions) -> Result<Vec<ResultSet>, TeradataError> {
+		let _permit = self.before_request()?;
+		fetch_result_sets_with_options(self.u_log(), self.conn_handle, request_text, bind_values, options)
+	}
+
+	// Runs EXPLAIN for a request, without executing it, and parses the plan
+	pub fn explain(&self, request_text: &str) -> Result<ExplainPlan, TeradataError> {
+		let _permit = self.before_request()?;
+		explain(self.u_log(), self.conn_handle, request_text)
+	}
+
+	// Executes a macro, binding params to its parameters in order, and returns one result set
+	// per statement in the macro
+	pub fn exec_macro<P: ToParams>(&self, macro_name: &str, params: P) -> Result<Vec<ResultSet>, TeradataError> {
+		let _permit = self.before_request()?;
+		exec_macro(self.u_log(), self.conn_handle, macro_name, params)
+	}
+
+	// Returns the metadata of every result of a request without executing it
+	pub fn prefetch_metadata(&self, request_text: &str) -> Result<Vec<ResultMetadata>, TeradataError> {
+		prefetch_metadata(self.u_log(), self.conn_handle, request_text)
+	}
+
+	// Executes a request and fetches only the results for which select returns true, given the
+	// zero-based result index and the result metadata. Returns one entry per result, None for
+	// each skipped result.
+	pub fn query_selected_result_sets<F: FnMut(usize, &ResultMetadata) -> bool>(&self, request_text: &str, bind_values: &str, select: F) -> Result<Vec<Option<ResultSet>>, TeradataError> {
+		let _permit = self.before_request()?;
+		fetch_selected_result_sets(self.u_log(), self.conn_handle, request_text, bind_values, select)
+	}
+
+	// Executes a request, retrying failed attempts under the policy if the request is marked
+	// Idempotent. Returns the results and the number of attempts made.
+	pub fn execute_with_retry(&self, request_text: &str, bind_values: &str, idempotency: Idempotency, request_id: Option<&str>, policy: &RetryPolicy) -> Result<(Vec<ResultSet>, u32), TeradataError> {
+		let _permit = self.before_request()?;
+		execute_with_retry(self.u_log(), self.conn_handle, request_text, bind_values, idempotency, request_id, policy)
+	}
+
+	// Executes a request and reports whether it was transparently redriven after a reconnect
+	pub fn execute_detecting_redrive(&self, request_text: &str, bind_values: &str) -> Result<(Vec<ResultSet>, bool), TeradataError> {
+		let _permit = self.before_request()?;
+		execute_detecting_redrive(self.u_log(), self.conn_handle, request_text, bind_values)
+	}
+
+	// Executes a DDL command and collects the warnings and SPL compilation messages of its
+	// successful response
+	pub fn execute_ddl(&self, request_text: &str) -> Result<DdlResult, TeradataError> {
+		let _permit = self.before_request()?;
+		execute_ddl(self.u_log(), self.conn_handle, request_text)
+	}
+
+	// Returns the warnings from the most recent operation that completed with warnings
+	pub fn warnings(&self) -> Result<Vec<String>, TeradataError> {
+		get_warnings(self.u_log(), self.conn_handle)
+	}
+
+	// Returns the request text that the driver sends to the database after processing escape
+	// syntax, without executing the request
+	pub fn native_sql(&self, request_text: &str) -> Result<String, TeradataError> {
+		native_sql(self.u_log(), self.conn_handle, request_text)
+	}
+
+	// Returns the qualified DDL of every object that a request references, using SHOW QUALIFIED
+	pub fn show_qualified(&self, request_text: &str) -> Result<Vec<String>, TeradataError> {
+		show_qualified(self.u_log(), self.conn_handle, request_text)
+	}
+
+	// Takes a permit for the request from the workload set with set_workload, waiting at most its
+	// timeout, then records the calling thread's trace ID in the session query band when
+	// automatic query banding is enabled with trace IDs. The permit must be held until the
+	// request and its rows are processed.
+	pub(crate) fn before_request(&self) -> Result<Option<WorkloadPermit>, TeradataError> {
+		let workload = self.workload.lock().unwrap().clone();
+		let permit = workload.map(|workload| workload.acquire()).transpose()?;
+		query_band::before_request(self.u_log(), self.conn_handle)?;
+		Ok(permit)
+	}
+
+	// Limits the requests of this connection by workload tag: every later request takes a permit
+	// for the tag from the limiter, waiting at most the timeout, and fails with
+	// TeradataError::Timeout if none becomes available. A Rows holds its permit until closed.
+	pub fn set_workload(&self, limiter: &WorkloadLimiter, tag: &str, timeout: Duration) {
+		*self.workload.lock().unwrap() = Some(RequestWorkload { limiter: limiter.clone(), tag: tag.to_string(), timeout });
+	}
+
+	// Stops limiting the requests of this connection
+	pub fn clear_workload(&self) {
+		*self.workload.lock().unwrap() = None;
+	}
+
+	// Workload tag set with set_workload, if any
+	pub fn workload_tag(&self) -> Option<String> {
+		self.workload.lock().unwrap().as_ref().map(|workload| workload.tag.clone())
+	}
+
+	// Prepares a request without executing it, returning a Statement with the metadata of its
+	// parameters and results. Requests are cached by request text, so preparing the same text
+	// again returns the cached metadata without a round trip to the database.
+	pub fn prepare(&self, request_text: &str) -> Result<Statement<'_>, TeradataError> {
+		Ok(Statement::from_prepared(self, self.prepared(request_text)?))
+	}
+
+	// Returns the prepared request for the request text from the statement cache, preparing it
+	// and adding it to the cache if it is not cached
+	fn prepared(&self, request_text: &str) -> Result<Arc<PreparedRequest>, TeradataError> {
+		if let Some(prepared) = self.statement_cache.lock().unwrap().get(request_text) {
+			return Ok(prepared);
+		}
+		let prepared = Arc::new(PreparedRequest::prepare(self, request_text)?);
+		self.statement_cache.lock().unwrap().insert(request_text, Arc::clone(&prepared));
+		Ok(prepared)
+	}
+
+	// Returns the request text to execute with bind values that hold byte values, if bytes is
+	// true, which is the text of the prepared request with the parameter markers of binary
+	// parameters converted from base64. Only such requests are prepared, through the statement
+	// cache, or every time they are executed while the cache is disabled, and a request that
+	// cannot be prepared returns the error. Other requests are executed as given.
+	pub(crate) fn bound_request_text(&self, request_text: &str, bytes: bool) -> Result<String, TeradataError> {
+		if !bytes || count_parameters(request_text) == 0 {
+			return Ok(request_text.to_string());
+		}
+		Ok(self.prepared(request_text)?.bound_text().to_string())
+	}
+
+	// Number of prepared requests the statement cache holds, DEFAULT_STATEMENT_CACHE_CAPACITY
+	// unless changed
+	pub fn statement_cache_capacity(&self) -> usize {
+		self.statement_cache.lock().unwrap().capacity()
+	}
+
+	// Changes the number of prepared requests the statement cache holds, evicting the least
+	// recently used requests that no longer fit; zero disables the cache
+	pub fn set_statement_cache_capacity(&self, capacity: usize) {
+		self.statement_cache.lock().unwrap().set_capacity(capacity);
+	}
+
+	// Removes every prepared request from the statement cache, such as after DDL changes the
+	// tables the requests refer to
+	pub fn clear_statement_cache(&self) {
+		self.statement_cache.lock().unwrap().clear();
+	}
+
+	pub fn statement_cache_stats(&self) -> StatementCacheStats {
+		self.statement_cache.lock().unwrap().stats()
+	}
+
+	pub fn commit(&self) -> Result<(), TeradataError> {
+		commit(self.u_log(), self.conn_handle)
+	}
+
+	pub fn rollback(&self) -> Result<(), TeradataError> {
+		rollback(self.u_log(), self.conn_handle)
+	}
+
+	pub fn set_autocommit(&self, autocommit: bool) -> Result<(), TeradataError> {
+		set_autocommit(self.u_log(), self.conn_handle, autocommit)
+	}
+
+	// Returns whether autocommit is on, such as to restore it after changing it
+	pub fn autocommit(&self) -> Result<bool, TeradataError> {
+		autocommit(self.u_log(), self.conn_handle)
+	}
+
+	// Turns autocommit off and returns a Transaction guard, which rolls back the transaction if
+	// dropped without commit. Teradata has no savepoints, so beginning a transaction while
+	// another is in progress on the connection returns TeradataError::InvalidArgument.
+	pub fn begin(&self) -> Result<Transaction<'_>, TeradataError> {
+		Transaction::begin(self)
+	}
+
+	// Marks a transaction as in progress, returning false if one already was
+	pub(crate) fn enter_transaction(&self) -> bool {
+		!self.in_transaction.swap(true, Ordering::Relaxed)
+	}
+
+	pub(crate) fn leave_transaction(&self) {
+		self.in_transaction.store(false, Ordering::Relaxed);
+	}
+
+	// Makes a database the session's default database. The statement cache is cleared, because
+	// unqualified names in cached requests may now refer to different objects.
+	pub fn set_database(&self, database: &str) -> Result<(), TeradataError> {
+		set_database(self.u_log(), self.conn_handle, database)?;
+		self.clear_statement_cache();
+		self.database_changed.store(true, Ordering::Relaxed);
+		Ok(())
+	}
+
+	// Returns the session's default database
+	pub fn current_database(&self) -> Result<String, TeradataError> {
+		current_database(self.u_log(), self.conn_handle)
+	}
+
+	// Returns the session number, user, default database, character set, and transaction mode
+	pub fn session_info(&self) -> Result<SessionInfo, TeradataError> {
+		session_info(self.u_log(), self.conn_handle)
+	}
+
+	// Returns the server's CURRENT_TIMESTAMP, with the session time zone offset
+	pub fn server_time(&self) -> Result<Timestamp, TeradataError> {
+		server_time(self.u_log(), self.conn_handle)
+	}
+
+	// Sets the session time zone, such as "America Central" or TimeZone::Offset(-300)
+	pub fn set_time_zone<Z: Into<TimeZone>>(&self, zone: Z) -> Result<(), TeradataError> {
+		set_time_zone(self.u_log(), self.conn_handle, zone)
+	}
+
+	// Returns the session's current time zone offset, and its name for a named time zone
+	pub fn time_zone(&self) -> Result<SessionTimeZone, TeradataError> {
+		time_zone(self.u_log(), self.conn_handle)
+	}
+
+	// Changes the driver log level of this connection, such as to turn on verbose tracing for
+	// one suspect connection. Later requests on the connection use the new log bitmask.
+	pub fn set_driver_log_level(&self, level: LogLevel) -> Result<(), TeradataError> {
+		let u_log = set_driver_log_level(self.u_log(), self.conn_handle, level)?;
+		self.u_log.store(u_log, Ordering::Relaxed);
+		Ok(())
+	}
+
+	pub fn driver_log_level(&self) -> Result<LogLevel, TeradataError> {
+		driver_log_level(self.u_log(), self.conn_handle)
+	}
+
+	// Captures the autocommit mode, session query band, time zone, and default database
+	pub fn snapshot_settings(&self) -> Result<SessionSettings, TeradataError> {
+		snapshot_settings(self.u_log(), self.conn_handle)
+	}
+
+	// Reapplies settings captured by snapshot_settings. If set_database was called since the
+	// last restore, the statement cache is cleared like set_database does.
+	pub fn restore_settings(&self, settings: &SessionSettings) -> Result<(), TeradataError> {
+		restore_settings(self.u_log(), self.conn_handle, settings)?;
+		if self.database_changed.swap(false, Ordering::Relaxed) {
+			self.clear_statement_cache();
+		}
+		Ok(())
+	}
+
+	// Makes the session act on behalf of an end user, and optionally a role, until
+	// clear_proxy_user is called
+	pub fn set_proxy_user(&self, user: &str, role: Option<&str>) -> Result<(), TeradataError> {
+		set_proxy_user(self.u_log(), self.conn_handle, user, role)
+	}
+
+	// Ends any proxy user and role, preserving the other session query band pairs
+	pub fn clear_proxy_user(&self) -> Result<(), TeradataError> {
+		clear_proxy_user(self.u_log(), self.conn_handle)
+	}
+
+	// Records a correlation ID in the session query band, so that later requests are logged in
+	// DBQL with the ID
+	pub fn set_correlation_id(&self, correlation_id: &str) -> Result<(), TeradataError> {
+		set_correlation_id(self.u_log(), self.conn_handle, correlation_id)
+	}
+
+	// Records client attributes in the session query band
+	pub fn set_client_attributes(&self, attributes: &ClientAttributes) -> Result<(), TeradataError> {
+		set_client_attributes(self.u_log(), self.conn_handle, attributes)
+	}
+
+	// Returns whether Redrive and Recoverable Network Protocol are active for the session
+	pub fn redrive_status(&self) -> Result<RedriveStatus, TeradataError> {
+		redrive_status(self.u_log(), self.conn_handle)
+	}
+
+	// Returns the local address and port of the TCP socket currently used by the connection
+	pub fn connection_endpoint(&self) -> Result<ConnectionEndpoint, TeradataError> {
+		connection_endpoint(self.u_log(), self.conn_handle)
+	}
+
+	// Returns the GoSQL driver version, and remembers it for subsequent calls to version()
+	pub fn driver_version(&self) -> Result<String, TeradataError> {
+		driver_version(self.u_log(), self.conn_handle)
+	}
+
+	// Returns the columns of a table or view, such as "db.tab", using HELP TABLE
+	pub fn help_table(&self, table_name: &str) -> Result<Vec<HelpColumn>, TeradataError> {
+		help_table(self.u_log(), self.conn_handle, table_name)
+	}
+
+	// Returns one column of a table or view using HELP COLUMN, or None if the column does not exist
+	pub fn help_column(&self, table_name: &str, column_name: &str) -> Result<Option<HelpColumn>, TeradataError> {
+		help_column(self.u_log(), self.conn_handle, table_name, column_name)
+	}
+
+	// Returns the indexes of a table using HELP INDEX
+	pub fn help_index(&self, table_name: &str) -> Result<Vec<HelpIndex>, TeradataError> {
+		help_index(self.u_log(), self.conn_handle, table_name)
+	}
+
+	// Returns the attributes of the current session using HELP SESSION
+	pub fn help_session(&self) -> Result<HelpSession, TeradataError> {
+		help_session(self.u_log(), self.conn_handle)
+	}
+
+	// Returns the character set, case, and FORMAT attributes of every column of a table or view
+	pub fn column_formats(&self, table_name: &str) -> Result<Vec<ColumnFormat>, TeradataError> {
+		column_formats(self.u_log(), self.conn_handle, table_name)
+	}
+
+	// Returns the kind and attribute metadata of a structured or distinct UDT
+	pub fn describe_udt(&self, type_name: &str) -> Result<UdtType, TeradataError> {
+		describe_udt(self.u_log(), self.conn_handle, type_name)
+	}
+
+	// Closes the connection, returning any error that dropping the Connection would ignore
+	pub fn close(mut self) -> Result<(), TeradataError> {
+		self.closed = true;
+		match go_close_connection_wrapper(self.u_log(), self.conn_handle) {
+			Ok(()) => Ok(()),
+			Err(err) => Err(TeradataError::from_driver("go_close_connection_wrapper", err)),
+		}
+	}
+}
+
+impl Drop for Connection {
+	fn drop(&mut self) {
+		if !self.closed {
+			let _ = go_close_connection_wrapper(self.u_log(), self.conn_handle);
+		}
+	}
+}
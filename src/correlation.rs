@@ -0,0 +1,57 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::process;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::TeradataError;
+use crate::execute_simple_request;
+use crate::query_band::forget;
+use crate::sql::{quote_literal, validate_query_band_value};
+
+// Query band name under which correlation IDs are recorded, so that DBQL records can be found
+// with QueryBand LIKE '%CorrelationId=<id>;%'
+pub const CORRELATION_ID_NAME: &str = "CorrelationId";
+
+// How a request is tagged with a correlation ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Correlation {
+	Generate,   // a new correlation ID is generated for every request
+	Id(String), // the application's own ID, such as the trace ID of the calling operation
+}
+
+impl Correlation {
+	// Returns the correlation ID to record for a request
+	pub fn id(&self) -> String {
+		match self {
+			Correlation::Generate => new_correlation_id(),
+			Correlation::Id(id) => id.trim().to_string(),
+		}
+	}
+}
+
+// Returns a new correlation ID, unique across the processes of a host and the requests of a
+// process, such as "18a3f0c2b7e41d20-3e8f-2a"
+pub fn new_correlation_id() -> String {
+	static COUNTER: AtomicU32 = AtomicU32::new(0);
+	let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0);
+	let sequence = COUNTER.fetch_add(1, Ordering::Relaxed);
+	format!("{:x}-{:x}-{:x}", nanos, process::id(), sequence)
+}
+
+// Records a correlation ID in the session query band as CorrelationId, replacing any previous
+// correlation ID and keeping the other query band pairs. Every later request of the session is
+// logged in DBQL with the ID until another one is set.
+pub fn set_correlation_id(
+	u_log: u64,
+	conn_handle: u64,
+	correlation_id: &str,
+) -> Result<(), TeradataError> {
+
+	validate_query_band_value("Correlation id", correlation_id)?;
+	// Whatever trace ID was recorded automatically is replaced
+	forget(conn_handle);
+	let query_band = format!("{}={};", CORRELATION_ID_NAME, correlation_id.trim());
+	execute_simple_request(u_log, conn_handle, &format!("set query_band = {} update for session", quote_literal(&query_band)))
+
+} // end set_correlation_id
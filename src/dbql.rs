@@ -0,0 +1,216 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Query history from the Database Query Log (DBQL). The logon user needs SELECT access to the
+// DBC.QryLogV and DBC.QryLogSQLV views, and query logging must be enabled for the users whose
+// queries are requested.
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::result::fetch_result_sets;
+use crate::temporal::Timestamp;
+
+// Default maximum number of records returned by query_log
+pub const DEFAULT_DBQL_LIMIT: u32 = 1000;
+
+// Selects the queries returned by query_log; None matches every query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbqlFilter {
+	pub user_name: Option<String>,
+	pub query_band: Option<(String, String)>, // query band name and value, such as ("ApplicationName", "billing")
+	pub session_id: Option<u32>,
+	pub start: Option<Timestamp>,             // queries that started at or after this time
+	pub end: Option<Timestamp>,               // queries that started before this time
+	pub limit: u32,                           // maximum number of records, most recent first
+}
+
+impl Default for DbqlFilter {
+	fn default() -> Self {
+		DbqlFilter { user_name: None, query_band: None, session_id: None, start: None, end: None, limit: DEFAULT_DBQL_LIMIT }
+	}
+}
+
+impl DbqlFilter {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn user_name(mut self, user_name: &str) -> Self {
+		self.user_name = Some(user_name.to_string());
+		self
+	}
+
+	pub fn query_band(mut self, name: &str, value: &str) -> Self {
+		self.query_band = Some((name.to_string(), value.to_string()));
+		self
+	}
+
+	pub fn session_id(mut self, session_id: u32) -> Self {
+		self.session_id = Some(session_id);
+		self
+	}
+
+	pub fn time_range(mut self, start: Option<Timestamp>, end: Option<Timestamp>) -> Self {
+		self.start = start;
+		self.end = end;
+		self
+	}
+
+	pub fn limit(mut self, limit: u32) -> Self {
+		self.limit = limit;
+		self
+	}
+
+	// Returns the WHERE clause conditions and their bind values
+	fn conditions(&self) -> (Vec<&'static str>, Vec<Value>) {
+		let mut conditions = Vec::new();
+		let mut values = Vec::new();
+		if let Some(user_name) = &self.user_name {
+			conditions.push("UserName = ?");
+			values.push(Value::from(user_name.as_str()));
+		}
+		if let Some((name, value)) = &self.query_band {
+			// Query band pairs are stored as "Name=Value;" and the position test avoids LIKE wildcards
+			conditions.push("position(? in ';' || QueryBand) > 0");
+			values.push(Value::from(format!(";{}={};", name.trim(), value.trim())));
+		}
+		if let Some(session_id) = self.session_id {
+			conditions.push("SessionID = ?");
+			values.push(Value::from(session_id));
+		}
+		if let Some(start) = &self.start {
+			conditions.push("StartTime >= cast(? as timestamp(6))");
+			values.push(Value::from(start.to_string()));
+		}
+		if let Some(end) = &self.end {
+			conditions.push("StartTime < cast(? as timestamp(6))");
+			values.push(Value::from(end.to_string()));
+		}
+		(conditions, values)
+	}
+}
+
+// One logged query from DBC.QryLogV
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryLogRecord {
+	pub query_id: String,
+	pub session_id: Option<u32>,
+	pub user_name: Option<String>,
+	pub start_time: Option<Timestamp>,
+	pub first_response_time: Option<Timestamp>,
+	pub query_band: Option<String>,
+	pub statement_type: Option<String>, // such as "Select" or "Insert"
+	pub amp_cpu_seconds: Option<f64>,
+	pub total_io_count: Option<f64>,
+	pub result_rows: Option<f64>,
+	pub spool_bytes: Option<f64>,
+	pub error_code: Option<u32>,        // None or 0 for a successful query
+	pub query_text: Option<String>,     // first characters of the request text; see query_sql_text for the full text
+}
+
+const QUERY_LOG_COLUMNS: &str = "QueryID, SessionID, UserName, StartTime, FirstRespTime, QueryBand, StatementType, \
+	AMPCPUTime, TotalIOCount, NumResultRows, SpoolUsage, ErrorCode, QueryText";
+
+fn value_string(value: &Value) -> Option<String> {
+	match value {
+		Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+		Value::Number(n) => Some(n.to_string()),
+		_ => None,
+	}
+}
+
+fn value_f64(value: &Value) -> Option<f64> {
+	match value {
+		Value::Number(n) => n.as_f64(),
+		Value::String(s) => s.trim().parse().ok(),
+		_ => None,
+	}
+}
+
+impl QueryLogRecord {
+	// Builds a record from a row with the columns in QUERY_LOG_COLUMNS order
+	fn from_row(row: &[Value]) -> Result<Self, TeradataError> {
+		if row.len() < 13 {
+			return Err(TeradataError::Conversion(format!("Expected 13 DBQL columns but found {}", row.len())));
+		}
+		Ok(QueryLogRecord {
+			query_id: value_string(&row[0]).unwrap_or_default(),
+			session_id: value_f64(&row[1]).map(|n| n as u32),
+			user_name: value_string(&row[2]),
+			start_time: value_string(&row[3]).map(|s| s.parse()).transpose().map_err(TeradataError::Conversion)?,
+			first_response_time: value_string(&row[4]).map(|s| s.parse()).transpose().map_err(TeradataError::Conversion)?,
+			query_band: value_string(&row[5]),
+			statement_type: value_string(&row[6]),
+			amp_cpu_seconds: value_f64(&row[7]),
+			total_io_count: value_f64(&row[8]),
+			result_rows: value_f64(&row[9]),
+			spool_bytes: value_f64(&row[10]),
+			error_code: value_f64(&row[11]).map(|n| n as u32),
+			query_text: value_string(&row[12]),
+		})
+	}
+}
+
+// Returns the logged queries selected by the filter, most recent first
+pub fn query_log(
+	u_log: u64,
+	conn_handle: u64,
+	filter: &DbqlFilter,
+) -> Result<Vec<QueryLogRecord>, TeradataError> {
+
+	let (conditions, values) = filter.conditions();
+	let mut request_text = format!("select top {} {} from DBC.QryLogV", filter.limit, QUERY_LOG_COLUMNS);
+	if !conditions.is_empty() {
+		request_text.push_str(" where ");
+		request_text.push_str(&conditions.join(" and "));
+	}
+	request_text.push_str(" order by StartTime desc");
+
+	let bind_values = if values.is_empty() { "null".to_string() } else { Value::Array(vec![Value::Array(values)]).to_string() };
+
+	let mut records = Vec::new();
+	for result in fetch_result_sets(u_log, conn_handle, &request_text, &bind_values)? {
+		for row in &result.rows {
+			let row: Vec<Value> = match serde_json::from_str(row) {
+				Ok(row) => row,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse DBQL row JSON: {}", err)));
+				}
+			};
+			records.push(QueryLogRecord::from_row(&row)?);
+		}
+	}
+
+	Ok(records)
+
+} // end query_log
+
+// Returns the full request text of a logged query from DBC.QryLogSQLV, or None if SQL text
+// logging was not enabled for the query
+pub fn query_sql_text(
+	u_log: u64,
+	conn_handle: u64,
+	query_id: &str,
+) -> Result<Option<String>, TeradataError> {
+
+	let request_text = "select SqlTextInfo from DBC.QryLogSQLV where QueryID = cast(? as decimal(18,0)) order by SqlRowNo";
+	let bind_values = Value::Array(vec![Value::Array(vec![Value::from(query_id)])]).to_string();
+
+	let mut text: Option<String> = None;
+	for result in fetch_result_sets(u_log, conn_handle, request_text, &bind_values)? {
+		for row in &result.rows {
+			let row: Vec<Value> = match serde_json::from_str(row) {
+				Ok(row) => row,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse DBQL row JSON: {}", err)));
+				}
+			};
+			if let Some(Value::String(s)) = row.first() {
+				text.get_or_insert_with(String::new).push_str(s);
+			}
+		}
+	}
+
+	Ok(text)
+
+} // end query_sql_text
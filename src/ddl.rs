@@ -0,0 +1,67 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::result::{fetch_result_sets, ResultSet};
+use crate::session::query_scalar_string;
+
+// Outcome of a DDL command such as CREATE PROCEDURE or REPLACE MACRO
+#[derive(Debug, Clone, Default)]
+pub struct DdlResult {
+	pub results: Vec<ResultSet>,
+	pub warnings: Vec<String>,         // warnings reported for the command, one per line
+	pub compile_messages: Vec<String>, // SPL compilation messages returned in result set rows
+}
+
+impl DdlResult {
+	pub fn has_warnings(&self) -> bool {
+		!self.warnings.is_empty() || !self.compile_messages.is_empty()
+	}
+}
+
+// Returns the warnings from the most recent operation that completed with warnings
+pub fn get_warnings(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<Vec<String>, TeradataError> {
+
+	let warnings = query_scalar_string(u_log, conn_handle, &Escape::GetWarnings.native())?.unwrap_or_default();
+	Ok(warnings.lines().map(str::trim).filter(|line| !line.is_empty()).map(str::to_string).collect())
+
+} // end get_warnings
+
+// Executes a DDL command and collects the warnings and SPL compilation messages that
+// accompany a successful response, so deployment tooling can fail builds on warnings
+pub fn execute_ddl(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<DdlResult, TeradataError> {
+
+	let results = fetch_result_sets(u_log, conn_handle, request_text, "null")?;
+
+	let mut compile_messages = Vec::new();
+	for result in &results {
+		for row in &result.rows {
+			let values: Vec<serde_json::Value> = match serde_json::from_str(row) {
+				Ok(values) => values,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse row JSON: {}", err)));
+				}
+			};
+			let message = values.iter().map(|v| match v {
+				serde_json::Value::String(s) => s.trim().to_string(),
+				serde_json::Value::Null => String::new(),
+				other => other.to_string(),
+			}).filter(|s| !s.is_empty()).collect::<Vec<_>>().join(" ");
+			if !message.is_empty() {
+				compile_messages.push(message);
+			}
+		}
+	}
+
+	let warnings = get_warnings(u_log, conn_handle)?;
+
+	Ok(DdlResult { results, warnings, compile_messages })
+
+} // end execute_ddl
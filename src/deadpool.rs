@@ -0,0 +1,124 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Manager for the deadpool async connection pool, so that async applications built on deadpool
+// can pool connections. The driver calls block, so the manager runs them on the tokio blocking
+// thread pool, and connections are pooled as Arc<Connection> so that an application can do the
+// same with the connections it borrows.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ::deadpool::managed::{self, Metrics, RecycleError, RecycleResult};
+
+use crate::blocking::run_blocking;
+use crate::connect_params::ReloadableConnectParams;
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::pool::{DEFAULT_VALIDATION_QUERY, NATIVE_SQL_VALIDATION};
+
+pub type Pool = managed::Pool<TeradataManager>;
+pub type PoolBuilder = managed::PoolBuilder<TeradataManager>;
+pub type PoolError = managed::PoolError<TeradataError>;
+pub type Object = managed::Object<TeradataManager>;
+pub type Hook = managed::Hook<TeradataManager>;
+
+// Request that recycle executes to check a connection before it is handed out again
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RecyclingMethod {
+	#[default]
+	Fast,           // NATIVE_SQL_VALIDATION, answered by the driver without a round trip to the database
+	Verified,       // DEFAULT_VALIDATION_QUERY, which also detects a database that stopped responding
+	Custom(String), // the request text
+}
+
+impl RecyclingMethod {
+	pub fn request_text(&self) -> &str {
+		match self {
+			RecyclingMethod::Fast => NATIVE_SQL_VALIDATION,
+			RecyclingMethod::Verified => DEFAULT_VALIDATION_QUERY,
+			RecyclingMethod::Custom(request_text) => request_text,
+		}
+	}
+}
+
+type RecycleCheck = Arc<dyn Fn(&Connection, &Metrics) -> Result<(), String> + Send + Sync>;
+
+// Opens connections for a deadpool Pool, and checks them when they are recycled: connections
+// older than the max_age are closed, then the RecyclingMethod request is executed, then each
+// recycle check is called. Connections are opened with the current connection parameters, so
+// replacing the parameters of a ReloadableConnectParams affects the connections that the pool
+// opens afterward. The manager must be used from within a tokio runtime.
+#[derive(Clone)]
+pub struct TeradataManager {
+	params: ReloadableConnectParams,
+	recycling_method: RecyclingMethod,
+	max_age: Option<Duration>,         // connections opened longer ago are closed instead of recycled
+	recycle_checks: Vec<RecycleCheck>,
+}
+
+impl fmt::Debug for TeradataManager {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("TeradataManager")
+			.field("params", &self.params)
+			.field("recycling_method", &self.recycling_method)
+			.field("max_age", &self.max_age)
+			.field("recycle_checks", &self.recycle_checks.len())
+			.finish()
+	}
+}
+
+impl TeradataManager {
+	pub fn new<P: Into<ReloadableConnectParams>>(params: P) -> Self {
+		TeradataManager { params: params.into(), recycling_method: RecyclingMethod::default(), max_age: None, recycle_checks: Vec::new() }
+	}
+
+	pub fn recycling_method(mut self, method: RecyclingMethod) -> Self {
+		self.recycling_method = method;
+		self
+	}
+
+	pub fn max_age(mut self, max_age: Option<Duration>) -> Self {
+		self.max_age = max_age;
+		self
+	}
+
+	// Adds a check of a connection being recycled, which receives the deadpool metrics of the
+	// connection, such as its age and recycle count, for example to export them or to retire
+	// connections after a number of uses. Returning Err closes the connection.
+	pub fn recycle_check<F>(mut self, check: F) -> Self
+	where
+		F: Fn(&Connection, &Metrics) -> Result<(), String> + Send + Sync + 'static,
+	{
+		self.recycle_checks.push(Arc::new(check));
+		self
+	}
+
+	pub fn params(&self) -> &ReloadableConnectParams {
+		&self.params
+	}
+}
+
+impl managed::Manager for TeradataManager {
+	type Type = Arc<Connection>;
+	type Error = TeradataError;
+
+	async fn create(&self) -> Result<Arc<Connection>, TeradataError> {
+		let (params, _) = self.params.current();
+		let params_json = params.to_json();
+		run_blocking(move || Connection::open(&params_json).map(Arc::new)).await
+	}
+
+	async fn recycle(&self, conn: &mut Arc<Connection>, metrics: &Metrics) -> RecycleResult<TeradataError> {
+		if let Some(max_age) = self.max_age && metrics.age() > max_age {
+			return Err(RecycleError::message(format!("The connection is older than the max_age {:?}", max_age)));
+		}
+		let validated = Arc::clone(conn);
+		let request_text = self.recycling_method.request_text().to_string();
+		run_blocking(move || validated.execute(&request_text, "null")).await?;
+		for check in &self.recycle_checks {
+			check(conn, metrics).map_err(RecycleError::message)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,160 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::result::ColumnInfo;
+use crate::row::Row;
+
+// How DECIMAL and NUMBER column values are surfaced
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DecimalMode {
+	#[default]
+	String,        // decimal digits exactly as returned by the driver
+	#[cfg(feature = "rust_decimal")]
+	RustDecimal,   // rust_decimal::Decimal, exact up to 28 digits
+	LossyF64,      // f64, which cannot represent most decimal fractions exactly; opt-in only
+	ScaledInteger, // i128 value multiplied by 10 to the power of the column scale
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecimalValue {
+	String(String),
+	#[cfg(feature = "rust_decimal")]
+	RustDecimal(rust_decimal::Decimal),
+	F64(f64),
+	Scaled { value: i128, scale: u32 }, // the decimal value is value / 10^scale
+}
+
+// Chooses the DecimalMode of each DECIMAL and NUMBER column. Columns without an override use
+// the default mode, which is DecimalMode::String so that no digits are lost unless requested.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecimalOptions {
+	pub default_mode: DecimalMode,
+	pub overrides: Vec<(String, DecimalMode)>, // column name, matched ignoring case, and its mode
+}
+
+impl DecimalOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn default_mode(mut self, mode: DecimalMode) -> Self {
+		self.default_mode = mode;
+		self
+	}
+
+	pub fn column(mut self, name: &str, mode: DecimalMode) -> Self {
+		self.overrides.push((name.to_string(), mode));
+		self
+	}
+
+	pub fn mode_for(&self, column_name: &str) -> DecimalMode {
+		self.overrides.iter().rev().find(|(name, _)| name.eq_ignore_ascii_case(column_name)).map_or(self.default_mode, |(_, mode)| *mode)
+	}
+}
+
+// Whether a column is a DECIMAL or NUMBER column
+pub fn is_decimal_column(column: &ColumnInfo) -> bool {
+	let type_name = column.type_name.trim();
+	type_name.eq_ignore_ascii_case("DECIMAL") || type_name.eq_ignore_ascii_case("NUMBER")
+}
+
+// Converts decimal digits, such as "-123.45" or "1.5E+3", to an i128 scaled by 10^scale. Returns
+// an error if the value has more fractional digits than the scale or does not fit in an i128.
+pub(crate) fn scaled_integer(digits: &str, scale: u32) -> Result<i128, String> {
+	let (mantissa, exponent) = match digits.find(['e', 'E']) {
+		Some(i) => (&digits[..i], digits[i + 1..].parse::<i32>().map_err(|_| format!("Invalid decimal value {}", digits))?),
+		None => (digits, 0),
+	};
+	let (negative, mantissa) = match mantissa.strip_prefix('-') {
+		Some(rest) => (true, rest),
+		None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+	};
+	let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+	if whole.is_empty() && fraction.is_empty() || !whole.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+		return Err(format!("Invalid decimal value {}", digits));
+	}
+
+	// The value is all_digits * 10^(exponent - fraction.len()); rescale it to 10^-scale
+	let all_digits = format!("{}{}", whole, fraction);
+	let shift = exponent as i64 - fraction.len() as i64 + scale as i64;
+	let (significant, dropped) = if shift >= 0 {
+		(format!("{}{}", all_digits, "0".repeat(shift as usize)), "")
+	} else {
+		let keep = all_digits.len().saturating_sub((-shift) as usize);
+		(all_digits[..keep].to_string(), &all_digits[keep..])
+	};
+	if dropped.bytes().any(|b| b != b'0') {
+		return Err(format!("Decimal value {} has more than {} fractional digits", digits, scale));
+	}
+
+	let significant = significant.trim_start_matches('0');
+	let value = if significant.is_empty() {
+		0
+	} else {
+		significant.parse::<i128>().map_err(|_| format!("Decimal value {} does not fit in a scaled i128", digits))?
+	};
+	Ok(if negative { -value } else { value })
+}
+
+// Converts a DECIMAL or NUMBER column value from its JSON text, a number or a string, to the
+// requested representation. Returns None for NULL.
+pub fn decode_decimal(
+	json: &str,
+	column: &ColumnInfo,
+	mode: DecimalMode,
+) -> Result<Option<DecimalValue>, String> {
+
+	let json = json.trim();
+	if json == "null" {
+		return Ok(None);
+	}
+	let digits = match json.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+		Some(s) => s.trim(),
+		None => json,
+	};
+
+	let value = match mode {
+		DecimalMode::String => DecimalValue::String(digits.to_string()),
+		#[cfg(feature = "rust_decimal")]
+		DecimalMode::RustDecimal => {
+			let parsed = if digits.contains(['e', 'E']) { rust_decimal::Decimal::from_scientific(digits) } else { digits.parse() };
+			match parsed {
+				Ok(decimal) => DecimalValue::RustDecimal(decimal),
+				Err(err) => {
+					return Err(format!("Could not convert {} value {} to rust_decimal: {}", column.name, digits, err));
+				}
+			}
+		}
+		DecimalMode::LossyF64 => match digits.parse::<f64>() {
+			Ok(f) => DecimalValue::F64(f),
+			Err(err) => {
+				return Err(format!("Could not convert {} value {} to f64: {}", column.name, digits, err));
+			}
+		},
+		DecimalMode::ScaledInteger => {
+			let scale = column.scale as u32;
+			match scaled_integer(digits, scale) {
+				Ok(value) => DecimalValue::Scaled { value, scale },
+				Err(err) => {
+					return Err(format!("Column {}: {}", column.name, err));
+				}
+			}
+		}
+	};
+
+	Ok(Some(value))
+
+} // end decode_decimal
+
+impl Row {
+	// Decodes a DECIMAL or NUMBER column value using the mode chosen by the options for the
+	// column. Returns None for NULL.
+	pub fn get_decimal(&self, index: usize, column: &ColumnInfo, options: &DecimalOptions) -> Result<Option<DecimalValue>, String> {
+		if !is_decimal_column(column) {
+			return Err(format!("Column {} is {}, not DECIMAL or NUMBER", column.name, column.type_name));
+		}
+		match self.column_json(index) {
+			Some(json) => decode_decimal(json, column, options.mode_for(&column.name)),
+			None => Err(format!("Column index {} is out of range for a row with {} columns", index, self.len())),
+		}
+	}
+}
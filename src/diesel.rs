@@ -0,0 +1,676 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Diesel backend and connection, so that applications built on the Diesel ORM can use its query
+// builder, table! schemas, and transactions with Teradata. Diesel queries are translated to
+// Teradata SQL with ? parameter markers and executed the same way as Connection::execute and
+// Connection::query, which convert the base64 values of binary parameters to bytes. Teradata has no
+// LIMIT clause, so limit(n) and first() select TOP n; offset, RETURNING, ON CONFLICT, batch
+// inserts, and locking clauses are not supported.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use ::diesel::backend::sql_dialect::{
+	aggregate_function_expressions, alias_syntax, array_comparison, batch_insert_support, built_in_window_function_require_order, concat_clause,
+	default_keyword_for_insert, default_value_clause, exists_syntax, from_clause_syntax, on_conflict_clause, returning_clause, window_frame_clause_group_support,
+	window_frame_exclusion_support,
+};
+use ::diesel::backend::{Backend, DieselReserveSpecialization, SqlDialect, TrustedBackend};
+use ::diesel::connection::{
+	CacheSize, Connection as DieselConnection, ConnectionSealed, DynInstrumentation, Instrumentation, InstrumentationEvent, LoadConnection, SimpleConnection,
+	StrQueryHelper, TransactionDepthChange, TransactionManager, TransactionManagerStatus,
+};
+use ::diesel::deserialize::{self, FromSql, FromSqlRef};
+use ::diesel::expression::QueryMetadata;
+use ::diesel::query_builder::bind_collector::BindCollector;
+use ::diesel::query_builder::{
+	AstPass, BoxedLimitOffsetClause, BoxedSelectStatement, IntoBoxedClause, LimitClause, LimitOffsetClause, NoLimitClause, NoOffsetClause, Query, QueryBuilder,
+	QueryFragment, QueryId, SelectStatement,
+};
+use ::diesel::result::{ConnectionError, ConnectionResult, DatabaseErrorInformation, DatabaseErrorKind, Error, QueryResult};
+use ::diesel::row::{Field, PartialRow, Row as DieselRow, RowIndex, RowSealed};
+use ::diesel::serialize::{self, IsNull, Output, ToSql};
+use ::diesel::sql_types::{self, HasSqlType, TypeMetadata};
+
+use crate::connection::Connection;
+use crate::error::{TeradataError, Violation};
+use crate::from_sql::FromSql as TeradataFromSql;
+use crate::params::{bind_json, Param};
+use crate::result::ColumnInfo;
+use crate::rows::Rows;
+use crate::sql::quote_identifier;
+use crate::statement_cache::DEFAULT_STATEMENT_CACHE_CAPACITY;
+use crate::temporal::{Date, Time, Timestamp};
+use crate::value::Value;
+
+// The Teradata backend, for example TeradataConnection or Teradata in the generic parameters of
+// Diesel types
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Teradata;
+
+impl Backend for Teradata {
+	type QueryBuilder = TeradataQueryBuilder;
+	type RawValue<'a> = TeradataRawValue<'a>;
+	type BindCollector<'a> = TeradataBindCollector;
+}
+
+// The database type name of a bind value, such as "INTEGER"; binds need no type lookup
+impl TypeMetadata for Teradata {
+	type TypeMetadata = &'static str;
+	type MetadataLookup = ();
+}
+
+// The select statement syntax of Teradata, which selects TOP n instead of ending with LIMIT n
+#[derive(Debug, Clone, Copy)]
+pub struct TeradataSelectStatement;
+
+impl SqlDialect for Teradata {
+	type ReturningClause = returning_clause::DoesNotSupportReturningClause;
+	type OnConflictClause = on_conflict_clause::DoesNotSupportOnConflictClause;
+	type InsertWithDefaultKeyword = default_keyword_for_insert::IsoSqlDefaultKeyword;
+	type BatchInsertSupport = batch_insert_support::DoesNotSupportBatchInsert;
+	type ConcatClause = concat_clause::ConcatWithPipesClause;
+	type DefaultValueClauseForInsert = default_value_clause::AnsiDefaultValueClause;
+	type EmptyFromClauseSyntax = from_clause_syntax::AnsiSqlFromClauseSyntax;
+	type ExistsSyntax = exists_syntax::AnsiSqlExistsSyntax;
+	type ArrayComparison = array_comparison::AnsiSqlArrayComparison;
+	type SelectStatementSyntax = TeradataSelectStatement;
+	type AliasSyntax = alias_syntax::AsAliasSyntax;
+	type WindowFrameClauseGroupSupport = window_frame_clause_group_support::NoGroupWindowFrameUnit;
+	type WindowFrameExclusionSupport = window_frame_exclusion_support::NoFrameFrameExclusionSupport;
+	type AggregateFunctionExpressions = aggregate_function_expressions::NoAggregateFunctionExpressions;
+	type BuiltInWindowFunctionRequireOrder = built_in_window_function_require_order::NoOrderRequired;
+}
+
+impl DieselReserveSpecialization for Teradata {}
+impl TrustedBackend for Teradata {}
+
+macro_rules! diesel_has_sql_type {
+	($($sql_type:ident => $type_name:expr),*) => {
+		$(
+			impl HasSqlType<sql_types::$sql_type> for Teradata {
+				fn metadata(_lookup: &mut ()) -> &'static str {
+					$type_name
+				}
+			}
+		)*
+	};
+}
+
+diesel_has_sql_type!(
+	TinyInt => "BYTEINT",
+	SmallInt => "SMALLINT",
+	Integer => "INTEGER",
+	BigInt => "BIGINT",
+	Float => "REAL",
+	Double => "FLOAT",
+	Text => "VARCHAR",
+	Binary => "VARBYTE",
+	Date => "DATE",
+	Time => "TIME",
+	Timestamp => "TIMESTAMP"
+);
+
+// Builds the request text of a query, with a ? parameter marker for each bind value
+#[derive(Debug, Default)]
+pub struct TeradataQueryBuilder {
+	sql: String,
+}
+
+impl QueryBuilder<Teradata> for TeradataQueryBuilder {
+	fn push_sql(&mut self, sql: &str) {
+		self.sql.push_str(sql);
+	}
+
+	fn push_identifier(&mut self, identifier: &str) -> QueryResult<()> {
+		self.sql.push_str(&quote_identifier(identifier));
+		Ok(())
+	}
+
+	fn push_bind_param(&mut self) {
+		self.sql.push('?');
+	}
+
+	fn finish(self) -> String {
+		self.sql
+	}
+}
+
+// Collects the bind values of a query as the Param of each parameter marker
+#[derive(Debug, Default)]
+pub struct TeradataBindCollector {
+	binds: Vec<Param>,
+}
+
+impl TeradataBindCollector {
+	// The bind values in the JSON format of Connection::execute, a single row of parameters, and
+	// whether any of them is a byte value
+	fn to_bind_json(&self) -> QueryResult<(String, bool)> {
+		if self.binds.is_empty() {
+			return Ok(("null".to_string(), false));
+		}
+		bind_json(&[&self.binds]).map_err(|err| Error::SerializationError(err.into()))
+	}
+}
+
+impl<'a> BindCollector<'a, Teradata> for TeradataBindCollector {
+	type Buffer = Param;
+
+	fn push_bound_value<T, U>(&mut self, bind: &'a U, metadata_lookup: &mut ()) -> QueryResult<()>
+	where
+		Teradata: HasSqlType<T>,
+		U: ToSql<T, Teradata> + ?Sized + 'a,
+	{
+		let mut out = Output::new(Param::Null, metadata_lookup);
+		let is_null = bind.to_sql(&mut out).map_err(Error::SerializationError)?;
+		self.binds.push(match is_null {
+			IsNull::Yes => Param::Null,
+			IsNull::No => out.into_inner(),
+		});
+		Ok(())
+	}
+
+	fn push_null_value(&mut self, _metadata: &'static str) -> QueryResult<()> {
+		self.binds.push(Param::Null);
+		Ok(())
+	}
+}
+
+// Selects TOP n for limit(n). The clause follows SELECT and DISTINCT, so
+// TeradataSelectStatement writes it before the select list.
+impl QueryFragment<Teradata> for LimitOffsetClause<NoLimitClause, NoOffsetClause> {
+	fn walk_ast<'b>(&'b self, _out: AstPass<'_, 'b, Teradata>) -> QueryResult<()> {
+		Ok(())
+	}
+}
+
+impl<L: QueryFragment<Teradata>> QueryFragment<Teradata> for LimitOffsetClause<LimitClause<L>, NoOffsetClause> {
+	fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Teradata>) -> QueryResult<()> {
+		out.push_sql("TOP ");
+		self.limit_clause.0.walk_ast(out.reborrow())?;
+		out.push_sql(" ");
+		Ok(())
+	}
+}
+
+// A boxed query holds the TOP n expression itself as its limit, because into_boxed boxes it with
+// the impl below. Calling limit or offset on a query that is already boxed is not supported.
+impl QueryFragment<Teradata> for BoxedLimitOffsetClause<'_, Teradata> {
+	fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Teradata>) -> QueryResult<()> {
+		if self.offset.is_some() {
+			return Err(Error::QueryBuilderError("Teradata does not support OFFSET".into()));
+		}
+		if let Some(ref limit) = self.limit {
+			out.push_sql("TOP ");
+			limit.walk_ast(out.reborrow())?;
+			out.push_sql(" ");
+		}
+		Ok(())
+	}
+}
+
+impl<'a> IntoBoxedClause<'a, Teradata> for LimitOffsetClause<NoLimitClause, NoOffsetClause> {
+	type BoxedClause = BoxedLimitOffsetClause<'a, Teradata>;
+
+	fn into_boxed(self) -> Self::BoxedClause {
+		BoxedLimitOffsetClause { limit: None, offset: None }
+	}
+}
+
+impl<'a, L: QueryFragment<Teradata> + Send + 'a> IntoBoxedClause<'a, Teradata> for LimitOffsetClause<LimitClause<L>, NoOffsetClause> {
+	type BoxedClause = BoxedLimitOffsetClause<'a, Teradata>;
+
+	fn into_boxed(self) -> Self::BoxedClause {
+		BoxedLimitOffsetClause { limit: Some(Box::new(self.limit_clause.0)), offset: None }
+	}
+}
+
+impl<F, S, D, W, O, LOf, G, H, LC> QueryFragment<Teradata, TeradataSelectStatement> for SelectStatement<F, S, D, W, O, LOf, G, H, LC>
+where
+	S: QueryFragment<Teradata>,
+	F: QueryFragment<Teradata>,
+	D: QueryFragment<Teradata>,
+	W: QueryFragment<Teradata>,
+	O: QueryFragment<Teradata>,
+	LOf: QueryFragment<Teradata>,
+	G: QueryFragment<Teradata>,
+	H: QueryFragment<Teradata>,
+	LC: QueryFragment<Teradata>,
+{
+	fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Teradata>) -> QueryResult<()> {
+		out.push_sql("SELECT ");
+		self.distinct.walk_ast(out.reborrow())?;
+		self.limit_offset.walk_ast(out.reborrow())?;
+		self.select.walk_ast(out.reborrow())?;
+		self.from.walk_ast(out.reborrow())?;
+		self.where_clause.walk_ast(out.reborrow())?;
+		self.group_by.walk_ast(out.reborrow())?;
+		self.having.walk_ast(out.reborrow())?;
+		self.order.walk_ast(out.reborrow())?;
+		self.locking.walk_ast(out.reborrow())?;
+		Ok(())
+	}
+}
+
+impl<ST, QS: QueryFragment<Teradata>, GB> QueryFragment<Teradata, TeradataSelectStatement> for BoxedSelectStatement<'_, ST, QS, Teradata, GB> {
+	fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Teradata>) -> QueryResult<()> {
+		out.push_sql("SELECT ");
+		self.distinct.walk_ast(out.reborrow())?;
+		self.limit_offset.walk_ast(out.reborrow())?;
+		self.select.walk_ast(out.reborrow())?;
+		self.from.walk_ast(out.reborrow())?;
+		self.where_clause.walk_ast(out.reborrow())?;
+		self.group_by.walk_ast(out.reborrow())?;
+		self.having.walk_ast(out.reborrow())?;
+		if let Some(ref order) = self.order {
+			out.push_sql(" ORDER BY ");
+			order.walk_ast(out.reborrow())?;
+		}
+		Ok(())
+	}
+}
+
+macro_rules! diesel_to_sql {
+	($($sql_type:ident => $t:ty),*) => {
+		$(
+			impl ToSql<sql_types::$sql_type, Teradata> for $t {
+				fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Teradata>) -> serialize::Result {
+					out.set_value(Param::from(self.to_owned()));
+					Ok(IsNull::No)
+				}
+			}
+		)*
+	};
+}
+
+diesel_to_sql!(
+	TinyInt => i8,
+	SmallInt => i16,
+	Integer => i32,
+	BigInt => i64,
+	Float => f32,
+	Double => f64,
+	Text => str,
+	Binary => [u8],
+	Date => Date,
+	Time => Time,
+	Timestamp => Timestamp
+);
+
+// A non-NULL column value of a row; Field::value returns None for NULL
+#[derive(Debug, Clone, Copy)]
+pub struct TeradataRawValue<'a> {
+	value: &'a Value,
+}
+
+impl<'a> TeradataRawValue<'a> {
+	pub fn value(&self) -> &'a Value {
+		self.value
+	}
+}
+
+// Converts column values with the FromSql conversions of this crate in lenient mode
+macro_rules! diesel_from_sql {
+	($($sql_type:ident => $t:ty),*) => {
+		$(
+			impl FromSql<sql_types::$sql_type, Teradata> for $t {
+				fn from_sql(value: TeradataRawValue<'_>) -> deserialize::Result<Self> {
+					<$t as TeradataFromSql>::from_sql(value.value.clone()).map_err(Into::into)
+				}
+			}
+		)*
+	};
+}
+
+diesel_from_sql!(
+	TinyInt => i8,
+	SmallInt => i16,
+	Integer => i32,
+	BigInt => i64,
+	Float => f32,
+	Double => f64,
+	Date => Date,
+	Time => Time,
+	Timestamp => Timestamp
+);
+
+// Diesel converts Text to String and Binary to Vec<u8> through these borrowed forms
+impl<'a> FromSqlRef<'a, sql_types::Text, Teradata> for &'a str {
+	fn from_sql(value: &'a mut TeradataRawValue<'_>) -> deserialize::Result<Self> {
+		match value.value {
+			Value::Varchar(s) | Value::Decimal(s) => Ok(s),
+			value => Err(format!("{:?} cannot be converted to String", value).into()),
+		}
+	}
+}
+
+impl<'a> FromSqlRef<'a, sql_types::Binary, Teradata> for &'a [u8] {
+	fn from_sql(value: &'a mut TeradataRawValue<'_>) -> deserialize::Result<Self> {
+		match value.value {
+			Value::Bytes(bytes) => Ok(bytes),
+			value => Err(format!("{:?} cannot be converted to Vec<u8>", value).into()),
+		}
+	}
+}
+
+impl DatabaseErrorInformation for TeradataError {
+	fn message(&self) -> &str {
+		TeradataError::message(self)
+	}
+
+	fn details(&self) -> Option<&str> {
+		None
+	}
+
+	fn hint(&self) -> Option<&str> {
+		None
+	}
+
+	fn table_name(&self) -> Option<&str> {
+		None
+	}
+
+	fn column_name(&self) -> Option<&str> {
+		None
+	}
+
+	fn constraint_name(&self) -> Option<&str> {
+		None
+	}
+
+	fn statement_position(&self) -> Option<i32> {
+		None
+	}
+}
+
+fn diesel_error(err: TeradataError) -> Error {
+	match err {
+		TeradataError::Conversion(_) => Error::DeserializationError(Box::new(err)),
+		TeradataError::InvalidArgument(_) => Error::SerializationError(Box::new(err)),
+		_ => {
+			let kind = match err.violation() {
+				Some(Violation::Unique) => DatabaseErrorKind::UniqueViolation,
+				Some(Violation::ForeignKey) => DatabaseErrorKind::ForeignKeyViolation,
+				Some(Violation::NotNull) => DatabaseErrorKind::NotNullViolation,
+				Some(Violation::Check) => DatabaseErrorKind::CheckViolation,
+				None => DatabaseErrorKind::Unknown,
+			};
+			Error::DatabaseError(kind, Box::new(err))
+		}
+	}
+}
+
+// A row with its column values decoded by Value::decode
+#[derive(Debug, Clone)]
+pub struct TeradataRow {
+	values: Vec<Value>,
+	columns: Arc<[ColumnInfo]>,
+}
+
+impl RowSealed for TeradataRow {}
+
+impl<'a> DieselRow<'a, Teradata> for TeradataRow {
+	type Field<'f>
+		= TeradataField<'f>
+	where
+		'a: 'f,
+		Self: 'f;
+	type InnerPartialRow = Self;
+
+	fn field_count(&self) -> usize {
+		self.values.len()
+	}
+
+	fn get<'b, I>(&'b self, idx: I) -> Option<TeradataField<'b>>
+	where
+		'a: 'b,
+		Self: RowIndex<I>,
+	{
+		let index = self.idx(idx)?;
+		Some(TeradataField { value: &self.values[index], column: &self.columns[index] })
+	}
+
+	fn partial_row(&self, range: Range<usize>) -> PartialRow<'_, Self> {
+		PartialRow::new(self, range)
+	}
+}
+
+impl RowIndex<usize> for TeradataRow {
+	fn idx(&self, idx: usize) -> Option<usize> {
+		(idx < self.values.len()).then_some(idx)
+	}
+}
+
+// Column names match case-insensitively, as in the request text
+impl RowIndex<&str> for TeradataRow {
+	fn idx(&self, name: &str) -> Option<usize> {
+		self.columns.iter().position(|column| column.name.eq_ignore_ascii_case(name))
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TeradataField<'f> {
+	value: &'f Value,
+	column: &'f ColumnInfo,
+}
+
+impl TeradataField<'_> {
+	pub fn column(&self) -> &ColumnInfo {
+		self.column
+	}
+}
+
+impl<'f> Field<'f, Teradata> for TeradataField<'f> {
+	fn field_name(&self) -> Option<&str> {
+		Some(&self.column.name)
+	}
+
+	fn value(&self) -> Option<TeradataRawValue<'_>> {
+		match self.value {
+			Value::Null => None,
+			value => Some(TeradataRawValue { value }),
+		}
+	}
+}
+
+// Iterates the rows of the first result of a query
+pub struct TeradataCursor<'conn> {
+	rows: Rows<'conn>,
+	columns: Option<Arc<[ColumnInfo]>>, // column descriptions of the result, once retrieved
+}
+
+impl TeradataCursor<'_> {
+	fn fetch(&mut self) -> Result<Option<TeradataRow>, TeradataError> {
+		if self.columns.is_none() {
+			self.columns = Some(self.rows.columns()?.into());
+		}
+		let columns = Arc::clone(self.columns.as_ref().unwrap());
+		match self.rows.fetch_values()? {
+			Some(values) => Ok(Some(TeradataRow { values, columns })),
+			None => Ok(None),
+		}
+	}
+}
+
+impl Iterator for TeradataCursor<'_> {
+	type Item = QueryResult<TeradataRow>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.fetch().map_err(diesel_error).transpose()
+	}
+}
+
+// Transaction manager of TeradataConnection, which turns off autocommit for the duration of a
+// transaction. Teradata has no savepoints, so nested transactions return
+// Error::AlreadyInTransaction.
+#[derive(Debug, Default)]
+pub struct TeradataTransactionManager {
+	status: TransactionManagerStatus,
+}
+
+impl TeradataTransactionManager {
+	fn change_depth(conn: &mut TeradataConnection, change: TransactionDepthChange) -> QueryResult<()> {
+		match &mut conn.transaction_manager.status {
+			TransactionManagerStatus::Valid(status) => status.change_transaction_depth(change),
+			TransactionManagerStatus::InError => Err(Error::BrokenTransactionManager),
+		}
+	}
+
+	// Ends the transaction with commit or rollback, then restores autocommit. A rollback after a
+	// failed commit discards whatever the commit left of the transaction.
+	fn end_transaction(conn: &mut TeradataConnection, commit: bool) -> QueryResult<()> {
+		let depth = match conn.transaction_manager.status.transaction_depth()? {
+			Some(depth) => depth,
+			None => return Err(Error::NotInTransaction),
+		};
+		let result = if commit {
+			conn.instrumentation.on_connection_event(InstrumentationEvent::commit_transaction(depth));
+			conn.conn.commit().inspect_err(|_| {
+				let _ = conn.conn.rollback();
+			})
+		} else {
+			conn.instrumentation.on_connection_event(InstrumentationEvent::rollback_transaction(depth));
+			conn.conn.rollback()
+		};
+		if let Err(err) = conn.conn.set_autocommit(true) {
+			conn.transaction_manager.status.set_in_error();
+			return Err(diesel_error(err));
+		}
+		Self::change_depth(conn, TransactionDepthChange::DecreaseDepth)?;
+		result.map_err(diesel_error)
+	}
+}
+
+impl TransactionManager<TeradataConnection> for TeradataTransactionManager {
+	type TransactionStateData = Self;
+
+	fn begin_transaction(conn: &mut TeradataConnection) -> QueryResult<()> {
+		if conn.transaction_manager.status.transaction_depth()?.is_some() {
+			return Err(Error::AlreadyInTransaction);
+		}
+		conn.conn.set_autocommit(false).map_err(diesel_error)?;
+		Self::change_depth(conn, TransactionDepthChange::IncreaseDepth)?;
+		if let Some(depth) = conn.transaction_manager.status.transaction_depth()? {
+			conn.instrumentation.on_connection_event(InstrumentationEvent::begin_transaction(depth));
+		}
+		Ok(())
+	}
+
+	fn rollback_transaction(conn: &mut TeradataConnection) -> QueryResult<()> {
+		Self::end_transaction(conn, false)
+	}
+
+	fn commit_transaction(conn: &mut TeradataConnection) -> QueryResult<()> {
+		Self::end_transaction(conn, true)
+	}
+
+	fn transaction_manager_status_mut(conn: &mut TeradataConnection) -> &mut TransactionManagerStatus {
+		&mut conn.transaction_manager.status
+	}
+}
+
+// A Diesel connection. establish takes a connection parameter JSON string, the same as
+// Connection::open, for example {"host":"whomooz","user":"guest","password":"please"}.
+pub struct TeradataConnection {
+	conn: Connection,
+	transaction_manager: TeradataTransactionManager,
+	instrumentation: DynInstrumentation,
+}
+
+impl TeradataConnection {
+	pub fn connection(&self) -> &Connection {
+		&self.conn
+	}
+
+	// Returns the request text and bind value JSON of a query, and whether it binds byte values
+	fn build_request<T: QueryFragment<Teradata>>(source: &T) -> QueryResult<(String, String, bool)> {
+		let mut query_builder = TeradataQueryBuilder::default();
+		source.to_sql(&mut query_builder, &Teradata)?;
+		let mut bind_collector = TeradataBindCollector::default();
+		source.collect_binds(&mut bind_collector, &mut (), &Teradata)?;
+		let (bind_values, bytes) = bind_collector.to_bind_json()?;
+		Ok((query_builder.finish(), bind_values, bytes))
+	}
+
+	// Runs f with the request text, reporting the query to the instrumentation
+	fn instrument<R>(&mut self, request_text: &str, f: impl FnOnce(&Connection) -> QueryResult<R>) -> QueryResult<R> {
+		self.instrumentation.on_connection_event(InstrumentationEvent::start_query(&StrQueryHelper::new(request_text)));
+		let result = f(&self.conn);
+		self.instrumentation.on_connection_event(InstrumentationEvent::finish_query(&StrQueryHelper::new(request_text), result.as_ref().err()));
+		result
+	}
+}
+
+impl From<Connection> for TeradataConnection {
+	fn from(conn: Connection) -> Self {
+		TeradataConnection { conn, transaction_manager: TeradataTransactionManager::default(), instrumentation: DynInstrumentation::default_instrumentation() }
+	}
+}
+
+impl SimpleConnection for TeradataConnection {
+	fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
+		self.instrument(query, |conn| conn.execute(query, "null").map(|_| ()).map_err(diesel_error))
+	}
+}
+
+impl ConnectionSealed for TeradataConnection {}
+
+impl DieselConnection for TeradataConnection {
+	type Backend = Teradata;
+	type TransactionManager = TeradataTransactionManager;
+
+	fn establish(connect_params_json: &str) -> ConnectionResult<Self> {
+		// The connection parameters include the password, so the instrumentation receives only
+		// the name of the backend in place of the database URL
+		let url = "teradata";
+		let mut instrumentation = DynInstrumentation::default_instrumentation();
+		instrumentation.on_connection_event(InstrumentationEvent::start_establish_connection(url));
+		let result = Connection::open(connect_params_json).map_err(|err| ConnectionError::BadConnection(err.to_string()));
+		instrumentation.on_connection_event(InstrumentationEvent::finish_establish_connection(url, result.as_ref().err()));
+		Ok(TeradataConnection { conn: result?, transaction_manager: TeradataTransactionManager::default(), instrumentation })
+	}
+
+	fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
+	where
+		T: QueryFragment<Teradata> + QueryId,
+	{
+		let (request_text, bind_values, bytes) = Self::build_request(source)?;
+		self.instrument(&request_text, |conn| conn.execute_json(&request_text, &bind_values, bytes).map(|count| count as usize).map_err(diesel_error))
+	}
+
+	fn transaction_state(&mut self) -> &mut TeradataTransactionManager {
+		&mut self.transaction_manager
+	}
+
+	fn instrumentation(&mut self) -> &mut dyn Instrumentation {
+		&mut *self.instrumentation
+	}
+
+	fn set_instrumentation(&mut self, instrumentation: impl Instrumentation) {
+		self.instrumentation = instrumentation.into();
+	}
+
+	fn set_prepared_statement_cache_size(&mut self, size: CacheSize) {
+		self.conn.set_statement_cache_capacity(match size {
+			CacheSize::Disabled => 0,
+			_ => DEFAULT_STATEMENT_CACHE_CAPACITY,
+		});
+	}
+}
+
+impl LoadConnection for TeradataConnection {
+	type Cursor<'conn, 'query> = TeradataCursor<'conn>;
+	type Row<'conn, 'query> = TeradataRow;
+
+	fn load<'conn, 'query, T>(&'conn mut self, source: T) -> QueryResult<TeradataCursor<'conn>>
+	where
+		T: Query + QueryFragment<Teradata> + QueryId + 'query,
+		Teradata: QueryMetadata<T::SqlType>,
+	{
+		let (request_text, bind_values, bytes) = Self::build_request(&source)?;
+		self.instrumentation.on_connection_event(InstrumentationEvent::start_query(&StrQueryHelper::new(&request_text)));
+		let result = Rows::open_json(&self.conn, &request_text, &bind_values, bytes).map_err(diesel_error);
+		self.instrumentation.on_connection_event(InstrumentationEvent::finish_query(&StrQueryHelper::new(&request_text), result.as_ref().err()));
+		Ok(TeradataCursor { rows: result?, columns: None })
+	}
+}
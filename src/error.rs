@@ -0,0 +1,137 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::retry::error_code;
+
+// Returns the SQLSTATE from a driver error message, such as 42S02 from
+// "[Error 3807] Object 'x' does not exist. SQLState 42S02"
+fn sqlstate(message: &str) -> Option<String> {
+	static SQLSTATE: OnceLock<Regex> = OnceLock::new();
+	let re = SQLSTATE.get_or_init(|| Regex::new(r"(?i)\bSQL ?State\s*[:=]?\s*([0-9A-Z]{5})\b").unwrap());
+	re.captures(message).map(|caps| caps[1].to_ascii_uppercase())
+}
+
+// Integrity constraint that a database error reports as violated, for the error kinds of the
+// sqlx and diesel integrations
+#[cfg(any(feature = "diesel", feature = "sqlx"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Violation {
+	Unique,     // 2801 to 2803: duplicate primary index, duplicate row, or unique secondary index
+	ForeignKey, // 2700: referential constraint violation
+	NotNull,    // 3604: cannot place a null value in a NOT NULL field
+	Check,      // 5317: check constraint violation
+}
+
+// Errors returned by the driver loading functions, the functions that execute requests, and the
+// Connection and Rows types. Each variant holds the complete error message.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TeradataError {
+	// The driver library could not be loaded or linked
+	#[error("{0}")]
+	DriverLoad(String),
+	// The driver reported a failure without a database error code, such as a broken connection
+	#[error("{0}")]
+	Ffi(String),
+	// The database rejected a request, such as "[Error 3807] Object 'x' does not exist."
+	#[error("{0}")]
+	Database(String),
+	// A value, row, or metadata JSON could not be converted
+	#[error("{0}")]
+	Conversion(String),
+	// A connect timeout or request deadline passed before the operation completed
+	#[error("{0}")]
+	Timeout(String),
+	// The application passed an invalid argument, such as malformed connection parameters
+	#[error("{0}")]
+	InvalidArgument(String),
+	// A request expected to return one row, or at most one row, returned a different number
+	#[error("{0}")]
+	RowCount(String),
+	// A file or writer, such as a spool file or an export destination, could not be read or written
+	#[error("{0}")]
+	Io(String),
+}
+
+impl TeradataError {
+	// Classifies an error message returned by a driver function, naming the function
+	pub(crate) fn from_driver(function: &str, message: String) -> TeradataError {
+		let message = format!("Error from {}: {}", function, message);
+		if error_code(&message).is_some() {
+			TeradataError::Database(message)
+		} else {
+			TeradataError::Ffi(message)
+		}
+	}
+
+	// Rewrites the message, such as to name the operation that failed, keeping the kind of error
+	pub(crate) fn map_message<F: FnOnce(String) -> String>(self, message: F) -> TeradataError {
+		match self {
+			TeradataError::DriverLoad(m) => TeradataError::DriverLoad(message(m)),
+			TeradataError::Ffi(m) => TeradataError::Ffi(message(m)),
+			TeradataError::Database(m) => TeradataError::Database(message(m)),
+			TeradataError::Conversion(m) => TeradataError::Conversion(message(m)),
+			TeradataError::Timeout(m) => TeradataError::Timeout(message(m)),
+			TeradataError::InvalidArgument(m) => TeradataError::InvalidArgument(message(m)),
+			TeradataError::RowCount(m) => TeradataError::RowCount(message(m)),
+			TeradataError::Io(m) => TeradataError::Io(message(m)),
+		}
+	}
+
+	pub fn message(&self) -> &str {
+		match self {
+			TeradataError::DriverLoad(message)
+			| TeradataError::Ffi(message)
+			| TeradataError::Database(message)
+			| TeradataError::Conversion(message)
+			| TeradataError::Timeout(message)
+			| TeradataError::InvalidArgument(message)
+			| TeradataError::RowCount(message)
+			| TeradataError::Io(message) => message,
+		}
+	}
+
+	// The database error code, such as 3807 from "[Error 3807] Object 'x' does not exist."
+	pub fn code(&self) -> Option<u32> {
+		error_code(self.message())
+	}
+
+	// The SQLSTATE, such as "42S02", when the error message includes one
+	pub fn sqlstate(&self) -> Option<String> {
+		sqlstate(self.message())
+	}
+
+	pub fn is_timeout(&self) -> bool {
+		matches!(self, TeradataError::Timeout(_))
+	}
+
+	#[cfg(any(feature = "diesel", feature = "sqlx"))]
+	pub(crate) fn violation(&self) -> Option<Violation> {
+		match self.code() {
+			Some(2801..=2803) => Some(Violation::Unique),
+			Some(2700) => Some(Violation::ForeignKey),
+			Some(3604) => Some(Violation::NotNull),
+			Some(5317) => Some(Violation::Check),
+			_ => None,
+		}
+	}
+}
+
+// Returns the message, so that the value conversion functions, which return Result<_, String>,
+// can use the ? operator on TeradataError
+impl From<TeradataError> for String {
+	fn from(err: TeradataError) -> Self {
+		match err {
+			TeradataError::DriverLoad(message)
+			| TeradataError::Ffi(message)
+			| TeradataError::Database(message)
+			| TeradataError::Conversion(message)
+			| TeradataError::Timeout(message)
+			| TeradataError::InvalidArgument(message)
+			| TeradataError::RowCount(message)
+			| TeradataError::Io(message) => message,
+		}
+	}
+}
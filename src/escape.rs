@@ -0,0 +1,263 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Typed driver escape functions. The driver recognizes {fn teradata_...} escape functions at the
+// beginning of the request text and acts on them itself: some change how the rest of the request
+// is executed, and others, preceded by teradata_nativesql, are answered by the driver without
+// sending a request to the database. Escape renders the escape syntax and parses it back, so
+// applications do not embed the strings by hand.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::options::LobMode;
+
+// How the database processes the request that follows {fn teradata_rpo(...)}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rpo {
+	Prepare, // S: prepare without executing, returning metadata only
+	Execute, // E: execute without returning metadata
+	Both,    // B: prepare and execute, the default
+}
+
+impl Rpo {
+	fn code(&self) -> char {
+		match self {
+			Rpo::Prepare => 'S',
+			Rpo::Execute => 'E',
+			Rpo::Both => 'B',
+		}
+	}
+}
+
+// How LOB values are returned by the query that follows {fn teradata_lobselect(...)}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LobSelect {
+	Inline,             // I: LOB values are materialized in the rows
+	SpoolLocator,       // S: spool-scoped LOB locators
+	TransactionLocator, // T: transaction-scoped LOB locators
+}
+
+impl LobSelect {
+	fn code(&self) -> char {
+		match self {
+			LobSelect::Inline => 'I',
+			LobSelect::SpoolLocator => 'S',
+			LobSelect::TransactionLocator => 'T',
+		}
+	}
+}
+
+// A driver escape function. The functions that return a value, and those that change connection
+// settings, are answered by the driver when they follow NativeSql, as Escape::native renders
+// them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Escape {
+	NativeSql,
+	Autocommit,            // returns "true" or "false"
+	AutocommitOn,
+	AutocommitOff,
+	Commit,
+	Rollback,
+	AmpCount,
+	DatabaseVersion,
+	DriverVersion,
+	SessionNumber,
+	GetWarnings,           // warnings of the previous request
+	GetErrors,             // errors of the previous request
+	GetLogLevel,
+	SetLogLevel(u64),
+	Provide(String),       // locally cached connection information, such as "session_charset_name"
+	FakeResultSets,        // each result is preceded by a fake result set of statement metadata
+	FakeResultSetsOff,
+	LobSelect(LobSelect),
+	RequestTimeout(u64),   // seconds
+	Rpo(Rpo),
+	TryFastLoad,           // uses FastLoad for a batch insert if possible
+	RequireFastLoad,
+	TryFastExport,         // uses FastExport for a query if possible
+	RequireFastExport,
+	Sessions(u32),         // number of FastLoad or FastExport data transfer connections
+}
+
+impl Escape {
+	// The escape function name, without the teradata_ prefix and arguments
+	fn name(&self) -> &'static str {
+		match self {
+			Escape::NativeSql => "nativesql",
+			Escape::Autocommit => "autocommit",
+			Escape::AutocommitOn => "autocommit_on",
+			Escape::AutocommitOff => "autocommit_off",
+			Escape::Commit => "commit",
+			Escape::Rollback => "rollback",
+			Escape::AmpCount => "amp_count",
+			Escape::DatabaseVersion => "database_version",
+			Escape::DriverVersion => "driver_version",
+			Escape::SessionNumber => "session_number",
+			Escape::GetWarnings => "get_warnings",
+			Escape::GetErrors => "get_errors",
+			Escape::GetLogLevel => "getloglevel",
+			Escape::SetLogLevel(_) => "setloglevel",
+			Escape::Provide(_) => "provide",
+			Escape::FakeResultSets => "fake_result_sets",
+			Escape::FakeResultSetsOff => "fake_result_sets_off",
+			Escape::LobSelect(_) => "lobselect",
+			Escape::RequestTimeout(_) => "request_timeout",
+			Escape::Rpo(_) => "rpo",
+			Escape::TryFastLoad => "try_fastload",
+			Escape::RequireFastLoad => "require_fastload",
+			Escape::TryFastExport => "try_fastexport",
+			Escape::RequireFastExport => "require_fastexport",
+			Escape::Sessions(_) => "sessions",
+		}
+	}
+
+	// Returns the escape function followed by the request text
+	pub fn apply(&self, request_text: &str) -> String {
+		format!("{}{}", self, request_text)
+	}
+
+	// Returns a request that the driver answers itself: the escape function preceded by
+	// NativeSql, such as "{fn teradata_nativesql}{fn teradata_driver_version}"
+	pub fn native(&self) -> String {
+		format!("{}{}", Escape::NativeSql, self)
+	}
+}
+
+impl fmt::Display for Escape {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Escape::SetLogLevel(level) => write!(f, "{{fn teradata_{}({})}}", self.name(), level),
+			Escape::Provide(name) => write!(f, "{{fn teradata_{}({})}}", self.name(), name),
+			Escape::LobSelect(mode) => write!(f, "{{fn teradata_{}({})}}", self.name(), mode.code()),
+			Escape::RequestTimeout(seconds) => write!(f, "{{fn teradata_{}({})}}", self.name(), seconds),
+			Escape::Rpo(rpo) => write!(f, "{{fn teradata_{}({})}}", self.name(), rpo.code()),
+			Escape::Sessions(count) => write!(f, "{{fn teradata_{}({})}}", self.name(), count),
+			_ => write!(f, "{{fn teradata_{}}}", self.name()),
+		}
+	}
+}
+
+impl From<LobMode> for Escape {
+	fn from(mode: LobMode) -> Self {
+		match mode {
+			LobMode::Inline => Escape::LobSelect(LobSelect::Inline),
+			LobMode::SpoolLocator => Escape::LobSelect(LobSelect::SpoolLocator),
+			LobMode::TransactionLocator => Escape::LobSelect(LobSelect::TransactionLocator),
+			LobMode::Disabled => Escape::Provide("request_scope_lob_support_off".to_string()),
+		}
+	}
+}
+
+// Parses one escape function, such as "{fn teradata_rpo(S)}". Names and codes are matched
+// ignoring case, as the driver matches them.
+impl FromStr for Escape {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || format!("{} is not a driver escape function", s);
+		let body = s.trim().strip_prefix('{').and_then(|rest| rest.strip_suffix('}')).ok_or_else(invalid)?.trim();
+		let function = match body.get(..2) {
+			Some(keyword) if keyword.eq_ignore_ascii_case("fn") && body[2..].starts_with(char::is_whitespace) => body[2..].trim(),
+			_ => return Err(invalid()),
+		};
+		let (name, argument) = match function.split_once('(') {
+			Some((name, rest)) => match rest.strip_suffix(')') {
+				Some(argument) => (name.trim(), Some(argument.trim())),
+				None => return Err(invalid()),
+			},
+			None => (function, None),
+		};
+		let name = match name.get(..9) {
+			Some(prefix) if prefix.eq_ignore_ascii_case("teradata_") => name[9..].to_ascii_lowercase(),
+			_ => return Err(invalid()),
+		};
+
+		let number = |argument: &str| argument.parse::<u64>().map_err(|err| format!("{} has an invalid argument: {}", s, err));
+		let code = |argument: &str| match argument.len() {
+			1 => Ok(argument.to_ascii_uppercase()),
+			_ => Err(format!("{} has an invalid argument", s)),
+		};
+		let escape = match (name.as_str(), argument) {
+			("setloglevel", Some(argument)) => Escape::SetLogLevel(number(argument)?),
+			("provide", Some(argument)) if !argument.is_empty() => Escape::Provide(argument.to_string()),
+			("lobselect", Some(argument)) => match code(argument)?.as_str() {
+				"I" => Escape::LobSelect(LobSelect::Inline),
+				"S" => Escape::LobSelect(LobSelect::SpoolLocator),
+				"T" => Escape::LobSelect(LobSelect::TransactionLocator),
+				_ => return Err(format!("{} has an invalid argument", s)),
+			},
+			("request_timeout", Some(argument)) => Escape::RequestTimeout(number(argument)?),
+			("rpo", Some(argument)) => match code(argument)?.as_str() {
+				"S" => Escape::Rpo(Rpo::Prepare),
+				"E" => Escape::Rpo(Rpo::Execute),
+				"B" => Escape::Rpo(Rpo::Both),
+				_ => return Err(format!("{} has an invalid argument", s)),
+			},
+			("sessions", Some(argument)) => Escape::Sessions(u32::try_from(number(argument)?).map_err(|err| format!("{} has an invalid argument: {}", s, err))?),
+			("setloglevel" | "provide" | "lobselect" | "request_timeout" | "rpo" | "sessions", _) => {
+				return Err(format!("{} requires an argument", s));
+			}
+			(_, Some(_)) => return Err(format!("{} does not take an argument", s)),
+			("nativesql", None) => Escape::NativeSql,
+			("autocommit", None) => Escape::Autocommit,
+			("autocommit_on", None) => Escape::AutocommitOn,
+			("autocommit_off", None) => Escape::AutocommitOff,
+			("commit", None) => Escape::Commit,
+			("rollback", None) => Escape::Rollback,
+			("amp_count", None) => Escape::AmpCount,
+			("database_version", None) => Escape::DatabaseVersion,
+			("driver_version", None) => Escape::DriverVersion,
+			("session_number", None) => Escape::SessionNumber,
+			("get_warnings", None) => Escape::GetWarnings,
+			("get_errors", None) => Escape::GetErrors,
+			("getloglevel", None) => Escape::GetLogLevel,
+			("fake_result_sets", None) => Escape::FakeResultSets,
+			("fake_result_sets_off", None) => Escape::FakeResultSetsOff,
+			("try_fastload", None) => Escape::TryFastLoad,
+			("require_fastload", None) => Escape::RequireFastLoad,
+			("try_fastexport", None) => Escape::TryFastExport,
+			("require_fastexport", None) => Escape::RequireFastExport,
+			_ => return Err(format!("{} is not a known driver escape function", s)),
+		};
+		Ok(escape)
+	}
+}
+
+// Returns the escape functions followed by the request text
+pub fn apply(
+	escapes: &[Escape],
+	request_text: &str,
+) -> String {
+
+	let mut text: String = escapes.iter().map(Escape::to_string).collect();
+	text.push_str(request_text);
+	text
+
+} // end apply
+
+// Splits the driver escape functions at the beginning of a request text from the rest of the
+// request, which is returned unchanged. Other escape syntax, such as "{fn left('abc',1)}", is
+// part of the rest of the request.
+pub fn split_escapes(
+	request_text: &str,
+) -> Result<(Vec<Escape>, &str), String> {
+
+	let mut escapes = Vec::new();
+	let mut rest = request_text;
+	loop {
+		let trimmed = rest.trim_start();
+		let candidate = match (trimmed.starts_with('{'), trimmed.find('}')) {
+			(true, Some(end)) => &trimmed[..=end],
+			(true, None) => trimmed,
+			(false, _) => break,
+		};
+		match candidate.parse::<Escape>() {
+			Ok(escape) => escapes.push(escape),
+			Err(err) if candidate.to_ascii_lowercase().contains("teradata_") => return Err(err),
+			Err(_) => break,
+		}
+		rest = &trimmed[candidate.len()..];
+	}
+	Ok((escapes, rest))
+
+} // end split_escapes
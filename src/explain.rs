@@ -0,0 +1,218 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::error::TeradataError;
+use crate::result::fetch_result_sets;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Confidence {
+	No,
+	Low,
+	High,
+	IndexJoin,
+}
+
+// One numbered step of an EXPLAIN plan. Steps that the database executes in parallel
+// are the children of the step that introduces them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainStep {
+	pub number: String, // step number, such as "3" or "4.2" for a parallel step
+	pub text: String,   // step text with line breaks and repeated spaces collapsed
+	pub estimated_rows: Option<u64>,
+	pub estimated_bytes: Option<u64>,
+	pub estimated_seconds: Option<f64>,
+	pub confidence: Option<Confidence>,
+	pub spools: Vec<u32>, // spool numbers the step writes into
+	pub children: Vec<ExplainStep>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainPlan {
+	pub steps: Vec<ExplainStep>,
+	pub total_estimated_seconds: Option<f64>,
+	pub text: String, // original EXPLAIN text
+}
+
+struct ExplainRegexes {
+	step_start: Regex,
+	size: Regex,
+	step_time: Regex,
+	total_time: Regex,
+	spool: Regex,
+}
+
+fn regexes() -> &'static ExplainRegexes {
+	static REGEXES: OnceLock<ExplainRegexes> = OnceLock::new();
+	REGEXES.get_or_init(|| ExplainRegexes {
+		step_start: Regex::new(r"^(\s*)(\d+)\)\s+(.*)$").unwrap(),
+		size: Regex::new(r"(?i)estimated\s+with\s+(no|low|high|index join)\s+confidence\s+to\s+be\s+([\d,]+)\s+rows?(?:\s+\(([\d,]+)\s+bytes\))?").unwrap(),
+		step_time: Regex::new(r"(?i)estimated\s+time\s+for\s+this\s+step\s+is\s+([\d:.]+)\s+seconds").unwrap(),
+		total_time: Regex::new(r"(?i)total\s+estimated\s+time\s+is\s+([\d:.]+)\s+seconds").unwrap(),
+		spool: Regex::new(r"(?i)into\s+Spool\s+(\d+)").unwrap(),
+	})
+}
+
+fn parse_count(s: &str) -> Option<u64> {
+	s.replace(',', "").parse().ok()
+}
+
+// Parses "12.34" or "1:02:03.45" into seconds
+fn parse_seconds(s: &str) -> Option<f64> {
+	let mut seconds = 0.0;
+	for part in s.split(':') {
+		seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+	}
+	Some(seconds)
+}
+
+impl ExplainStep {
+	fn new(number: String, text: &str) -> Self {
+		ExplainStep {
+			number,
+			text: text.to_string(),
+			estimated_rows: None,
+			estimated_bytes: None,
+			estimated_seconds: None,
+			confidence: None,
+			spools: Vec::new(),
+			children: Vec::new(),
+		}
+	}
+
+	fn append(&mut self, text: &str) {
+		if !self.text.is_empty() {
+			self.text.push(' ');
+		}
+		self.text.push_str(text);
+	}
+
+	fn analyze(&mut self) {
+		let re = regexes();
+		self.text = self.text.split_whitespace().collect::<Vec<_>>().join(" ");
+		if let Some(caps) = re.size.captures(&self.text) {
+			self.confidence = match caps[1].to_lowercase().as_str() {
+				"no" => Some(Confidence::No),
+				"low" => Some(Confidence::Low),
+				"high" => Some(Confidence::High),
+				_ => Some(Confidence::IndexJoin),
+			};
+			self.estimated_rows = parse_count(&caps[2]);
+			self.estimated_bytes = caps.get(3).and_then(|m| parse_count(m.as_str()));
+		}
+		self.estimated_seconds = re.step_time.captures(&self.text).and_then(|caps| parse_seconds(&caps[1]));
+		self.spools = re.spool.captures_iter(&self.text).filter_map(|caps| caps[1].parse().ok()).collect();
+		self.spools.dedup();
+		for child in &mut self.children {
+			child.analyze();
+		}
+	}
+
+	// Returns this step followed by all of its descendants in plan order
+	pub fn flatten(&self) -> Vec<&ExplainStep> {
+		let mut steps = vec![self];
+		for child in &self.children {
+			steps.extend(child.flatten());
+		}
+		steps
+	}
+}
+
+impl ExplainPlan {
+	// Parses the text produced by an EXPLAIN request
+	pub fn parse(text: &str) -> ExplainPlan {
+		let re = regexes();
+		let mut steps: Vec<ExplainStep> = Vec::new();
+		let mut top_indent: Option<usize> = None;
+		let mut in_child = false;
+		let mut trailer = String::new();
+
+		for line in text.lines() {
+			if let Some(caps) = re.step_start.captures(line) {
+				let indent = caps[1].len();
+				let number = &caps[2];
+				let body = &caps[3];
+				let top = *top_indent.get_or_insert(indent);
+				if indent > top && !steps.is_empty() {
+					let parent = steps.last_mut().unwrap();
+					let child_number = format!("{}.{}", parent.number, number);
+					parent.children.push(ExplainStep::new(child_number, body));
+					in_child = true;
+				} else {
+					steps.push(ExplainStep::new(number.to_string(), body));
+					in_child = false;
+				}
+				continue;
+			}
+
+			let trimmed = line.trim();
+			if trimmed.is_empty() {
+				continue;
+			}
+			if trimmed.starts_with("->") || steps.is_empty() {
+				trailer.push(' ');
+				trailer.push_str(trimmed);
+				continue;
+			}
+			if !trailer.is_empty() {
+				// Text following the "->" trailer belongs to the trailer
+				trailer.push(' ');
+				trailer.push_str(trimmed);
+				continue;
+			}
+			let step = steps.last_mut().unwrap();
+			match (in_child, step.children.last_mut()) {
+				(true, Some(child)) => child.append(trimmed),
+				_ => step.append(trimmed),
+			}
+		}
+
+		for step in &mut steps {
+			step.analyze();
+		}
+
+		let total_estimated_seconds = re.total_time.captures(&trailer).and_then(|caps| parse_seconds(&caps[1]));
+
+		ExplainPlan { steps, total_estimated_seconds, text: text.to_string() }
+	}
+
+	// Returns every step, including parallel steps, in plan order
+	pub fn flatten(&self) -> Vec<&ExplainStep> {
+		self.steps.iter().flat_map(|step| step.flatten()).collect()
+	}
+
+	// Returns the largest row estimate of any step
+	pub fn max_estimated_rows(&self) -> Option<u64> {
+		self.flatten().iter().filter_map(|step| step.estimated_rows).max()
+	}
+}
+
+pub fn explain(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<ExplainPlan, TeradataError> {
+
+	let results = fetch_result_sets(u_log, conn_handle, &format!("explain {}", request_text), "null")?;
+
+	let mut text = String::new();
+	for result in &results {
+		for row in &result.rows {
+			let values: Vec<serde_json::Value> = match serde_json::from_str(row) {
+				Ok(values) => values,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse EXPLAIN row JSON: {}", err)));
+				}
+			};
+			if let Some(serde_json::Value::String(line)) = values.first() {
+				text.push_str(line);
+				text.push('\n');
+			}
+		}
+	}
+
+	Ok(ExplainPlan::parse(&text))
+
+} // end explain
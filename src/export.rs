@@ -0,0 +1,413 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::io::Write;
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::result::{parse_column_info, ColumnInfo};
+use crate::row::Row;
+use crate::raw::{rustgo_fetch_row_wrapper, rustgo_result_metadata_wrapper};
+use crate::value::Value as ColumnValue;
+
+// Default number of rows written between flushes of the underlying writer
+pub const DEFAULT_EXPORT_BATCH_ROWS: usize = 1000;
+
+// How exported column values are rendered, for downstream systems that are particular about
+// NULLs, dates, and numbers. The default renders values exactly as returned by the driver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueFormat {
+	pub null_text: Option<String>,              // text written for NULL; None writes JSON null or an empty CSV field
+	pub date_format: Option<String>,            // pattern for DATE values, such as "%m/%d/%Y"
+	pub time_format: Option<String>,            // pattern for TIME values, such as "%H%M%S"
+	pub timestamp_format: Option<String>,       // pattern for TIMESTAMP values, such as "%Y-%m-%dT%H:%M:%S%z"
+	pub decimal_separator: char,                // separator written in DECIMAL, NUMBER, and FLOAT values
+	pub boolean_columns: Vec<String>,           // columns holding flags such as 0/1 or 'Y'/'N', matched ignoring case
+	pub boolean_text: Option<(String, String)>, // text for true and false flags; None writes JSON booleans or true/false
+}
+
+impl Default for ValueFormat {
+	fn default() -> Self {
+		ValueFormat {
+			null_text: None,
+			date_format: None,
+			time_format: None,
+			timestamp_format: None,
+			decimal_separator: '.',
+			boolean_columns: Vec::new(),
+			boolean_text: None,
+		}
+	}
+}
+
+impl ValueFormat {
+	// Whether every value is written exactly as returned by the driver
+	pub fn is_default(&self) -> bool {
+		*self == ValueFormat::default()
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportOptions {
+	pub batch_rows: usize,   // the underlying writer is flushed after this many rows
+	pub objects: bool,       // NDJSON: write each row as a JSON object keyed by column name rather than a JSON array
+	pub header: bool,        // CSV: write a header line of column names
+	pub delimiter: char,     // CSV: field delimiter
+	pub format: ValueFormat, // rendering of NULLs, dates, numbers, and flags
+}
+
+impl Default for ExportOptions {
+	fn default() -> Self {
+		ExportOptions { batch_rows: DEFAULT_EXPORT_BATCH_ROWS, objects: false, header: true, delimiter: ',', format: ValueFormat::default() }
+	}
+}
+
+// A column value rendered according to a ValueFormat
+pub(crate) enum Rendered<'a> {
+	Raw(&'a str), // the JSON text returned by the driver, unchanged
+	Text(String),
+	Flag(bool),
+	Null,
+}
+
+// Parses a flag such as 1, 0, 'Y', 'N', 'T', 'F', 'true', or 'false'
+fn parse_flag(value: &ColumnValue) -> Option<bool> {
+	match value {
+		ColumnValue::Integer(n) => Some(*n != 0),
+		ColumnValue::Decimal(s) | ColumnValue::Varchar(s) => match s.trim().to_ascii_uppercase().as_str() {
+			"1" | "Y" | "YES" | "T" | "TRUE" => Some(true),
+			"0" | "N" | "NO" | "F" | "FALSE" => Some(false),
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+pub(crate) fn render<'a>(json: &'a str, column: &ColumnInfo, format: &ValueFormat) -> Result<Rendered<'a>, TeradataError> {
+	if format.is_default() {
+		return Ok(if json == "null" { Rendered::Null } else { Rendered::Raw(json) });
+	}
+	let value = ColumnValue::decode(json, column).map_err(TeradataError::Conversion)?;
+	if format.boolean_columns.iter().any(|name| name.eq_ignore_ascii_case(&column.name)) && !value.is_null() {
+		return match (parse_flag(&value), &format.boolean_text) {
+			(Some(flag), Some((true_text, false_text))) => Ok(Rendered::Text(if flag { true_text.clone() } else { false_text.clone() })),
+			(Some(flag), None) => Ok(Rendered::Flag(flag)),
+			(None, _) => Err(TeradataError::Conversion(format!("Could not convert column {} value {} to a flag", column.name, json))),
+		};
+	}
+	let rendered = match &value {
+		ColumnValue::Null => match &format.null_text {
+			Some(text) => Rendered::Text(text.clone()),
+			None => Rendered::Null,
+		},
+		ColumnValue::Date(date) if format.date_format.is_some() => Rendered::Text(date.format(format.date_format.as_deref().unwrap())),
+		ColumnValue::Time(time) if format.time_format.is_some() => Rendered::Text(time.format(format.time_format.as_deref().unwrap())),
+		ColumnValue::Timestamp(timestamp) if format.timestamp_format.is_some() => Rendered::Text(timestamp.format(format.timestamp_format.as_deref().unwrap())),
+		ColumnValue::Decimal(_) | ColumnValue::Float(_) if format.decimal_separator != '.' => {
+			let digits = json.trim().trim_matches('"');
+			Rendered::Text(digits.replace('.', &format.decimal_separator.to_string()))
+		}
+		_ => Rendered::Raw(json),
+	};
+	Ok(rendered)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportSummary {
+	pub rows_written: u64,
+	pub bytes_written: u64,
+}
+
+// Returns the JSON text of a rendered column value
+fn rendered_json(rendered: Rendered<'_>) -> String {
+	match rendered {
+		Rendered::Raw(json) => json.to_string(),
+		Rendered::Text(text) => Value::String(text).to_string(),
+		Rendered::Flag(flag) => flag.to_string(),
+		Rendered::Null => "null".to_string(),
+	}
+}
+
+// Returns a JSON array or, when keys are supplied, a JSON object for the row. Without a value
+// format, column values are copied as raw JSON text without decoding them.
+fn row_json_text(row_json: &str, keys: Option<&[String]>, columns: &[ColumnInfo], format: &ValueFormat) -> Result<String, TeradataError> {
+	let row = Row::from_json(row_json.to_string())?;
+	let expected = keys.map_or(columns.len(), |keys| keys.len());
+	if row.len() != expected {
+		return Err(TeradataError::Conversion(format!("Row has {} columns but {} columns were described", row.len(), expected)));
+	}
+	let mut text = String::with_capacity(row_json.len() + keys.map_or(0, |keys| keys.iter().map(|k| k.len() + 2).sum::<usize>()));
+	text.push(if keys.is_some() { '{' } else { '[' });
+	for index in 0..row.len() {
+		if index > 0 {
+			text.push(',');
+		}
+		if let Some(keys) = keys {
+			text.push_str(&keys[index]);
+			text.push(':');
+		}
+		let json = row.column_json(index).unwrap_or("null");
+		match columns.get(index) {
+			Some(column) if !format.is_default() => text.push_str(&rendered_json(render(json, column, format)?)),
+			_ => text.push_str(json),
+		}
+	}
+	text.push(if keys.is_some() { '}' } else { ']' });
+	Ok(text)
+}
+
+// Writes rows as newline-delimited JSON to any io::Write, one row per line. Rows are written
+// as they are received and the writer is flushed after every batch, so memory use does not
+// grow with the number of rows.
+pub struct NdjsonWriter<W: Write> {
+	writer: W,
+	options: ExportOptions,
+	keys: Option<Vec<String>>, // JSON-encoded column names, when writing objects
+	columns: Vec<ColumnInfo>,  // column descriptions, when rendering with a value format
+	rows_in_batch: usize,
+	summary: ExportSummary,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+	// column_names is required when options.objects is true. Use with_columns when
+	// options.format is not the default.
+	pub fn new(writer: W, column_names: &[String], options: ExportOptions) -> Result<Self, TeradataError> {
+		if !options.format.is_default() {
+			return Err(TeradataError::InvalidArgument("A value format requires column descriptions; use NdjsonWriter::with_columns".to_string()));
+		}
+		let columns: Vec<ColumnInfo> = column_names.iter().map(|name| ColumnInfo { name: name.clone(), ..ColumnInfo::default() }).collect();
+		NdjsonWriter::with_columns(writer, &columns, options)
+	}
+
+	// Creates a writer that renders values according to options.format using the column
+	// descriptions, such as from ResultMetadata::columns
+	pub fn with_columns(writer: W, columns: &[ColumnInfo], options: ExportOptions) -> Result<Self, TeradataError> {
+		if options.batch_rows == 0 {
+			return Err(TeradataError::InvalidArgument("batch_rows must be greater than zero".to_string()));
+		}
+		let keys = if options.objects {
+			Some(columns.iter().map(|column| Value::String(column.name.clone()).to_string()).collect())
+		} else {
+			None
+		};
+		let columns = if options.format.is_default() { Vec::new() } else { columns.to_vec() };
+		Ok(NdjsonWriter { writer, options, keys, columns, rows_in_batch: 0, summary: ExportSummary::default() })
+	}
+
+	fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), TeradataError> {
+		match self.writer.write_all(bytes) {
+			Ok(()) => {
+				self.summary.bytes_written += bytes.len() as u64;
+				Ok(())
+			}
+			Err(err) => Err(TeradataError::Io(format!("Could not write export row: {}", err))),
+		}
+	}
+
+	// Writes one row, given as the JSON array text returned by rustgo_fetch_row_wrapper
+	pub fn write_row(&mut self, row_json: &str) -> Result<(), TeradataError> {
+		if self.keys.is_none() && self.columns.is_empty() {
+			self.write_bytes(row_json.as_bytes())?;
+		} else {
+			let text = row_json_text(row_json, self.keys.as_deref(), &self.columns, &self.options.format)?;
+			self.write_bytes(text.as_bytes())?;
+		}
+		self.write_bytes(b"\n")?;
+		self.summary.rows_written += 1;
+
+		self.rows_in_batch += 1;
+		if self.rows_in_batch >= self.options.batch_rows {
+			self.flush()?;
+		}
+		Ok(())
+	}
+
+	pub fn flush(&mut self) -> Result<(), TeradataError> {
+		self.rows_in_batch = 0;
+		self.writer.flush().map_err(|err| TeradataError::Io(format!("Could not flush export writer: {}", err)))
+	}
+
+	pub fn summary(&self) -> ExportSummary {
+		self.summary
+	}
+
+	// Flushes and returns the underlying writer, such as a compression encoder that needs finishing
+	pub fn finish(mut self) -> Result<W, TeradataError> {
+		self.flush()?;
+		Ok(self.writer)
+	}
+}
+
+fn result_columns(u_log: u64, rows_handle: u64) -> Result<Vec<ColumnInfo>, TeradataError> {
+	match rustgo_result_metadata_wrapper(u_log, rows_handle) {
+		Ok((_, _, _, column_metadata)) => parse_column_info(&column_metadata),
+		Err(err) => Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err)),
+	}
+}
+
+// Writes every remaining row of the current result of rows_handle to the writer as
+// newline-delimited JSON
+pub fn export_ndjson<W: Write>(
+	u_log: u64,
+	rows_handle: u64,
+	writer: W,
+	options: ExportOptions,
+) -> Result<(ExportSummary, W), TeradataError> {
+
+	let columns = if options.objects || !options.format.is_default() {
+		result_columns(u_log, rows_handle)?
+	} else {
+		Vec::new()
+	};
+
+	let mut ndjson = NdjsonWriter::with_columns(writer, &columns, options)?;
+	loop {
+		match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+			Ok(Some(row)) => ndjson.write_row(&row)?,
+			Ok(None) => break,
+			Err(err) => {
+				return Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err));
+			}
+		}
+	}
+
+	let summary = ndjson.summary();
+	Ok((summary, ndjson.finish()?))
+
+} // end export_ndjson
+
+// Returns the plain text of a rendered column value; NULL is empty
+pub(crate) fn rendered_text(rendered: Rendered<'_>) -> Result<String, TeradataError> {
+	let text = match rendered {
+		Rendered::Raw(json) if json.starts_with('"') => match serde_json::from_str::<String>(json) {
+			Ok(s) => s,
+			Err(err) => {
+				return Err(TeradataError::Conversion(format!("Could not parse column JSON: {}", err)));
+			}
+		},
+		Rendered::Raw(json) => json.to_string(),
+		Rendered::Text(text) => text,
+		Rendered::Flag(flag) => flag.to_string(),
+		Rendered::Null => String::new(),
+	};
+	Ok(text)
+}
+
+// Returns the CSV text of a rendered column value, quoted when it contains the delimiter, a
+// quotation mark, or a line break
+fn csv_field(rendered: Rendered<'_>, delimiter: char) -> Result<String, TeradataError> {
+	let text = rendered_text(rendered)?;
+	if text.contains([delimiter, '"', '\n', '\r']) {
+		Ok(format!("\"{}\"", text.replace('"', "\"\"")))
+	} else {
+		Ok(text)
+	}
+}
+
+// Writes rows as CSV to any io::Write, one row per line, with an optional header line of column
+// names. Like NdjsonWriter, rows are written as they are received and the writer is flushed
+// after every batch.
+pub struct CsvWriter<W: Write> {
+	writer: W,
+	options: ExportOptions,
+	columns: Vec<ColumnInfo>,
+	rows_in_batch: usize,
+	summary: ExportSummary,
+}
+
+impl<W: Write> CsvWriter<W> {
+	// Creates a writer for rows with the described columns, such as from ResultMetadata::columns,
+	// and writes the header line if options.header is true
+	pub fn new(writer: W, columns: &[ColumnInfo], options: ExportOptions) -> Result<Self, TeradataError> {
+		if options.batch_rows == 0 {
+			return Err(TeradataError::InvalidArgument("batch_rows must be greater than zero".to_string()));
+		}
+		if matches!(options.delimiter, '"' | '\n' | '\r') {
+			return Err(TeradataError::InvalidArgument(format!("{:?} cannot be used as the CSV delimiter", options.delimiter)));
+		}
+		let mut csv = CsvWriter { writer, options, columns: columns.to_vec(), rows_in_batch: 0, summary: ExportSummary::default() };
+		if csv.options.header {
+			let names: Vec<String> = csv.columns.iter().map(|column| csv_field(Rendered::Text(column.name.clone()), csv.options.delimiter)).collect::<Result<_, _>>()?;
+			let line = names.join(&csv.options.delimiter.to_string());
+			csv.write_bytes(line.as_bytes())?;
+			csv.write_bytes(b"\n")?;
+		}
+		Ok(csv)
+	}
+
+	fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), TeradataError> {
+		match self.writer.write_all(bytes) {
+			Ok(()) => {
+				self.summary.bytes_written += bytes.len() as u64;
+				Ok(())
+			}
+			Err(err) => Err(TeradataError::Io(format!("Could not write export row: {}", err))),
+		}
+	}
+
+	// Writes one row, given as the JSON array text returned by rustgo_fetch_row_wrapper
+	pub fn write_row(&mut self, row_json: &str) -> Result<(), TeradataError> {
+		let row = Row::from_json(row_json.to_string())?;
+		if row.len() != self.columns.len() {
+			return Err(TeradataError::Conversion(format!("Row has {} columns but {} columns were described", row.len(), self.columns.len())));
+		}
+		let mut line = String::with_capacity(row_json.len());
+		for (index, column) in self.columns.iter().enumerate() {
+			if index > 0 {
+				line.push(self.options.delimiter);
+			}
+			let json = row.column_json(index).unwrap_or("null");
+			line.push_str(&csv_field(render(json, column, &self.options.format)?, self.options.delimiter)?);
+		}
+		line.push('\n');
+		self.write_bytes(line.as_bytes())?;
+		self.summary.rows_written += 1;
+
+		self.rows_in_batch += 1;
+		if self.rows_in_batch >= self.options.batch_rows {
+			self.flush()?;
+		}
+		Ok(())
+	}
+
+	pub fn flush(&mut self) -> Result<(), TeradataError> {
+		self.rows_in_batch = 0;
+		self.writer.flush().map_err(|err| TeradataError::Io(format!("Could not flush export writer: {}", err)))
+	}
+
+	pub fn summary(&self) -> ExportSummary {
+		self.summary
+	}
+
+	// Flushes and returns the underlying writer, such as a compression encoder that needs finishing
+	pub fn finish(mut self) -> Result<W, TeradataError> {
+		self.flush()?;
+		Ok(self.writer)
+	}
+}
+
+// Writes every remaining row of the current result of rows_handle to the writer as CSV
+pub fn export_csv<W: Write>(
+	u_log: u64,
+	rows_handle: u64,
+	writer: W,
+	options: ExportOptions,
+) -> Result<(ExportSummary, W), TeradataError> {
+
+	let columns = result_columns(u_log, rows_handle)?;
+
+	let mut csv = CsvWriter::new(writer, &columns, options)?;
+	loop {
+		match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+			Ok(Some(row)) => csv.write_row(&row)?,
+			Ok(None) => break,
+			Err(err) => {
+				return Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err));
+			}
+		}
+	}
+
+	let summary = csv.summary();
+	Ok((summary, csv.finish()?))
+
+} // end export_csv
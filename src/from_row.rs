@@ -0,0 +1,51 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::from_sql::{Coercion, FromSql};
+use crate::result::ColumnInfo;
+use crate::row::Row;
+
+// Construction of a Rust value from a fetched row. With the derive cargo feature,
+// #[derive(FromRow)] implements this trait for a struct with named fields, matching each field
+// to the column of the same name, ignoring case, or to the column named by
+// #[teradata(rename = "...")].
+pub trait FromRow: Sized {
+	fn from_row(row: &Row, columns: &[ColumnInfo]) -> Result<Self, String>;
+
+	// Constructs the value converting each column with the given coercion mode. Implementations
+	// that do not override it convert the same way in both modes.
+	fn from_row_with(row: &Row, columns: &[ColumnInfo], _coercion: Coercion) -> Result<Self, String> {
+		Self::from_row(row, columns)
+	}
+}
+
+// Decodes the value of the column with a name, matched ignoring case, and converts it with FromSql
+pub fn column_by_name<T: FromSql>(
+	row: &Row,
+	columns: &[ColumnInfo],
+	name: &str,
+) -> Result<T, String> {
+
+	column_by_name_with(row, columns, name, Coercion::Lenient)
+
+} // end column_by_name
+
+// Decodes the value of the column with a name, matched ignoring case, and converts it with
+// FromSql using the given coercion mode
+pub fn column_by_name_with<T: FromSql>(
+	row: &Row,
+	columns: &[ColumnInfo],
+	name: &str,
+	coercion: Coercion,
+) -> Result<T, String> {
+
+	let index = match columns.iter().position(|column| column.name.eq_ignore_ascii_case(name)) {
+		Some(index) => index,
+		None => {
+			return Err(format!("Result has no column named {}", name));
+		}
+	};
+
+	let value = row.get_value(index, &columns[index])?;
+	T::from_sql_with(value, coercion).map_err(|err| format!("Could not convert column {}: {}", columns[index].name, err))
+
+} // end column_by_name_with
@@ -0,0 +1,241 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::str::FromStr;
+
+use crate::decimal::scaled_integer;
+use crate::temporal::{Date, Period, Time, Timestamp};
+use crate::value::Value;
+
+// How FromSql treats a conversion that can represent the value only approximately. Conversions
+// that would change the value itself, such as an out-of-range integer or a DECIMAL with a
+// fractional part to an integer type, return an error in both modes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Coercion {
+	#[default]
+	Lenient, // round to the nearest value of the target type, and drop the time or date part of a TIMESTAMP
+	Strict,  // return an error for any conversion that does not preserve the value exactly
+}
+
+// Conversion of a decoded column value to a Rust type, following the coercion matrix in the
+// README. Conversions that would lose information, such as an out-of-range integer, return an
+// error; conversions that lose only precision are allowed unless the Coercion is Strict. Use
+// Option<T> for columns that may be NULL.
+pub trait FromSql: Sized {
+	fn from_sql(value: Value) -> Result<Self, String>;
+
+	// Converts the value with the given coercion mode. Types that do not override it convert the
+	// same way in both modes.
+	fn from_sql_with(value: Value, _coercion: Coercion) -> Result<Self, String> {
+		Self::from_sql(value)
+	}
+}
+
+pub(crate) fn mismatch<T>(value: &Value, rust_type: &str) -> Result<T, String> {
+	match value {
+		Value::Null => Err(format!("NULL cannot be converted to {}; use Option<{}>", rust_type, rust_type)),
+		_ => Err(format!("{:?} cannot be converted to {}", value, rust_type)),
+	}
+}
+
+impl FromSql for Value {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Ok(value)
+	}
+}
+
+impl<T: FromSql> FromSql for Option<T> {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Null => Ok(None),
+			value => T::from_sql(value).map(Some),
+		}
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		match value {
+			Value::Null => Ok(None),
+			value => T::from_sql_with(value, coercion).map(Some),
+		}
+	}
+}
+
+pub(crate) fn inexact<T>(value: &Value, rust_type: &str) -> Result<T, String> {
+	Err(format!("{:?} cannot be converted to {} exactly, which strict coercion requires", value, rust_type))
+}
+
+// Number of significant decimal digits in decimal digits such as "-0.01230" or "1.5E+3"
+fn significant_digits(digits: &str) -> usize {
+	let mantissa = digits.split(['e', 'E']).next().unwrap_or_default();
+	let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+	digits.trim_start_matches('0').trim_end_matches('0').len()
+}
+
+// Converts a DECIMAL value to an integer. Lenient coercion accepts fractional digits that are all
+// zero, such as "12.00"; strict coercion accepts only values without fractional digits, as
+// returned for a column with a scale of 0.
+fn decimal_to_integer(digits: &str, coercion: Coercion) -> Result<i128, String> {
+	if coercion == Coercion::Strict && digits.contains('.') {
+		return Err(format!("Decimal value {} has fractional digits, which strict coercion does not convert to an integer", digits));
+	}
+	scaled_integer(digits, 0).map_err(|_| format!("Decimal value {} is not a whole number", digits))
+}
+
+// Converts a FLOAT value that is a whole number to an integer
+fn float_to_integer(f: f64) -> Result<i128, String> {
+	let n = f as i128;
+	if !f.is_finite() || n as f64 != f {
+		return Err(format!("Float value {} is not a whole number", f));
+	}
+	Ok(n)
+}
+
+fn is_midnight(timestamp: &Timestamp) -> bool {
+	timestamp.hour == 0 && timestamp.minute == 0 && timestamp.second == 0 && timestamp.microsecond == 0 && timestamp.offset_minutes.is_none()
+}
+
+macro_rules! from_sql_integer {
+	($($t:ty),*) => {
+		$(
+			impl FromSql for $t {
+				fn from_sql(value: Value) -> Result<Self, String> {
+					Self::from_sql_with(value, Coercion::Lenient)
+				}
+
+				fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+					let n = match value {
+						Value::Integer(n) => n as i128,
+						Value::Decimal(ref digits) => decimal_to_integer(digits, coercion)?,
+						Value::Float(f) => float_to_integer(f)?,
+						value => return mismatch(&value, stringify!($t)),
+					};
+					<$t>::try_from(n).map_err(|_| format!("{} is out of range for {}", n, stringify!($t)))
+				}
+			}
+		)*
+	};
+}
+
+from_sql_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl FromSql for f64 {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		let strict = coercion == Coercion::Strict;
+		match value {
+			Value::Float(f) => Ok(f),
+			// f64 represents every integer up to 2^53 exactly
+			Value::Integer(n) if strict && n.unsigned_abs() > 1 << 53 => inexact(&value, "f64"),
+			Value::Integer(n) => Ok(n as f64),
+			// f64 preserves up to 15 significant decimal digits
+			Value::Decimal(ref digits) if strict && significant_digits(digits) > 15 => inexact(&value, "f64"),
+			Value::Decimal(ref digits) => match digits.parse::<f64>() {
+				Ok(f) if f.is_finite() => Ok(f),
+				_ => Err(format!("Decimal value {} is out of range for f64", digits)),
+			},
+			value => mismatch(&value, "f64"),
+		}
+	}
+}
+
+impl FromSql for f32 {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		let strict = coercion == Coercion::Strict;
+		let f = match value {
+			Value::Float(f) if strict && f as f32 as f64 != f && f.is_finite() => return inexact(&value, "f32"),
+			Value::Float(f) => f,
+			// f32 represents every integer up to 2^24 exactly
+			Value::Integer(n) if strict && n.unsigned_abs() > 1 << 24 => return inexact(&value, "f32"),
+			Value::Integer(n) => n as f64,
+			// f32 preserves up to 6 significant decimal digits
+			Value::Decimal(ref digits) if strict && significant_digits(digits) > 6 => return inexact(&value, "f32"),
+			Value::Decimal(ref digits) => digits.parse::<f64>().map_err(|_| format!("Invalid decimal value {}", digits))?,
+			value => return mismatch(&value, "f32"),
+		};
+		if f.is_finite() && (f as f32).is_infinite() {
+			return Err(format!("{} is out of range for f32", f));
+		}
+		Ok(f as f32)
+	}
+}
+
+// Every value with a text form converts to a String exactly
+impl FromSql for String {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Varchar(s) | Value::Decimal(s) => Ok(s),
+			Value::Integer(n) => Ok(n.to_string()),
+			Value::Float(f) => Ok(f.to_string()),
+			Value::Date(date) => Ok(date.to_string()),
+			Value::Time(time) => Ok(time.to_string()),
+			Value::Timestamp(timestamp) => Ok(timestamp.to_string()),
+			value => mismatch(&value, "String"),
+		}
+	}
+}
+
+impl FromSql for Vec<u8> {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Bytes(bytes) => Ok(bytes),
+			value => mismatch(&value, "Vec<u8>"),
+		}
+	}
+}
+
+impl FromSql for Date {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		match value {
+			Value::Date(date) => Ok(date),
+			// Strict coercion accepts only a TIMESTAMP at midnight without a time zone
+			Value::Timestamp(timestamp) if coercion == Coercion::Strict && !is_midnight(&timestamp) => inexact(&value, "Date"),
+			Value::Timestamp(timestamp) => Ok(timestamp.date()),
+			value => mismatch(&value, "Date"),
+		}
+	}
+}
+
+impl FromSql for Time {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		match value {
+			Value::Time(time) => Ok(time),
+			Value::Timestamp(_) if coercion == Coercion::Strict => inexact(&value, "Time"),
+			Value::Timestamp(timestamp) => Ok(timestamp.time()),
+			value => mismatch(&value, "Time"),
+		}
+	}
+}
+
+impl FromSql for Timestamp {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Timestamp(timestamp) => Ok(timestamp),
+			Value::Date(date) => Ok(Timestamp::from(date)),
+			value => mismatch(&value, "Timestamp"),
+		}
+	}
+}
+
+// PERIOD values are returned as strings, such as "('2025-01-01', '2025-12-31')"
+impl<T: FromStr<Err = String>> FromSql for Period<T> {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Varchar(s) => s.parse(),
+			value => mismatch(&value, "Period"),
+		}
+	}
+}
@@ -0,0 +1,108 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::env;
+use std::time::{Duration, Instant};
+
+use crate::connect::TIMEOUT_ERROR_PREFIX;
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::options::QueryOptions;
+use crate::raw::GOSIDE_LIBRARY;
+use crate::session::query_scalar_string;
+use crate::version::driver_version;
+use crate::{load_driver, LIB_DIR_ENV_VAR};
+
+// Request that healthcheck executes on the database
+pub const HEALTHCHECK_QUERY: &str = "select 1";
+
+// Phases of healthcheck, in the order they run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HealthPhase {
+	LoadDriver,
+	Connect,
+	Query,
+}
+
+// Outcome of healthcheck. The duration of a phase is recorded whether or not it succeeded, so a
+// slow failure can be told apart from a fast one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HealthReport {
+	pub load_driver: Option<Duration>,    // None if the driver library was already loaded
+	pub connect: Option<Duration>,        // None if the check failed before connecting
+	pub query: Option<Duration>,          // None if the check failed before the query
+	pub total: Duration,
+	pub database_version: Option<String>, // as reported by the database at logon
+	pub driver_version: Option<String>,
+	pub failed_phase: Option<HealthPhase>,
+	pub error: Option<TeradataError>,     // why the failed phase failed
+}
+
+impl HealthReport {
+	pub fn is_healthy(&self) -> bool {
+		self.error.is_none()
+	}
+
+	fn fail(mut self, phase: HealthPhase, error: TeradataError, started: Instant) -> HealthReport {
+		self.failed_phase = Some(phase);
+		self.error = Some(error);
+		self.total = started.elapsed();
+		self
+	}
+}
+
+// Checks that the database can be reached and answers a request, for readiness probes of
+// services that depend on it: loads the driver library if it is not loaded, from the directory
+// named by the TERADATASQL_LIB_DIR environment variable or else the current directory, connects,
+// and executes HEALTHCHECK_QUERY. The timeout covers connecting and the query. The connection is
+// closed before returning. Failures are reported in the HealthReport rather than returned as
+// errors, so the report always has the phase durations.
+pub fn healthcheck(
+	connect_params_json: &str,
+	timeout: Duration,
+) -> HealthReport {
+
+	let started = Instant::now();
+	let deadline = started + timeout;
+	let mut report = HealthReport::default();
+
+	if GOSIDE_LIBRARY.get().is_none() {
+		let lib_dir = env::var(LIB_DIR_ENV_VAR).ok().filter(|dir| !dir.trim().is_empty()).unwrap_or_else(|| ".".to_string());
+		let phase_started = Instant::now();
+		let loaded = load_driver(&lib_dir);
+		report.load_driver = Some(phase_started.elapsed());
+		// Another thread may have loaded the library in the meantime
+		if let Err(err) = loaded && GOSIDE_LIBRARY.get().is_none() {
+			return report.fail(HealthPhase::LoadDriver, err, started);
+		}
+	}
+
+	let remaining = deadline.saturating_duration_since(Instant::now());
+	if remaining.is_zero() {
+		let err = TeradataError::Timeout(format!("{} health check did not connect within {:?}", TIMEOUT_ERROR_PREFIX, timeout));
+		return report.fail(HealthPhase::Connect, err, started);
+	}
+	let phase_started = Instant::now();
+	let opened = Connection::open_with_timeout(connect_params_json, remaining);
+	report.connect = Some(phase_started.elapsed());
+	let conn = match opened {
+		Ok(conn) => conn,
+		Err(err) => return report.fail(HealthPhase::Connect, err, started),
+	};
+
+	// Both versions are cached by the driver and do not need a round trip to the database
+	report.database_version = query_scalar_string(conn.u_log(), conn.conn_handle(), &Escape::DatabaseVersion.native()).ok().flatten();
+	report.driver_version = driver_version(conn.u_log(), conn.conn_handle()).ok();
+
+	let phase_started = Instant::now();
+	let queried = conn.query_result_sets_with_options(HEALTHCHECK_QUERY, "null", &QueryOptions::new().deadline(deadline));
+	report.query = Some(phase_started.elapsed());
+	if let Err(err) = queried {
+		return report.fail(HealthPhase::Query, err, started);
+	}
+
+	drop(conn);
+	report.total = started.elapsed();
+	report
+
+} // end healthcheck
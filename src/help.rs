@@ -0,0 +1,203 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::result::fetch_result_sets;
+use crate::sql::{quote_identifier, quote_qualified_name};
+
+// Column of a table or view, as reported by HELP TABLE and HELP COLUMN
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpColumn {
+	pub name: String,
+	pub type_code: String,              // data type code, such as "CV" or "I"
+	pub nullable: bool,
+	pub format: Option<String>,         // FORMAT string, such as "X(100)"
+	pub title: Option<String>,
+	pub comment: Option<String>,
+	pub max_length: Option<u64>,        // maximum length in bytes
+	pub total_digits: Option<u32>,      // precision of DECIMAL and NUMBER columns
+	pub fractional_digits: Option<u32>, // scale of DECIMAL and NUMBER columns
+	pub default_value: Option<String>,
+	pub udt_name: Option<String>,       // type name of UDT columns
+}
+
+// Index of a table, as reported by HELP INDEX
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpIndex {
+	pub name: Option<String>,           // None for unnamed indexes
+	pub index_id: Option<u32>,
+	pub unique: bool,
+	pub primary: bool,                  // primary index, otherwise secondary
+	pub column_names: Vec<String>,
+	pub approximate_count: Option<u64>, // approximate number of distinct index values
+}
+
+// Session attributes, as reported by HELP SESSION. Attributes without a typed field are
+// available by their HELP SESSION column name in attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpSession {
+	pub user_name: Option<String>,
+	pub account_name: Option<String>,
+	pub current_database: Option<String>,
+	pub character_set: Option<String>,
+	pub transaction_semantics: Option<String>, // "Teradata" or "ANSI"
+	pub collation: Option<String>,
+	pub time_zone: Option<String>,
+	pub attributes: Vec<(String, String)>,     // every non-null attribute, by column name
+}
+
+// One row of HELP output, with values looked up by column name
+struct HelpRow<'a> {
+	names: &'a [String],
+	values: Vec<Value>,
+}
+
+impl HelpRow<'_> {
+	fn get(&self, name: &str) -> Option<String> {
+		let index = self.names.iter().position(|n| n.eq_ignore_ascii_case(name))?;
+		match self.values.get(index) {
+			Some(Value::String(s)) if !s.trim().is_empty() => Some(s.trim().to_string()),
+			Some(Value::Number(n)) => Some(n.to_string()),
+			_ => None,
+		}
+	}
+
+	fn get_number<T: std::str::FromStr>(&self, name: &str) -> Option<T> {
+		self.get(name).and_then(|s| s.replace(',', "").parse().ok())
+	}
+
+	fn get_flag(&self, name: &str, yes: &str) -> bool {
+		self.get(name).is_some_and(|s| s.eq_ignore_ascii_case(yes))
+	}
+}
+
+// Executes a HELP statement and calls f with each row of each result
+fn for_each_help_row<F: FnMut(&HelpRow)>(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	mut f: F,
+) -> Result<(), TeradataError> {
+
+	for result in fetch_result_sets(u_log, conn_handle, request_text, "null")? {
+		let names = result.column_names()?;
+		for row in &result.rows {
+			let values: Vec<Value> = match serde_json::from_str(row) {
+				Ok(values) => values,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse HELP row JSON: {}", err)));
+				}
+			};
+			f(&HelpRow { names: &names, values });
+		}
+	}
+
+	Ok(())
+
+} // end for_each_help_row
+
+fn help_columns(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<Vec<HelpColumn>, TeradataError> {
+
+	let mut columns = Vec::new();
+	for_each_help_row(u_log, conn_handle, request_text, |row| {
+		if let Some(name) = row.get("Column Name") {
+			columns.push(HelpColumn {
+				name,
+				type_code: row.get("Type").unwrap_or_default(),
+				nullable: row.get_flag("Nullable", "Y"),
+				format: row.get("Format"),
+				title: row.get("Title"),
+				comment: row.get("Comment"),
+				max_length: row.get_number("Max Length"),
+				total_digits: row.get_number("Decimal Total Digits"),
+				fractional_digits: row.get_number("Decimal Fractional Digits"),
+				default_value: row.get("Default value"),
+				udt_name: row.get("UDT Name"),
+			});
+		}
+	})?;
+	Ok(columns)
+
+} // end help_columns
+
+// Returns the columns of a table or view, such as "db.tab", using HELP TABLE
+pub fn help_table(
+	u_log: u64,
+	conn_handle: u64,
+	table_name: &str,
+) -> Result<Vec<HelpColumn>, TeradataError> {
+
+	help_columns(u_log, conn_handle, &format!("help table {}", quote_qualified_name(table_name)))
+
+} // end help_table
+
+// Returns one column of a table or view using HELP COLUMN, or None if the column does not exist
+pub fn help_column(
+	u_log: u64,
+	conn_handle: u64,
+	table_name: &str,
+	column_name: &str,
+) -> Result<Option<HelpColumn>, TeradataError> {
+
+	let request_text = format!("help column {}.{}", quote_qualified_name(table_name), quote_identifier(column_name));
+	Ok(help_columns(u_log, conn_handle, &request_text)?.into_iter().next())
+
+} // end help_column
+
+// Returns the indexes of a table, such as "db.tab", using HELP INDEX
+pub fn help_index(
+	u_log: u64,
+	conn_handle: u64,
+	table_name: &str,
+) -> Result<Vec<HelpIndex>, TeradataError> {
+
+	let mut indexes = Vec::new();
+	for_each_help_row(u_log, conn_handle, &format!("help index {}", quote_qualified_name(table_name)), |row| {
+		indexes.push(HelpIndex {
+			name: row.get("Index Name"),
+			index_id: row.get_number("Index Id"),
+			unique: row.get_flag("Unique?", "Y"),
+			primary: row.get_flag("Primary or Secondary?", "P"),
+			column_names: row.get("Column Names").map(|s| s.split(',').map(|n| n.trim().to_string()).collect()).unwrap_or_default(),
+			approximate_count: row.get_number("Approximate Count"),
+		});
+	})?;
+	Ok(indexes)
+
+} // end help_index
+
+// Returns the attributes of the current session using HELP SESSION
+pub fn help_session(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<HelpSession, TeradataError> {
+
+	let mut session = None;
+	for_each_help_row(u_log, conn_handle, "help session", |row| {
+		if session.is_some() {
+			return;
+		}
+		let attributes = row.names.iter().filter_map(|name| row.get(name).map(|value| (name.clone(), value))).collect();
+		session = Some(HelpSession {
+			user_name: row.get("User Name"),
+			account_name: row.get("Account Name"),
+			current_database: row.get("Current DataBase"),
+			character_set: row.get("Character Set"),
+			transaction_semantics: row.get("Transaction Semantics"),
+			collation: row.get("Collation"),
+			time_zone: row.get("Session Time Zone"),
+			attributes,
+		});
+	})?;
+
+	match session {
+		Some(session) => Ok(session),
+		None => Err(TeradataError::RowCount("No rows returned by HELP SESSION".to_string())),
+	}
+
+} // end help_session
@@ -0,0 +1,117 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Client-side parameter inlining: an opt-in emulation of bind values for statements that the
+// database cannot parameterize, such as DDL. Values are rendered as typed SQL literals by this
+// module only; arbitrary SQL text cannot be passed as a value. Prefer bind values whenever the
+// database accepts them, because inlined requests are not reused from the request cache.
+
+use serde_json::Value;
+
+use crate::sql::{parameter_markers, quote_literal, validate_parameter_count};
+use crate::temporal::{Date, Time, Timestamp};
+
+// A value to be inlined as a SQL literal
+#[derive(Debug, Clone, PartialEq)]
+pub enum InlineLiteral {
+	Null,
+	Integer(i64),
+	Decimal(String), // decimal digits, such as "-123.45" or "1.5E3"
+	Float(f64),      // must be finite
+	String(String),
+	Bytes(Vec<u8>),
+	Date(Date),
+	Time(Time),
+	Timestamp(Timestamp),
+}
+
+fn is_decimal_digits(s: &str) -> bool {
+	let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+	let (mantissa, exponent) = match s.find(['e', 'E']) {
+		Some(i) => (&s[..i], Some(&s[i + 1..])),
+		None => (s, None),
+	};
+	let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+	let digits = |p: &str| p.bytes().all(|b| b.is_ascii_digit());
+	let exponent_ok = exponent.is_none_or(|e| {
+		let e = e.strip_prefix(['+', '-']).unwrap_or(e);
+		!e.is_empty() && digits(e)
+	});
+	!(whole.is_empty() && fraction.is_empty()) && digits(whole) && digits(fraction) && exponent_ok
+}
+
+impl InlineLiteral {
+	// Converts a JSON bind value. Arrays and objects are rejected, and booleans are rendered
+	// as 1 or 0 because the database has no boolean literal.
+	pub fn from_json(value: &Value) -> Result<InlineLiteral, String> {
+		match value {
+			Value::Null => Ok(InlineLiteral::Null),
+			Value::Bool(b) => Ok(InlineLiteral::Integer(*b as i64)),
+			Value::Number(n) => match n.as_i64() {
+				Some(i) => Ok(InlineLiteral::Integer(i)),
+				None => Ok(InlineLiteral::Decimal(n.to_string())),
+			},
+			Value::String(s) => Ok(InlineLiteral::String(s.clone())),
+			Value::Array(_) | Value::Object(_) => Err(format!("Cannot inline JSON value {} as a SQL literal", value)),
+		}
+	}
+
+	// Returns the SQL literal text, such as 123, 'O''Brien', or DATE '2025-12-25'
+	pub fn to_sql(&self) -> Result<String, String> {
+		match self {
+			InlineLiteral::Null => Ok("NULL".to_string()),
+			InlineLiteral::Integer(i) => Ok(i.to_string()),
+			InlineLiteral::Decimal(s) => {
+				if !is_decimal_digits(s.trim()) {
+					return Err(format!("Invalid decimal literal {:?}", s));
+				}
+				Ok(s.trim().to_string())
+			}
+			InlineLiteral::Float(f) => {
+				if !f.is_finite() {
+					return Err(format!("Cannot inline non-finite float {}", f));
+				}
+				Ok(format!("{:E}", f))
+			}
+			InlineLiteral::String(s) => {
+				if s.contains('\0') {
+					return Err("Cannot inline a string containing a NUL character".to_string());
+				}
+				Ok(quote_literal(s))
+			}
+			InlineLiteral::Bytes(bytes) => Ok(format!("'{}'XB", bytes.iter().map(|b| format!("{:02X}", b)).collect::<String>())),
+			InlineLiteral::Date(d) => Ok(format!("DATE '{}'", d)),
+			InlineLiteral::Time(t) => Ok(format!("TIME '{}'", t)),
+			InlineLiteral::Timestamp(ts) => Ok(format!("TIMESTAMP '{}'", ts)),
+		}
+	}
+}
+
+// Returns the request text with each question-mark parameter marker replaced by the
+// corresponding literal. Markers within character literals, quoted identifiers, and comments
+// are left alone. Returns an error if the number of values does not match the number of markers.
+pub fn inline_parameters(request_text: &str, values: &[InlineLiteral]) -> Result<String, String> {
+	validate_parameter_count(request_text, values.len())?;
+
+	let mut inlined = String::with_capacity(request_text.len());
+	let mut last = 0;
+	for (offset, value) in parameter_markers(request_text).into_iter().zip(values) {
+		inlined.push_str(&request_text[last..offset]);
+		inlined.push_str(&value.to_sql()?);
+		last = offset + 1;
+	}
+	inlined.push_str(&request_text[last..]);
+	Ok(inlined)
+}
+
+// Same as inline_parameters, with the values given as a JSON array of bind values for one row,
+// such as "[123,\"abc\",null]"
+pub fn inline_json_parameters(request_text: &str, bind_values: &str) -> Result<String, String> {
+	let values: Vec<Value> = match serde_json::from_str(bind_values) {
+		Ok(values) => values,
+		Err(err) => {
+			return Err(format!("Could not parse bind values JSON array: {}", err));
+		}
+	};
+	let literals = values.iter().map(InlineLiteral::from_json).collect::<Result<Vec<_>, String>>()?;
+	inline_parameters(request_text, &literals)
+}
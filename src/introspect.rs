@@ -0,0 +1,58 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::result::fetch_result_sets;
+use crate::session::query_scalar_string;
+
+// Returns the request text that the driver sends to the database after processing escape
+// syntax, such as "{fn left('abc',1)}", without executing the request
+pub fn native_sql(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<String, TeradataError> {
+
+	match query_scalar_string(u_log, conn_handle, &Escape::NativeSql.apply(request_text))? {
+		Some(s) => Ok(s),
+		None => Err(TeradataError::RowCount(format!("No native SQL returned for {}", request_text))),
+	}
+
+} // end native_sql
+
+// Returns the DDL of every object that a request references, with every object name qualified
+// by its database and views resolved to the objects they reference, using SHOW QUALIFIED. The
+// request is not executed. Lineage tools can use the DDL to find the tables a request reads and
+// writes. Each string is the DDL of one object, with line breaks normalized to "\n".
+pub fn show_qualified(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<Vec<String>, TeradataError> {
+
+	let mut ddl = Vec::new();
+
+	for result in fetch_result_sets(u_log, conn_handle, &format!("show qualified {}", request_text), "null")? {
+		let mut text = String::new();
+		for row in &result.rows {
+			let row: Vec<Value> = match serde_json::from_str(row) {
+				Ok(row) => row,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse SHOW QUALIFIED row JSON: {}", err)));
+				}
+			};
+			if let Some(Value::String(s)) = row.first() {
+				text.push_str(s);
+			}
+		}
+		let text = text.replace("\r\n", "\n").replace('\r', "\n");
+		if !text.trim().is_empty() {
+			ddl.push(text.trim_end().to_string());
+		}
+	}
+
+	Ok(ddl)
+
+} // end show_qualified
@@ -1,360 +1,159 @@
 // Copyright 2025 by Teradata Corporation. All Rights Reserved.
 
-use std::backtrace::Backtrace;
-use std::collections::HashMap;
 use std::env;
-use std::ffi::{CString, CStr};
 use std::fs;
 use std::mem;
-use std::os::raw::{c_char, c_ulonglong, c_ushort};
 use std::path::PathBuf;
-use std::ptr;
 use std::sync::Arc;
-use std::sync::OnceLock;
 use libloading::{Library, Symbol};
-use serde_json;
-
-// Function pointer types matching the C function signatures
-
-type GoCombineJSON = unsafe extern "C" fn(
-	json1: *const c_char,
-	json2: *const c_char,
-	error: *mut *mut c_char,
-	combined: *mut *mut c_char,
-);
-
-type GoParseParams = unsafe extern "C" fn(
-	params: *const c_char,
-	error: *mut *mut c_char,
-	log: *mut c_ulonglong,
-);
-
-type GoCreateConnection = unsafe extern "C" fn(
-	log: c_ulonglong,
-	version: *const c_char,
-	params: *const c_char,
-	error: *mut *mut c_char,
-	conn_handle: *mut c_ulonglong,
-);
-
-type GoCloseConnection = unsafe extern "C" fn(
-	log: c_ulonglong,
-	conn_handle: c_ulonglong,
-	error: *mut *mut c_char,
-);
-
-type GoCancelRequest = unsafe extern "C" fn(
-	log: c_ulonglong,
-	conn_handle: c_ulonglong,
-	error: *mut *mut c_char,
-);
-
-type RustGoCreateRows = unsafe extern "C" fn(
-	log: c_ulonglong,
-	conn_handle: c_ulonglong,
-	request_text: *const c_char,
-	bind_values: *const c_char,
-	error: *mut *mut c_char,
-	rows_handle: *mut c_ulonglong,
-);
-
-type RustGoResultMetaData = unsafe extern "C" fn(
-	log: c_ulonglong,
-	rows_handle: c_ulonglong,
-	error: *mut *mut c_char,
-	activity_count: *mut c_ulonglong,
-	activity_type: *mut c_ushort,
-	activity_name: *mut *mut c_char,
-	column_metadata: *mut *mut c_char,
-);
-
-type RustGoFetchRow = unsafe extern "C" fn(
-	log: c_ulonglong,
-	rows_handle: c_ulonglong,
-	error: *mut *mut c_char,
-	column_values: *mut *mut c_char,
-);
-
-type GoNextResult = unsafe extern "C" fn(
-	log: c_ulonglong,
-	rows_handle: c_ulonglong,
-	error: *mut *mut c_char,
-	avail: *mut c_char,
-);
-
-type GoCloseRows = unsafe extern "C" fn(
-	log: c_ulonglong,
-	rows_handle: c_ulonglong,
-	error: *mut *mut c_char,
-);
-
-type GoFreePointer = unsafe extern "C" fn(
-	log: c_ulonglong,
-	ptr: *mut c_char,
-);
-
-static GOSIDE_LIBRARY: OnceLock<Arc<Library>> = OnceLock::new();
-
-static GO_COMBINE_JSON: OnceLock<Symbol<'static, GoCombineJSON>> = OnceLock::new();
-static GO_PARSE_PARAMS: OnceLock<Symbol<'static, GoParseParams>> = OnceLock::new();
-static GO_CREATE_CONNECTION: OnceLock<Symbol<'static, GoCreateConnection>> = OnceLock::new();
-static GO_CLOSE_CONNECTION: OnceLock<Symbol<'static, GoCloseConnection>> = OnceLock::new();
-static GO_CANCEL_REQUEST: OnceLock<Symbol<'static, GoCancelRequest>> = OnceLock::new();
-static RUSTGO_CREATE_ROWS: OnceLock<Symbol<'static, RustGoCreateRows>> = OnceLock::new();
-static RUSTGO_RESULT_METADATA: OnceLock<Symbol<'static, RustGoResultMetaData>> = OnceLock::new();
-static RUSTGO_FETCH_ROW: OnceLock<Symbol<'static, RustGoFetchRow>> = OnceLock::new();
-static GO_NEXT_RESULT: OnceLock<Symbol<'static, GoNextResult>> = OnceLock::new();
-static GO_CLOSE_ROWS: OnceLock<Symbol<'static, GoCloseRows>> = OnceLock::new();
-static GO_FREE_POINTER: OnceLock<Symbol<'static, GoFreePointer>> = OnceLock::new();
-
-// Rust wrapper for goCombineJSON
-fn go_combine_json_wrapper(
-	json1: &str,
-	json2: &str,
-) -> Result<String, String> {
-	let c_json1 = CString::new(json1).unwrap();
-	let c_json2 = CString::new(json2).unwrap();
-	let mut error: *mut c_char = ptr::null_mut();
-	let mut combined: *mut c_char = ptr::null_mut();
-	unsafe {
-		GO_COMBINE_JSON.get().unwrap()(
-			c_json1.as_ptr(),
-			c_json2.as_ptr(),
-			&mut error,
-			&mut combined,
-		);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(0, error);
-			return Err(err_str);
-		}
-		let result = CStr::from_ptr(combined).to_string_lossy().into_owned();
-		go_free_pointer_wrapper(0, combined);
-		Ok(result)
-	}
-}
-
-// Rust wrapper for goParseParams
-fn go_parse_params_wrapper(
-	params: &str,
-) -> Result<u64, String> {
-	let c_params = CString::new(params).unwrap();
-	let mut error: *mut c_char = ptr::null_mut();
-	let mut u_log: u64 = 0;
-	unsafe {
-		GO_PARSE_PARAMS.get().unwrap()(
-			c_params.as_ptr(),
-			&mut error,
-			&mut u_log,
-		);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		Ok(u_log)
-	}
-}
-
-// Rust wrapper for goCreateConnection
-fn go_create_connection_wrapper(
-	u_log: u64,
-	version: &str,
-	params: &str,
-) -> Result<u64, String> {
-	let c_version = CString::new(version).unwrap();
-	let c_params = CString::new(params).unwrap();
-	let mut error: *mut c_char = ptr::null_mut();
-	let mut conn_handle: u64 = 0;
-	unsafe {
-		GO_CREATE_CONNECTION.get().unwrap()(
-			u_log,
-			c_version.as_ptr(),
-			c_params.as_ptr(),
-			&mut error,
-			&mut conn_handle,
-		);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		Ok(conn_handle)
-	}
-}
 
-// Rust wrapper for goCloseConnection
-pub fn go_close_connection_wrapper(
-	u_log: u64,
-	conn_handle: u64,
-) -> Result<(), String> {
-	let mut error: *mut c_char = ptr::null_mut();
-	unsafe {
-		GO_CLOSE_CONNECTION.get().unwrap()(u_log, conn_handle, &mut error);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		Ok(())
-	}
-}
-
-// Rust wrapper for goCancelRequest
-pub fn go_cancel_request_wrapper(
-	u_log: u64,
-	conn_handle: u64,
-) -> Result<(), String> {
-	let mut error: *mut c_char = ptr::null_mut();
-	unsafe {
-		GO_CANCEL_REQUEST.get().unwrap()(u_log, conn_handle, &mut error);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		Ok(())
-	}
-}
-
-// Rust wrapper for rustgoCreateRows
-pub fn rustgo_create_rows_wrapper(
-	u_log: u64,
-	conn_handle: u64,
-	request_text: &str,
-	bind_values: &str,
-) -> Result<u64, String> {
-	let c_request_text = CString::new(request_text).unwrap();
-	let c_bind_values = CString::new(bind_values).unwrap();
-	let mut error: *mut c_char = ptr::null_mut();
-	let mut rows_handle: u64 = 0;
-	unsafe {
-		RUSTGO_CREATE_ROWS.get().unwrap()(
-			u_log,
-			conn_handle,
-			c_request_text.as_ptr(),
-			c_bind_values.as_ptr(),
-			&mut error,
-			&mut rows_handle,
-		);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		Ok(rows_handle)
-	}
-}
-
-// Rust wrapper for rustgoResultMetaData
-pub fn rustgo_result_metadata_wrapper(
-	u_log: u64,
-	rows_handle: u64,
-) -> Result<(u64, u16, String, String), String> {
-	let mut error: *mut c_char = ptr::null_mut();
-	let mut activity_count: u64 = 0;
-	let mut activity_type: u16 = 0;
-	let mut activity_name: *mut c_char = ptr::null_mut();
-	let mut column_metadata: *mut c_char = ptr::null_mut();
-	unsafe {
-		RUSTGO_RESULT_METADATA.get().unwrap()(
-			u_log,
-			rows_handle,
-			&mut error,
-			&mut activity_count,
-			&mut activity_type,
-			&mut activity_name,
-			&mut column_metadata,
-		);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		let activity_name_str = CStr::from_ptr(activity_name).to_string_lossy().into_owned();
-		let column_metadata_str = CStr::from_ptr(column_metadata).to_string_lossy().into_owned();
-		go_free_pointer_wrapper(u_log, activity_name);
-		go_free_pointer_wrapper(u_log, column_metadata);
-		Ok((activity_count, activity_type, activity_name_str, column_metadata_str))
-	}
-}
-
-// Rust wrapper for rustgoFetchRow
-pub fn rustgo_fetch_row_wrapper(
-	u_log: u64,
-	rows_handle: u64,
-) -> Result<Option<String>, String> {
-	let mut error: *mut c_char = ptr::null_mut();
-	let mut column_values: *mut c_char = ptr::null_mut();
-	unsafe {
-		RUSTGO_FETCH_ROW.get().unwrap()(
-			u_log,
-			rows_handle,
-			&mut error,
-			&mut column_values,
-		);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		if column_values.is_null() {
-			// No more rows to fetch
-			return Ok(None);
-		}
-		let column_values_str = CStr::from_ptr(column_values).to_string_lossy().into_owned();
-		go_free_pointer_wrapper(u_log, column_values);
-		Ok(Some(column_values_str))
-	}
-}
-
-// Rust wrapper for goNextResult
-pub fn go_next_result_wrapper(
-	u_log: u64,
-	rows_handle: u64,
-) -> Result<bool, String> {
-	let mut error: *mut c_char = ptr::null_mut();
-	let mut avail: c_char = 0;
-	unsafe {
-		GO_NEXT_RESULT.get().unwrap()(
-			u_log,
-			rows_handle,
-			&mut error,
-			&mut avail,
-		);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		Ok(avail == 'Y' as c_char)
-	}
-}
-
-// Rust wrapper for goCloseRows
-pub fn go_close_rows_wrapper(
-	u_log: u64,
-	rows_handle: u64,
-) -> Result<(), String> {
-	let mut error: *mut c_char = ptr::null_mut();
-	unsafe {
-		GO_CLOSE_ROWS.get().unwrap()(u_log, rows_handle, &mut error);
-		if !error.is_null() {
-			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
-			go_free_pointer_wrapper(u_log, error);
-			return Err(err_str);
-		}
-		Ok(())
-	}
-}
-
-// Rust wrapper for goFreePointer
-fn go_free_pointer_wrapper(
-	u_log: u64,
-	ptr: *mut c_char
-) {
-	unsafe { GO_FREE_POINTER.get().unwrap()(u_log, ptr); }
-}
+use raw::*;
+
+mod activity;
+pub mod admin;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "tokio")]
+pub mod r#async;
+mod batch;
+#[cfg(feature = "bb8")]
+pub mod bb8;
+#[cfg(any(feature = "bb8", feature = "deadpool", feature = "tokio"))]
+mod blocking;
+mod cache;
+mod cancel;
+mod capabilities;
+#[cfg(feature = "chrono")]
+mod chrono;
+mod client;
+mod columns;
+mod compare;
+mod connect;
+mod connect_params;
+mod connection;
+mod correlation;
+#[cfg(feature = "dbql")]
+pub mod dbql;
+mod ddl;
+#[cfg(feature = "diesel")]
+pub mod diesel;
+#[cfg(feature = "deadpool")]
+pub mod deadpool;
+mod decimal;
+mod error;
+pub mod escape;
+mod explain;
+mod export;
+mod from_row;
+mod from_sql;
+mod health;
+mod help;
+mod inline;
+mod introspect;
+mod limiter;
+mod logging;
+mod macros;
+mod metadata_cache;
+#[cfg(feature = "monitor")]
+pub mod monitor;
+mod multi;
+mod options;
+mod params;
+mod pool;
+mod profile;
+mod proxy;
+mod query_band;
+#[cfg(feature = "r2d2")]
+pub mod r2d2;
+pub mod raw;
+mod redrive;
+mod render;
+mod result;
+mod retry;
+mod row;
+mod row_hash;
+mod row_limit;
+mod rows;
+mod schema;
+mod session;
+mod settings;
+mod shutdown;
+mod spool;
+mod sql;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+mod statement;
+mod statement_cache;
+mod tee;
+mod temporal;
+#[cfg(feature = "time")]
+mod time;
+mod time_travel;
+mod transaction;
+mod truncate;
+mod udt;
+mod value;
+mod version;
+
+pub use activity::{ActivityCategory, ActivityKind, ActivityType};
+pub use batch::{BatchSummary, ChunkFailure, RowFailure, DEFAULT_BATCH_CHUNK_SIZE};
+pub use cache::{ResultCache, ResultCacheKey, ResultCacheOptions, ResultCacheStats};
+pub use cancel::{CancelTimer, CANCEL_GRACE};
+pub use capabilities::{driver_capabilities, Capabilities};
+pub use client::ClientAttributes;
+pub use columns::{CharacterSet, ColumnFormat};
+pub use compare::{compare_tables, compare_tables_with_options, CompareOptions, TableComparison, DEFAULT_MAX_REPORTED_ROWS};
+pub use connect::{is_timeout_error, TIMEOUT_ERROR_PREFIX};
+pub use connect_params::{combine_connect_params, ConnectParams, ReloadableConnectParams};
+pub use connection::Connection;
+pub use correlation::{new_correlation_id, Correlation, CORRELATION_ID_NAME};
+pub use ddl::DdlResult;
+pub use decimal::{decode_decimal, is_decimal_column, DecimalMode, DecimalOptions, DecimalValue};
+pub use error::TeradataError;
+pub use explain::{Confidence, ExplainPlan, ExplainStep};
+pub use export::{CsvWriter, ExportOptions, ExportSummary, NdjsonWriter, ValueFormat, DEFAULT_EXPORT_BATCH_ROWS};
+pub use from_row::{column_by_name, column_by_name_with, FromRow};
+pub use from_sql::{Coercion, FromSql};
+pub use health::{healthcheck, HealthPhase, HealthReport, HEALTHCHECK_QUERY};
+pub use help::{HelpColumn, HelpIndex, HelpSession};
+pub use inline::{inline_json_parameters, inline_parameters, InlineLiteral};
+pub use limiter::{WorkloadLimiter, WorkloadPermit, WorkloadStats};
+pub use logging::{LogLevel, SharedLog};
+pub use macros::exec_macro_request_text;
+pub use metadata_cache::{CachedMetadata, MetadataCache, MetadataCacheStats, DEFAULT_METADATA_CACHE_CAPACITY};
+pub use multi::{LabeledResults, MultiStatementRequest};
+pub use options::{LobMode, QueryOptions};
+pub use params::{param_value, BindValues, Bytes, JsonValue, Param, ToParams};
+pub use pool::{Pool, PoolOptions, PoolStatus, PooledConnection, DEFAULT_POOL_ACQUIRE_TIMEOUT, DEFAULT_POOL_MAX_SIZE, DEFAULT_VALIDATION_QUERY, NATIVE_SQL_VALIDATION};
+pub use profile::SessionProfile;
+pub use proxy::ProxyUserGuard;
+pub use query_band::{auto_query_band, disable_auto_query_band, enable_auto_query_band, set_trace_id, trace_id, AutoQueryBand, CLIENT_HOST_NAME};
+pub use redrive::{ConnectionEndpoint, RedriveStatus};
+pub use render::{render_html, render_markdown, TableOptions};
+pub use result::{parse_column_info, ColumnInfo, ResultMetadata, ResultSet, ResultStats, TRADITIONAL_MAX_ROW_BYTES};
+pub use retry::{Idempotency, RetryPolicy};
+pub use row::{ColumnIndex, Row};
+pub use row_hash::{RowHashOptions, RowHasher};
+pub use row_limit::{RowLimit, Sample};
+pub use rows::Rows;
+pub use schema::{diff_schema, ExpectedColumn, ExpectedSchema, RetypedColumn, SchemaDiff};
+pub use session::{SessionInfo, SessionTimeZone, TimeZone, TransactionMode};
+pub use settings::SessionSettings;
+pub use shutdown::{shutdown, ShutdownMode, ShutdownReport};
+pub use sql::{count_parameters, parameter_markers, quote_identifier, quote_literal, quote_qualified_name, translate_named_parameters, validate_parameter_count};
+pub use spool::{SpoolOptions, SpooledRows, SpooledRowsIter, DEFAULT_SPOOL_MEMORY_LIMIT};
+pub use statement::{ParameterInfo, Statement, StatementInfo};
+pub use statement_cache::{StatementCacheStats, DEFAULT_STATEMENT_CACHE_CAPACITY};
+pub use tee::{FnSink, RowSink, DEFAULT_TEE_BUFFER_ROWS};
+pub use temporal::{Date, Period, Time, Timestamp};
+pub use time_travel::{TemporalPoint, TemporalQualifier, TransactionTime, ValidTime};
+pub use transaction::Transaction;
+pub use truncate::{truncate_for_column, truncate_utf8_bytes, Truncated};
+pub use udt::{StructValue, UdtAttribute, UdtKind, UdtType, UdtValue};
+pub use value::Value;
+pub use version::{version, VersionInfo};
+#[cfg(feature = "derive")]
+pub use teradatarustapi_derive::{FromRow, ToParams};
 
 fn get_extension() -> String {
 	let os_type = env::consts::OS.to_lowercase();
@@ -392,29 +191,59 @@ fn get_extension() -> String {
 	}
 } // end get_extension
 
+// Environment variable that overrides the driver library file name extension chosen by get_extension
+pub const LIB_EXTENSION_ENV_VAR: &str = "TERADATASQL_LIB_EXTENSION";
+
+// Environment variable naming the directory of the driver library, which healthcheck loads when
+// the library is not loaded yet
+pub const LIB_DIR_ENV_VAR: &str = "TERADATASQL_LIB_DIR";
+
+// Returns the driver library file name extension that load_driver will use, such as "so" or
+// "arm.so". The TERADATASQL_LIB_EXTENSION environment variable, when set and not empty,
+// takes precedence over the extension determined from the platform.
+pub fn driver_extension() -> String {
+	match env::var(LIB_EXTENSION_ENV_VAR) {
+		Ok(extension) if !extension.trim().is_empty() => extension.trim().trim_start_matches('.').to_string(),
+		_ => get_extension(),
+	}
+} // end driver_extension
+
 pub fn load_driver(
 	lib_dir: &str
-) -> Result<(), String> {
-	let extension = get_extension();
+) -> Result<(), TeradataError> {
+	load_driver_with_extension(lib_dir, &driver_extension())
+} // end load_driver
+
+// Loads the driver library with an explicit file name extension, such as "arm.fips.so", for
+// cross-compiled binaries, emulated environments, and platforms that get_extension misjudges
+pub fn load_driver_with_extension(
+	lib_dir: &str,
+	extension: &str,
+) -> Result<(), TeradataError> {
+	let extension = extension.trim_start_matches('.');
+	if extension.is_empty() {
+		return Err(TeradataError::InvalidArgument("Driver library extension must not be empty".to_string()));
+	}
 
 	let mut lib_path = PathBuf::from(lib_dir);
 	lib_path.push(format!("teradatasql.{}", extension));
 
 	// Only initialize the global library once
-	match unsafe { Library::new(lib_path) } {
+	match unsafe { Library::new(&lib_path) } {
 		Ok(lib) => {
-			GOSIDE_LIBRARY.set(Arc::new(lib)).map_err(|_| "Library already set".to_string())?;
+			GOSIDE_LIBRARY.set(Arc::new(lib)).map_err(|_| TeradataError::DriverLoad("Library already set".to_string()))?;
+			version::set_loaded_library(lib_path, extension);
 		},
 		Err(err) => {
-			return Err(format!("Could not load library: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not load library: {}", err)));
 		}
 	}
 
-	let go_combine_json_result         = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<GoCombineJSON>        ("goCombineJSON"       .as_bytes()) };
+	// goCombineJSON and goCancelRequest, which older driver libraries may not export, are
+	// resolved on first use; see driver_capabilities
 	let go_parse_params_result         = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<GoParseParams>        ("goParseParams"       .as_bytes()) };
 	let go_create_connection_result    = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<GoCreateConnection>   ("goCreateConnection"  .as_bytes()) };
 	let go_close_connection_result     = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<GoCloseConnection>    ("goCloseConnection"   .as_bytes()) };
-	let go_cancel_request_result       = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<GoCancelRequest>      ("goCancelRequest"     .as_bytes()) };
 	let rustgo_create_rows_result      = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<RustGoCreateRows>     ("rustgoCreateRows"    .as_bytes()) };
 	let rustgo_result_metadata_result  = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<RustGoResultMetaData> ("rustgoResultMetaData".as_bytes()) };
 	let rustgo_fetch_row_result        = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<RustGoFetchRow>       ("rustgoFetchRow"      .as_bytes()) };
@@ -422,233 +251,140 @@ pub fn load_driver(
 	let go_close_rows_result           = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<GoCloseRows>          ("goCloseRows"         .as_bytes()) };
 	let go_free_pointer_result         = unsafe { GOSIDE_LIBRARY.get().unwrap().get::<GoFreePointer>        ("goFreePointer"       .as_bytes()) };
 
-	match go_combine_json_result {
-		Ok(f) => {
-			GO_COMBINE_JSON.set(unsafe { mem::transmute::<Symbol<GoCombineJSON>, Symbol<'static, GoCombineJSON>>(f) }).map_err(|_| "goCombineJSON already set".to_string())?;
-		},
-		Err(err) => {
-			return Err(format!("Could not link to function goCombineJSON: {}", err));
-		}
-	}
-
 	match go_parse_params_result {
 		Ok(f) => {
-			GO_PARSE_PARAMS.set(unsafe { mem::transmute::<Symbol<GoParseParams>, Symbol<'static, GoParseParams>>(f) }).map_err(|_| "goParseParams already set".to_string())?;
+			GO_PARSE_PARAMS.set(unsafe { mem::transmute::<Symbol<GoParseParams>, Symbol<'static, GoParseParams>>(f) }).map_err(|_| TeradataError::DriverLoad("goParseParams already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function goParseParams: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function goParseParams: {}", err)));
 		}
 	}
 
 	match go_create_connection_result {
 		Ok(f) => {
-			GO_CREATE_CONNECTION.set(unsafe { mem::transmute::<Symbol<GoCreateConnection>, Symbol<'static, GoCreateConnection>>(f) }).map_err(|_| "goCreateConnection already set".to_string())?;
+			GO_CREATE_CONNECTION.set(unsafe { mem::transmute::<Symbol<GoCreateConnection>, Symbol<'static, GoCreateConnection>>(f) }).map_err(|_| TeradataError::DriverLoad("goCreateConnection already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function goCreateConnection: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function goCreateConnection: {}", err)));
 		}
 	}
 
 	match go_close_connection_result {
 		Ok(f) => {
-			GO_CLOSE_CONNECTION.set(unsafe { mem::transmute::<Symbol<GoCloseConnection>, Symbol<'static, GoCloseConnection>>(f) }).map_err(|_| "goCloseConnection already set".to_string())?;
+			GO_CLOSE_CONNECTION.set(unsafe { mem::transmute::<Symbol<GoCloseConnection>, Symbol<'static, GoCloseConnection>>(f) }).map_err(|_| TeradataError::DriverLoad("goCloseConnection already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function goCloseConnection: {}", err));
-		}
-	}
-
-	match go_cancel_request_result {
-		Ok(f) => {
-			GO_CANCEL_REQUEST.set(unsafe { mem::transmute::<Symbol<GoCancelRequest>, Symbol<'static, GoCancelRequest>>(f) }).map_err(|_| "goCancelRequest already set".to_string())?;
-		},
-		Err(err) => {
-			return Err(format!("Could not link to function goCancelRequest: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function goCloseConnection: {}", err)));
 		}
 	}
 
 	match rustgo_create_rows_result {
 		Ok(f) => {
-			RUSTGO_CREATE_ROWS.set(unsafe { mem::transmute::<Symbol<RustGoCreateRows>, Symbol<'static, RustGoCreateRows>>(f) }).map_err(|_| "rustgoCreateRows already set".to_string())?;
+			RUSTGO_CREATE_ROWS.set(unsafe { mem::transmute::<Symbol<RustGoCreateRows>, Symbol<'static, RustGoCreateRows>>(f) }).map_err(|_| TeradataError::DriverLoad("rustgoCreateRows already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function rustgoCreateRows: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function rustgoCreateRows: {}", err)));
 		}
 	}
 
 	match rustgo_result_metadata_result {
 		Ok(f) => {
-			RUSTGO_RESULT_METADATA.set(unsafe { mem::transmute::<Symbol<RustGoResultMetaData>, Symbol<'static, RustGoResultMetaData>>(f) }).map_err(|_| "rustgoResultMetaData already set".to_string())?;
+			RUSTGO_RESULT_METADATA.set(unsafe { mem::transmute::<Symbol<RustGoResultMetaData>, Symbol<'static, RustGoResultMetaData>>(f) }).map_err(|_| TeradataError::DriverLoad("rustgoResultMetaData already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function rustgoResultMetaData: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function rustgoResultMetaData: {}", err)));
 		}
 	}
 
 	match rustgo_fetch_row_result {
 		Ok(f) => {
-			RUSTGO_FETCH_ROW.set(unsafe { mem::transmute::<Symbol<RustGoFetchRow>, Symbol<'static, RustGoFetchRow>>(f) }).map_err(|_| "rustgoFetchRow already set".to_string())?;
+			RUSTGO_FETCH_ROW.set(unsafe { mem::transmute::<Symbol<RustGoFetchRow>, Symbol<'static, RustGoFetchRow>>(f) }).map_err(|_| TeradataError::DriverLoad("rustgoFetchRow already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function rustgoFetchRow: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function rustgoFetchRow: {}", err)));
 		}
 	}
 
 	match go_next_result_result {
 		Ok(f) => {
-			GO_NEXT_RESULT.set(unsafe { mem::transmute::<Symbol<GoNextResult>, Symbol<'static, GoNextResult>>(f) }).map_err(|_| "goNextResult already set".to_string())?;
+			GO_NEXT_RESULT.set(unsafe { mem::transmute::<Symbol<GoNextResult>, Symbol<'static, GoNextResult>>(f) }).map_err(|_| TeradataError::DriverLoad("goNextResult already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function goNextResult: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function goNextResult: {}", err)));
 		}
 	}
 
 	match go_close_rows_result {
 		Ok(f) => {
-			GO_CLOSE_ROWS.set(unsafe { mem::transmute::<Symbol<GoCloseRows>, Symbol<'static, GoCloseRows>>(f) }).map_err(|_| "goCloseRows already set".to_string())?;
+			GO_CLOSE_ROWS.set(unsafe { mem::transmute::<Symbol<GoCloseRows>, Symbol<'static, GoCloseRows>>(f) }).map_err(|_| TeradataError::DriverLoad("goCloseRows already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function goCloseRows: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function goCloseRows: {}", err)));
 		}
 	}
 
 	match go_free_pointer_result {
 		Ok(f) => {
-			GO_FREE_POINTER.set(unsafe { mem::transmute::<Symbol<GoFreePointer>, Symbol<'static, GoFreePointer>>(f) }).map_err(|_| "goFreePointer already set".to_string())?;
+			GO_FREE_POINTER.set(unsafe { mem::transmute::<Symbol<GoFreePointer>, Symbol<'static, GoFreePointer>>(f) }).map_err(|_| TeradataError::DriverLoad("goFreePointer already set".to_string()))?;
 		},
 		Err(err) => {
-			return Err(format!("Could not link to function goFreePointer: {}", err));
+			return Err(TeradataError::DriverLoad(format!("Could not link to function goFreePointer: {}", err)));
 		}
 	}
 
 	Ok(())
 
-} // end load_driver
-
-pub fn create_connection(
-	connect_params_json: &str,
-) -> Result<(u64, u64), String> {
-
-	// Backtrace::capture() captures a backtrace of the current OS thread according to the environment variable RUST_BACKTRACE
-	// If RUST_BACKTRACE is not set, then Backtrace::capture() returns a disabled backtrace
-	// Backtrace::force_capture() always forcibly captures a backtrace regardless of the RUST_BACKTRACE setting
-	let stack_trace = Backtrace::force_capture();
-	let stack_trace_str = format!("{}", stack_trace);
-
-	let mut abbrev_stack_trace_str = String::new();
-	for line in stack_trace_str.lines() {
-		// Trim leading and trailing whitespace
-		let trimmed_line = line.trim();
-		// Use regular expression to trim leading number and colon if present
-		let re = regex::Regex::new(r"^\d+:\s*").unwrap();
-		let trimmed_line = re.replace(trimmed_line, "").to_string();
-
-		// Replace all backslashes with forward slashes
-		let trimmed_line = trimmed_line.replace("\\", "/");
-
-		if trimmed_line == "std::rt::lang_start_internal" {
-			break;
-		}
-		if trimmed_line.starts_with("std::") || trimmed_line.starts_with("core::") {
-			continue;
-		}
-
-		// Trim "at " prefix if present
-		let trimmed_line = if trimmed_line.starts_with("at ") {
-			trimmed_line[3..].to_string()
-		} else {
-			trimmed_line
-		};
-
-		// Skip if trimmed_line contains /library/std/src/ or /library/core/src/
-		if trimmed_line.contains("/library/std/src/") || trimmed_line.contains("/library/core/src/") {
-			continue;
-		}
-
-		if !abbrev_stack_trace_str.is_empty() {
-			abbrev_stack_trace_str.insert_str(0, " ");
-		}
-
-		abbrev_stack_trace_str.insert_str(0, &trimmed_line);
-	}
-
-	let mut map = HashMap::new();
-	map.insert("client_kind", "U");
-	map.insert("client_stack", &abbrev_stack_trace_str);
-	let json_str = serde_json::to_string(&map).unwrap();
-
-	let combined_json = match go_combine_json_wrapper(connect_params_json, json_str.as_str()) {
-		Ok(combined_json) => combined_json,
-		Err(err) => {
-			return Err(format!("Error from go_combine_json_wrapper: {}", err));
-		}
-	};
-
-	// Call go_parse_params_wrapper with the result from go_combine_json_wrapper
-	let u_log = match go_parse_params_wrapper(combined_json.as_str()) {
-		Ok(u_log) => u_log,
-		Err(err) => {
-			return Err(format!("Error from go_parse_params_wrapper: {}", err));
-		}
-	};
+} // end load_driver_with_extension
 
-	let version_str = ""; // omit to use GoSQL Driver version
-	let conn_handle = match go_create_connection_wrapper(u_log, version_str, combined_json.as_str()) {
-		Ok(handle) => handle,
-		Err(err) => {
-			return Err(format!("Error from go_create_connection_wrapper: {}", err));
-		}
-	};
-
-	Ok((u_log, conn_handle))
-
-} // end create_connection
-
-fn execute_simple_request(
+pub(crate) fn execute_simple_request(
 	u_log: u64,
 	conn_handle: u64,
 	request_text: &str,
-) -> Result<(), String> {
+) -> Result<(), TeradataError> {
 
 	let rows_handle = match rustgo_create_rows_wrapper(u_log, conn_handle, request_text, "null") { // JSON null for no bind values
 		Ok(handle) => handle,
 		Err(err) => {
-			return Err(format!("Error from rustgo_create_rows_wrapper: {}", err));
+			return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
 		}
 	};
 
 	if let Err(err) = go_close_rows_wrapper(u_log, rows_handle) {
-		return Err(format!("Error from go_close_rows_wrapper: {}", err));
+		return Err(TeradataError::from_driver("go_close_rows_wrapper", err));
 	}
 
 	Ok(())
 
 } // end execute_simple_request
 
-pub fn commit(
-	u_log: u64,
-	conn_handle: u64,
-) -> Result<(), String> {
-
-	execute_simple_request(u_log, conn_handle, "{fn teradata_commit}")
-
-} // end commit
-
-pub fn rollback(
+// Executes a request and returns the first row of the first result, decoded from JSON
+pub(crate) fn fetch_first_row(
 	u_log: u64,
 	conn_handle: u64,
-) -> Result<(), String> {
+	request_text: &str,
+	bind_values: &str,
+) -> Result<Option<Vec<serde_json::Value>>, TeradataError> {
 
-	execute_simple_request(u_log, conn_handle, "{fn teradata_rollback}")
+	let rows_handle = match rustgo_create_rows_wrapper(u_log, conn_handle, request_text, bind_values) {
+		Ok(handle) => handle,
+		Err(err) => {
+			return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
+		}
+	};
 
-} // end rollback
+	let result = match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+		Ok(Some(row)) => match serde_json::from_str::<Vec<serde_json::Value>>(&row) {
+			Ok(values) => Ok(Some(values)),
+			Err(err) => Err(TeradataError::Conversion(format!("Could not parse row JSON: {}", err))),
+		},
+		Ok(None) => Ok(None),
+		Err(err) => Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err)),
+	};
 
-pub fn set_autocommit(
-	u_log: u64,
-	conn_handle: u64,
-	b: bool,
-) -> Result<(), String> {
+	if let Err(err) = go_close_rows_wrapper(u_log, rows_handle) && result.is_ok() {
+		return Err(TeradataError::from_driver("go_close_rows_wrapper", err));
+	}
 
-	execute_simple_request(u_log, conn_handle, &format!("{{fn teradata_nativesql}}{{fn teradata_autocommit_{}}}", if b { "on" } else { "off" }))
+	result
 
-} // end set_autocommit
+} // end fetch_first_row
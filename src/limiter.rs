@@ -0,0 +1,155 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::connect::TIMEOUT_ERROR_PREFIX;
+use crate::error::TeradataError;
+
+#[derive(Debug, Default)]
+struct LimiterState {
+	limits: HashMap<String, usize>,    // maximum in-flight requests by workload tag
+	in_flight: HashMap<String, usize>, // current in-flight requests by workload tag
+	waiting: HashMap<String, usize>,   // callers queued for a permit by workload tag
+}
+
+#[derive(Debug)]
+struct LimiterShared {
+	state: Mutex<LimiterState>,
+	released: Condvar,
+}
+
+// Limits the number of concurrent in-flight requests per workload tag, so that a burst of one
+// kind of work, such as report queries, cannot use every connection a service has and starve
+// other work. Tags without a configured limit are not limited. Cloning a limiter shares its
+// limits and permits, so one limiter can govern every connection of a service, each connection
+// taking a permit for every request with Connection::set_workload.
+#[derive(Debug, Clone)]
+pub struct WorkloadLimiter {
+	shared: Arc<LimiterShared>,
+}
+
+// Permission to run one request for a workload tag, released when dropped
+#[derive(Debug)]
+pub struct WorkloadPermit {
+	shared: Arc<LimiterShared>,
+	tag: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadStats {
+	pub tag: String,
+	pub limit: Option<usize>,
+	pub in_flight: usize,
+	pub waiting: usize,
+}
+
+impl Default for WorkloadLimiter {
+	fn default() -> Self {
+		WorkloadLimiter::new()
+	}
+}
+
+impl WorkloadLimiter {
+	pub fn new() -> Self {
+		WorkloadLimiter {
+			shared: Arc::new(LimiterShared { state: Mutex::new(LimiterState::default()), released: Condvar::new() }),
+		}
+	}
+
+	pub fn limit(self, tag: &str, max_in_flight: usize) -> Result<Self, TeradataError> {
+		self.set_limit(tag, Some(max_in_flight))?;
+		Ok(self)
+	}
+
+	// Changes or removes the limit of a tag. Raising or removing a limit wakes queued callers;
+	// lowering a limit does not affect requests already in flight. A limit of zero, which no
+	// request could ever pass, is rejected.
+	pub fn set_limit(&self, tag: &str, max_in_flight: Option<usize>) -> Result<(), TeradataError> {
+		if max_in_flight == Some(0) {
+			return Err(TeradataError::InvalidArgument(format!("The limit of workload {} must be greater than zero", tag)));
+		}
+		let mut state = self.shared.state.lock().unwrap();
+		match max_in_flight {
+			Some(max) => state.limits.insert(tag.to_string(), max),
+			None => state.limits.remove(tag),
+		};
+		self.shared.released.notify_all();
+		Ok(())
+	}
+
+	// Waits until a request of the tag may run, queuing behind other requests of the same tag
+	// for at most the timeout. Returns TeradataError::Timeout if no permit became available in time.
+	pub fn acquire(&self, tag: &str, timeout: Duration) -> Result<WorkloadPermit, TeradataError> {
+		let deadline = Instant::now() + timeout;
+		let mut state = self.shared.state.lock().unwrap();
+		*state.waiting.entry(tag.to_string()).or_default() += 1;
+
+		loop {
+			let in_flight = state.in_flight.get(tag).copied().unwrap_or(0);
+			let available = state.limits.get(tag).is_none_or(|&max| in_flight < max);
+			if available {
+				*state.waiting.get_mut(tag).unwrap() -= 1;
+				*state.in_flight.entry(tag.to_string()).or_default() += 1;
+				return Ok(WorkloadPermit { shared: Arc::clone(&self.shared), tag: tag.to_string() });
+			}
+
+			let now = Instant::now();
+			if now >= deadline {
+				*state.waiting.get_mut(tag).unwrap() -= 1;
+				return Err(TeradataError::Timeout(format!(
+					"{} no permit for workload {} became available within {:?} ({} requests in flight)",
+					TIMEOUT_ERROR_PREFIX, tag, timeout, in_flight
+				)));
+			}
+			state = self.shared.released.wait_timeout(state, deadline - now).unwrap().0;
+		}
+	}
+
+	// Takes a permit only if one is available without waiting
+	pub fn try_acquire(&self, tag: &str) -> Option<WorkloadPermit> {
+		self.acquire(tag, Duration::ZERO).ok()
+	}
+
+	pub fn stats(&self, tag: &str) -> WorkloadStats {
+		let state = self.shared.state.lock().unwrap();
+		WorkloadStats {
+			tag: tag.to_string(),
+			limit: state.limits.get(tag).copied(),
+			in_flight: state.in_flight.get(tag).copied().unwrap_or(0),
+			waiting: state.waiting.get(tag).copied().unwrap_or(0),
+		}
+	}
+}
+
+// The limiter and workload tag under which every request of a connection takes a permit, set
+// with Connection::set_workload
+#[derive(Debug, Clone)]
+pub(crate) struct RequestWorkload {
+	pub(crate) limiter: WorkloadLimiter,
+	pub(crate) tag: String,
+	pub(crate) timeout: Duration, // wait for a permit before a request fails
+}
+
+impl RequestWorkload {
+	pub(crate) fn acquire(&self) -> Result<WorkloadPermit, TeradataError> {
+		self.limiter.acquire(&self.tag, self.timeout)
+	}
+}
+
+impl WorkloadPermit {
+	pub fn tag(&self) -> &str {
+		&self.tag
+	}
+}
+
+impl Drop for WorkloadPermit {
+	fn drop(&mut self) {
+		let mut state = self.shared.state.lock().unwrap();
+		if let Some(in_flight) = state.in_flight.get_mut(&self.tag) {
+			*in_flight = in_flight.saturating_sub(1);
+		}
+		self.shared.released.notify_all();
+	}
+}
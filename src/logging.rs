@@ -0,0 +1,96 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::ops::BitOr;
+
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::execute_simple_request;
+use crate::raw::go_parse_params_wrapper;
+use crate::session::query_scalar_string;
+
+// Driver log level bitmask, as defined by the log connection parameter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LogLevel(pub u64);
+
+impl LogLevel {
+	pub const OFF: LogLevel = LogLevel(0);
+	pub const TRACE: LogLevel = LogLevel(1);    // function and method tracing
+	pub const DEBUG: LogLevel = LogLevel(2);    // debug logging
+	pub const HEX_DUMP: LogLevel = LogLevel(4); // transmit and receive message hex dumps
+	pub const TIMING: LogLevel = LogLevel(8);   // timing
+
+	pub fn contains(&self, other: LogLevel) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl BitOr for LogLevel {
+	type Output = LogLevel;
+
+	fn bitor(self, rhs: LogLevel) -> LogLevel {
+		LogLevel(self.0 | rhs.0)
+	}
+}
+
+// A log bitmask parsed once and shared by the connections opened with create_connection_with_log
+// or Connection::open_with_log, which do not parse their connection parameters with
+// go_parse_params_wrapper. The u_log is a value rather than a driver resource, so a SharedLog
+// needs no closing and can be copied freely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SharedLog {
+	level: LogLevel,
+}
+
+impl SharedLog {
+	// Parses the log bitmask from the log connection parameter of a connection parameter JSON string
+	pub fn parse(connect_params_json: &str) -> Result<SharedLog, TeradataError> {
+		match go_parse_params_wrapper(connect_params_json) {
+			Ok(u_log) => Ok(SharedLog { level: LogLevel(u_log) }),
+			Err(err) => Err(TeradataError::from_driver("go_parse_params_wrapper", err)),
+		}
+	}
+
+	// Uses a log level without calling the driver
+	pub fn new(level: LogLevel) -> SharedLog {
+		SharedLog { level }
+	}
+
+	pub fn level(&self) -> LogLevel {
+		self.level
+	}
+
+	pub fn u_log(&self) -> u64 {
+		self.level.0
+	}
+}
+
+// Changes the driver log level for one connection. The log bitmask is also passed by the
+// caller to every wrapper function, so the returned value must be used as the u_log
+// argument for subsequent calls on this connection.
+pub fn set_driver_log_level(
+	u_log: u64,
+	conn_handle: u64,
+	level: LogLevel,
+) -> Result<u64, TeradataError> {
+
+	execute_simple_request(u_log, conn_handle, &Escape::SetLogLevel(level.0).native())?;
+
+	Ok(level.0)
+
+} // end set_driver_log_level
+
+// Returns the connection's current driver log level
+pub fn driver_log_level(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<LogLevel, TeradataError> {
+
+	match query_scalar_string(u_log, conn_handle, &Escape::GetLogLevel.native())? {
+		Some(s) => match s.trim().parse::<u64>() {
+			Ok(level) => Ok(LogLevel(level)),
+			Err(_) => Err(TeradataError::Conversion(format!("Could not parse log level {}", s))),
+		},
+		None => Ok(LogLevel::OFF),
+	}
+
+} // end driver_log_level
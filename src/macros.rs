@@ -0,0 +1,42 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::params::ToParams;
+use crate::result::{fetch_result_sets, ResultSet};
+use crate::sql::quote_qualified_name;
+
+// Returns the EXEC statement for a macro with the specified number of parameters, such as
+// exec "db"."m"(?, ?)
+pub fn exec_macro_request_text(macro_name: &str, param_count: usize) -> String {
+	let name = quote_qualified_name(macro_name);
+	if param_count == 0 {
+		format!("exec {}", name)
+	} else {
+		format!("exec {}({})", name, vec!["?"; param_count].join(", "))
+	}
+}
+
+// Executes a macro, binding params to its parameters in order, and returns one result set
+// per statement in the macro. The macro name may be qualified with a database name, such as
+// "db.m"; each part is quoted as an identifier.
+pub fn exec_macro<P: ToParams>(
+	u_log: u64,
+	conn_handle: u64,
+	macro_name: &str,
+	params: P,
+) -> Result<Vec<ResultSet>, TeradataError> {
+
+	let values = params.to_params().map_err(TeradataError::Conversion)?;
+
+	let request_text = exec_macro_request_text(macro_name, values.len());
+	let bind_values = if values.is_empty() {
+		"null".to_string()
+	} else {
+		Value::Array(vec![Value::Array(values)]).to_string()
+	};
+
+	fetch_result_sets(u_log, conn_handle, &request_text, &bind_values)
+
+} // end exec_macro
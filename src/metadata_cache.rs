@@ -0,0 +1,133 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::result::parse_column_names;
+
+// Default number of statements whose metadata is cached
+pub const DEFAULT_METADATA_CACHE_CAPACITY: usize = 256;
+
+// Parsed column metadata of one result of a statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedMetadata {
+	pub column_metadata: String, // column metadata JSON that was parsed
+	pub column_names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct MetadataKey {
+	conn_handle: u64,
+	statement_hash: u64,
+	result_index: usize,
+}
+
+#[derive(Default)]
+struct MetadataState {
+	entries: HashMap<MetadataKey, Arc<CachedMetadata>>,
+	order: VecDeque<MetadataKey>, // insertion order, for evicting the oldest entry
+	hits: u64,
+	misses: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetadataCacheStats {
+	pub entries: usize,
+	pub hits: u64,
+	pub misses: u64,
+}
+
+// Caches parsed column metadata by connection, statement text hash, and result index, so that
+// repeated executions of the same statement skip re-parsing the column metadata JSON. A cached
+// entry is only used when the result's column metadata JSON is identical to the JSON that was
+// parsed, so a table altered between executions is never described with stale metadata.
+pub struct MetadataCache {
+	capacity: usize,
+	state: Mutex<MetadataState>,
+}
+
+fn statement_hash(request_text: &str) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	request_text.hash(&mut hasher);
+	hasher.finish()
+}
+
+impl Default for MetadataCache {
+	fn default() -> Self {
+		MetadataCache::new(DEFAULT_METADATA_CACHE_CAPACITY)
+	}
+}
+
+impl MetadataCache {
+	pub fn new(capacity: usize) -> Self {
+		MetadataCache { capacity, state: Mutex::new(MetadataState::default()) }
+	}
+
+	// Returns the parsed metadata for a result of the statement, parsing column_metadata only
+	// if it is not already cached
+	pub fn get_or_parse(
+		&self,
+		conn: &Connection,
+		request_text: &str,
+		result_index: usize,
+		column_metadata: &str,
+	) -> Result<Arc<CachedMetadata>, TeradataError> {
+
+		let key = MetadataKey { conn_handle: conn.conn_handle(), statement_hash: statement_hash(request_text), result_index };
+
+		{
+			let mut state = self.state.lock().unwrap();
+			if let Some(entry) = state.entries.get(&key).cloned() && entry.column_metadata == column_metadata {
+				state.hits += 1;
+				return Ok(entry);
+			}
+			state.misses += 1;
+		}
+
+		let entry = Arc::new(CachedMetadata {
+			column_metadata: column_metadata.to_string(),
+			column_names: parse_column_names(column_metadata)?,
+		});
+
+		if self.capacity > 0 {
+			let mut state = self.state.lock().unwrap();
+			if state.entries.insert(key, Arc::clone(&entry)).is_none() {
+				state.order.push_back(key);
+			}
+			while state.entries.len() > self.capacity {
+				match state.order.pop_front() {
+					Some(oldest) => {
+						state.entries.remove(&oldest);
+					}
+					None => break,
+				}
+			}
+		}
+
+		Ok(entry)
+
+	} // end get_or_parse
+
+	// Removes every entry for a connection, such as before the connection is closed
+	pub fn invalidate_connection(&self, conn: &Connection) {
+		let conn_handle = conn.conn_handle();
+		let mut state = self.state.lock().unwrap();
+		state.entries.retain(|key, _| key.conn_handle != conn_handle);
+		state.order.retain(|key| key.conn_handle != conn_handle);
+	}
+
+	pub fn clear(&self) {
+		let mut state = self.state.lock().unwrap();
+		state.entries.clear();
+		state.order.clear();
+	}
+
+	pub fn stats(&self) -> MetadataCacheStats {
+		let state = self.state.lock().unwrap();
+		MetadataCacheStats { entries: state.entries.len(), hits: state.hits, misses: state.misses }
+	}
+}
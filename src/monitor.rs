@@ -0,0 +1,194 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Session monitoring through the database Monitor partition (PM/API). A MonitorSession
+// logs on with the partition connection parameter set to MONITOR; the logon user needs the
+// MONITOR privileges required by each request.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::connect_params::ConnectParams;
+use crate::create_connection;
+use crate::error::TeradataError;
+use crate::raw::go_close_connection_wrapper;
+use crate::result::{fetch_result_sets, ResultSet};
+
+// One row of a Monitor partition response, keyed by column name
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MonitorRecord {
+	pub columns: HashMap<String, Value>,
+}
+
+impl MonitorRecord {
+	// Column names are matched ignoring case
+	pub fn get(&self, name: &str) -> Option<&Value> {
+		self.columns.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v).filter(|v| !v.is_null())
+	}
+
+	pub fn get_str(&self, name: &str) -> Option<String> {
+		self.get(name).map(|v| match v {
+			Value::String(s) => s.trim().to_string(),
+			other => other.to_string(),
+		})
+	}
+
+	pub fn get_i64(&self, name: &str) -> Option<i64> {
+		match self.get(name)? {
+			Value::Number(n) => n.as_i64().or_else(|| n.as_f64().map(|f| f as i64)),
+			Value::String(s) => s.trim().parse().ok(),
+			_ => None,
+		}
+	}
+
+	pub fn get_f64(&self, name: &str) -> Option<f64> {
+		match self.get(name)? {
+			Value::Number(n) => n.as_f64(),
+			Value::String(s) => s.trim().parse().ok(),
+			_ => None,
+		}
+	}
+}
+
+// A session that is blocking another session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockingSession {
+	pub host_id: i64,
+	pub session_no: i64,
+	pub lock_mode: Option<String>,
+	pub object_type: Option<String>,
+}
+
+// State of one session as reported by MONITOR SESSION
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionStatus {
+	pub host_id: i64,
+	pub session_no: i64,
+	pub user_name: Option<String>,
+	pub account: Option<String>,
+	pub pe_state: Option<String>,
+	pub amp_state: Option<String>,
+	pub amp_cpu_seconds: Option<f64>,
+	pub blocked_by: Vec<BlockingSession>,
+	pub record: MonitorRecord, // every column of the response
+}
+
+impl SessionStatus {
+	pub fn is_blocked(&self) -> bool {
+		!self.blocked_by.is_empty()
+	}
+
+	fn from_record(record: MonitorRecord) -> Self {
+		let mut blocked_by = Vec::new();
+		for n in 1..=3 {
+			if let (Some(host_id), Some(session_no)) = (record.get_i64(&format!("Blk_{}_HostId", n)), record.get_i64(&format!("Blk_{}_SessNo", n))) && session_no != 0 {
+				blocked_by.push(BlockingSession {
+					host_id,
+					session_no,
+					lock_mode: record.get_str(&format!("Blk_{}_LMode", n)),
+					object_type: record.get_str(&format!("Blk_{}_OType", n)),
+				});
+			}
+		}
+		SessionStatus {
+			host_id: record.get_i64("HostId").unwrap_or_default(),
+			session_no: record.get_i64("SessionNo").unwrap_or_default(),
+			user_name: record.get_str("UserName"),
+			account: record.get_str("UserAccount"),
+			pe_state: record.get_str("PEState"),
+			amp_state: record.get_str("AMPState"),
+			amp_cpu_seconds: record.get_f64("AMPCPUSec"),
+			blocked_by,
+			record,
+		}
+	}
+}
+
+// Selects the sessions reported by MONITOR SESSION; None selects all
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionFilter {
+	pub host_id: Option<i16>,
+	pub session_no: Option<u32>,
+	pub user_name: Option<String>,
+}
+
+// AMP resource usage as reported by MONITOR VIRTUAL SUMMARY
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmpUsage {
+	pub amp_count: Option<i64>,
+	pub avg_amp_cpu_percent: Option<f64>,
+	pub high_amp_cpu_percent: Option<f64>,
+	pub low_amp_cpu_percent: Option<f64>,
+	pub record: MonitorRecord,
+}
+
+fn records(result: &ResultSet) -> Result<Vec<MonitorRecord>, TeradataError> {
+	let names = result.column_names()?;
+	let mut records = Vec::with_capacity(result.rows.len());
+	for row in &result.rows {
+		let values: Vec<Value> = match serde_json::from_str(row) {
+			Ok(values) => values,
+			Err(err) => {
+				return Err(TeradataError::Conversion(format!("Could not parse row JSON: {}", err)));
+			}
+		};
+		records.push(MonitorRecord { columns: names.iter().cloned().zip(values).collect() });
+	}
+	Ok(records)
+}
+
+// A Monitor partition connection, closed when dropped
+pub struct MonitorSession {
+	u_log: u64,
+	conn_handle: u64,
+}
+
+impl MonitorSession {
+	// Logs on to the Monitor partition using the specified connection parameters
+	pub fn open(params: &ConnectParams) -> Result<MonitorSession, TeradataError> {
+		let params = params.clone().set("partition", "MONITOR");
+		let (u_log, conn_handle) = create_connection(&params.to_json())?;
+		Ok(MonitorSession { u_log, conn_handle })
+	}
+
+	// Executes a PM/API request and returns the rows of every result
+	pub fn request(&self, request_text: &str, bind_values: &str) -> Result<Vec<MonitorRecord>, TeradataError> {
+		let mut all = Vec::new();
+		for result in fetch_result_sets(self.u_log, self.conn_handle, request_text, bind_values)? {
+			all.extend(records(&result)?);
+		}
+		Ok(all)
+	}
+
+	pub fn sessions(&self, filter: &SessionFilter) -> Result<Vec<SessionStatus>, TeradataError> {
+		let bind_values = Value::Array(vec![Value::Array(vec![
+			Value::from(filter.host_id.unwrap_or(-1)),
+			Value::from(filter.session_no.unwrap_or(0)),
+			Value::from(filter.user_name.clone().unwrap_or_else(|| "*".to_string())),
+		])]);
+		let records = self.request("MONITOR SESSION", &bind_values.to_string())?;
+		Ok(records.into_iter().map(SessionStatus::from_record).collect())
+	}
+
+	// Returns the sessions that are waiting on a lock held by another session
+	pub fn blocked_sessions(&self) -> Result<Vec<SessionStatus>, TeradataError> {
+		Ok(self.sessions(&SessionFilter::default())?.into_iter().filter(SessionStatus::is_blocked).collect())
+	}
+
+	pub fn amp_usage(&self) -> Result<Vec<AmpUsage>, TeradataError> {
+		let records = self.request("MONITOR VIRTUAL SUMMARY", "null")?;
+		Ok(records.into_iter().map(|record| AmpUsage {
+			amp_count: record.get_i64("AMPCount"),
+			avg_amp_cpu_percent: record.get_f64("AvgAMPCPUUse"),
+			high_amp_cpu_percent: record.get_f64("HighAMPCPUUse"),
+			low_amp_cpu_percent: record.get_f64("LowAMPCPUUse"),
+			record,
+		}).collect())
+	}
+}
+
+impl Drop for MonitorSession {
+	fn drop(&mut self) {
+		let _ = go_close_connection_wrapper(self.u_log, self.conn_handle);
+	}
+}
@@ -0,0 +1,213 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use serde_json::Value;
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::params::ToParams;
+use crate::result::{ResultMetadata, ResultSet};
+use crate::sql::validate_parameter_count;
+
+fn bind_values_json(values: Vec<Value>) -> String {
+	if values.is_empty() {
+		"null".to_string()
+	} else {
+		Value::Array(vec![Value::Array(values)]).to_string()
+	}
+}
+
+// Composes several labeled statements into one multi-statement request
+#[derive(Debug, Clone, Default)]
+pub struct MultiStatementRequest {
+	labels: Vec<String>,
+	statements: Vec<String>,
+	bind_values: Vec<Vec<Value>>, // bind values of each statement
+}
+
+// Results of a multi-statement request, in statement order, keyed by label
+#[derive(Debug, Clone, Default)]
+pub struct LabeledResults {
+	results: Vec<(String, ResultSet)>,
+}
+
+impl MultiStatementRequest {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	// Appends a statement without parameter markers
+	pub fn statement(self, label: &str, request_text: &str) -> Self {
+		self.push(label, request_text, Vec::new())
+	}
+
+	// Appends a statement along with the bind values for its question-mark parameter markers
+	pub fn statement_with_params<P: ToParams>(self, label: &str, request_text: &str, params: P) -> Result<Self, TeradataError> {
+		let values = params.to_params().map_err(|err| TeradataError::Conversion(format!("Statement {}: {}", label, err)))?;
+		match validate_parameter_count(request_text, values.len()) {
+			Ok(()) => Ok(self.push(label, request_text, values)),
+			Err(err) => Err(err.map_message(|message| format!("Statement {}: {}", label, message))),
+		}
+	}
+
+	fn push(mut self, label: &str, request_text: &str, values: Vec<Value>) -> Self {
+		self.labels.push(label.to_string());
+		self.statements.push(request_text.trim().trim_end_matches(';').trim_end().to_string());
+		self.bind_values.push(values);
+		self
+	}
+
+	pub fn labels(&self) -> &[String] {
+		&self.labels
+	}
+
+	// Returns the combined request text with statements separated by semicolons
+	pub fn request_text(&self) -> String {
+		self.statements.join(" ; ")
+	}
+
+	// Returns the combined bind values as a JSON array of arrays, or JSON null if there are none
+	pub fn bind_values(&self) -> String {
+		bind_values_json(self.bind_values.concat())
+	}
+
+	fn validate(&self) -> Result<(), TeradataError> {
+		if self.statements.is_empty() {
+			return Err(TeradataError::InvalidArgument("Multi-statement request contains no statements".to_string()));
+		}
+		for (i, label) in self.labels.iter().enumerate() {
+			if self.labels[..i].contains(label) {
+				return Err(TeradataError::InvalidArgument(format!("Duplicate statement label {}", label)));
+			}
+			if self.statements[i].is_empty() {
+				return Err(TeradataError::InvalidArgument(format!("Statement {} is empty", label)));
+			}
+		}
+		Ok(())
+	}
+
+	pub fn execute(
+		&self,
+		conn: &Connection,
+	) -> Result<LabeledResults, TeradataError> {
+
+		self.validate()?;
+
+		let results = conn.query_result_sets(&self.request_text(), &self.bind_values())?;
+
+		// Each statement is expected to produce exactly one result
+		if results.len() != self.labels.len() {
+			return Err(TeradataError::InvalidArgument(format!("Multi-statement request with {} statements returned {} results", self.labels.len(), results.len())));
+		}
+
+		Ok(LabeledResults { results: self.labels.iter().cloned().zip(results).collect() })
+
+	} // end execute
+
+	// Executes each statement as a separate request on its own new session, with at most
+	// max_sessions sessions at once, and returns the results in statement order. Suits
+	// independent queries, such as the statements of a dashboard, whose combined elapsed time
+	// matters more than the cost of additional logons. The statements do not share a
+	// transaction, volatile tables, or session settings. After a statement fails, statements
+	// not yet started are skipped, and the error of the first failed statement is returned.
+	pub fn execute_parallel(
+		&self,
+		connect_params_json: &str,
+		max_sessions: usize,
+	) -> Result<LabeledResults, TeradataError> {
+
+		self.validate()?;
+		if max_sessions == 0 {
+			return Err(TeradataError::InvalidArgument("max_sessions must be greater than zero".to_string()));
+		}
+
+		let next = AtomicUsize::new(0);
+		let failed = AtomicBool::new(false);
+		let outcomes: Vec<Mutex<Option<Result<ResultSet, TeradataError>>>> = self.statements.iter().map(|_| Mutex::new(None)).collect();
+		thread::scope(|scope| {
+			for _ in 0..max_sessions.min(self.statements.len()) {
+				scope.spawn(|| {
+					while !failed.load(Ordering::SeqCst) {
+						let index = next.fetch_add(1, Ordering::SeqCst);
+						if index >= self.statements.len() {
+							break;
+						}
+						let outcome = self.execute_statement(connect_params_json, index);
+						if outcome.is_err() {
+							failed.store(true, Ordering::SeqCst);
+						}
+						*outcomes[index].lock().unwrap() = Some(outcome);
+					}
+				});
+			}
+		});
+
+		let mut results = Vec::with_capacity(self.statements.len());
+		for (label, outcome) in self.labels.iter().zip(outcomes) {
+			match outcome.into_inner().unwrap() {
+				Some(Ok(result)) => results.push((label.clone(), result)),
+				Some(Err(err)) => {
+					return Err(err.map_message(|message| format!("Statement {}: {}", label, message)));
+				}
+				// Statements are started in order, so skipped statements follow the failed one
+				None => break,
+			}
+		}
+
+		Ok(LabeledResults { results })
+
+	} // end execute_parallel
+
+	// Executes one statement on a new session, which is closed afterward
+	fn execute_statement(&self, connect_params_json: &str, index: usize) -> Result<ResultSet, TeradataError> {
+		let conn = Connection::open(connect_params_json)?;
+		let mut results = conn.query_result_sets(&self.statements[index], &bind_values_json(self.bind_values[index].clone()))?;
+		if results.len() != 1 {
+			return Err(TeradataError::InvalidArgument(format!("Statement returned {} results", results.len())));
+		}
+		Ok(results.remove(0))
+	}
+
+	// Returns the metadata of each statement's result, keyed by label, without executing the
+	// request; see prefetch_metadata
+	pub fn describe(
+		&self,
+		conn: &Connection,
+	) -> Result<Vec<(String, ResultMetadata)>, TeradataError> {
+
+		self.validate()?;
+
+		let metadata = conn.prefetch_metadata(&self.request_text())?;
+		if metadata.len() != self.labels.len() {
+			return Err(TeradataError::InvalidArgument(format!("Multi-statement request with {} statements described {} results", self.labels.len(), metadata.len())));
+		}
+
+		Ok(self.labels.iter().cloned().zip(metadata).collect())
+
+	} // end describe
+}
+
+impl LabeledResults {
+	pub fn get(&self, label: &str) -> Option<&ResultSet> {
+		self.results.iter().find(|(l, _)| l == label).map(|(_, r)| r)
+	}
+
+	pub fn len(&self) -> usize {
+		self.results.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.results.is_empty()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &ResultSet)> {
+		self.results.iter().map(|(l, r)| (l.as_str(), r))
+	}
+
+	pub fn into_vec(self) -> Vec<(String, ResultSet)> {
+		self.results
+	}
+}
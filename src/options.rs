@@ -0,0 +1,140 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::time::{Duration, Instant};
+
+use crate::correlation::Correlation;
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::row_limit::{limit_rows, RowLimit, Sample};
+
+// How BLOB and CLOB values are returned by a query
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LobMode {
+	#[default]
+	Inline,              // LOB values are materialized in the result set rows
+	SpoolLocator,        // LOB values are returned as spool-scoped LOB locators
+	TransactionLocator,  // LOB values are returned as transaction-scoped LOB locators
+	Disabled,            // LOB support is turned off for the request
+}
+
+impl LobMode {
+	// Returns the escape function that selects this mode
+	pub fn escape(&self) -> &'static str {
+		match self {
+			LobMode::Inline => "{fn teradata_lobselect(I)}",
+			LobMode::SpoolLocator => "{fn teradata_lobselect(S)}",
+			LobMode::TransactionLocator => "{fn teradata_lobselect(T)}",
+			LobMode::Disabled => "{fn teradata_provide(request_scope_lob_support_off)}",
+		}
+	}
+
+	// Whether LOB column values are returned as locators rather than values
+	pub fn is_deferred(&self) -> bool {
+		matches!(self, LobMode::SpoolLocator | LobMode::TransactionLocator)
+	}
+}
+
+// Per-request options applied by the *_with_options functions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryOptions {
+	pub timeout: Option<Duration>,
+	pub deadline: Option<Instant>,        // the request is cancelled once this instant passes, whatever phase it is in
+	pub lob_mode: Option<LobMode>,        // None uses the driver default, which is LobMode::Inline
+	pub correlation: Option<Correlation>, // tags the request with a correlation ID in the session query band
+	pub row_limit: Option<RowLimit>,      // TOP or SAMPLE clause added to each SELECT statement
+}
+
+// Rounds a duration up to whole seconds, as required by the driver's timeout settings
+pub(crate) fn whole_seconds(d: Duration) -> u64 {
+	d.as_secs() + if d.subsec_nanos() > 0 { 1 } else { 0 }
+}
+
+// Returns an error naming the phase if the deadline has passed
+pub(crate) fn check_deadline(deadline: Option<Instant>, phase: &str) -> Result<(), TeradataError> {
+	match deadline {
+		Some(deadline) if Instant::now() >= deadline => Err(TeradataError::Timeout(format!("Request deadline exceeded before {}", phase))),
+		_ => Ok(()),
+	}
+}
+
+impl QueryOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	// Request timeout for this request, overriding the request_timeout connection parameter.
+	// The driver enforces the timeout, and the request is also cancelled from the client side
+	// if the driver has not returned shortly after the timeout elapses.
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	// Absolute deadline for the whole request, including fetching every row of every result.
+	// The remaining budget is recomputed before each phase, the driver's request timeout is
+	// limited to the remaining budget, and the request is cancelled once the deadline passes.
+	pub fn deadline(mut self, deadline: Instant) -> Self {
+		self.deadline = Some(deadline);
+		self
+	}
+
+	// Returns the request timeout to give the driver: the smaller of the timeout and the
+	// budget remaining before the deadline
+	pub fn effective_timeout(&self) -> Option<Duration> {
+		let remaining = self.deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+		match (self.timeout, remaining) {
+			(Some(timeout), Some(remaining)) => Some(timeout.min(remaining)),
+			(timeout, remaining) => timeout.or(remaining),
+		}
+	}
+
+	// Selects how BLOB and CLOB values are returned for this request
+	pub fn lob_mode(mut self, lob_mode: LobMode) -> Self {
+		self.lob_mode = Some(lob_mode);
+		self
+	}
+
+	// Records a new correlation ID for each request in the session query band, and in the
+	// correlation_id of each ResultSet returned
+	pub fn generate_correlation_id(mut self) -> Self {
+		self.correlation = Some(Correlation::Generate);
+		self
+	}
+
+	// Records the application's correlation ID, such as a trace ID, for this request
+	pub fn correlation_id(mut self, correlation_id: &str) -> Self {
+		self.correlation = Some(Correlation::Id(correlation_id.to_string()));
+		self
+	}
+
+	// Returns at most n rows from each SELECT statement, using SELECT TOP n. Replaces sample.
+	pub fn top(mut self, n: u64) -> Self {
+		self.row_limit = Some(RowLimit::Top(n));
+		self
+	}
+
+	// Returns randomly sampled rows from each SELECT statement, using a SAMPLE clause: a number
+	// of rows for an integer, such as sample(100), or a percentage of the rows for a float, such
+	// as sample(2.5). Replaces top.
+	pub fn sample<S: Into<Sample>>(mut self, sample: S) -> Self {
+		self.row_limit = Some(RowLimit::Sample(sample.into()));
+		self
+	}
+
+	// Returns the request text with the escape functions needed for these options prepended,
+	// and the TOP or SAMPLE clause added to each SELECT statement
+	pub fn apply(&self, request_text: &str) -> String {
+		let mut prefix = String::new();
+		if let Some(timeout) = self.effective_timeout() {
+			// Zero means no timeout to the driver, so an exhausted budget is rounded up to one second
+			prefix.push_str(&Escape::RequestTimeout(whole_seconds(timeout).max(1)).to_string());
+		}
+		if let Some(lob_mode) = self.lob_mode {
+			prefix.push_str(lob_mode.escape());
+		}
+		match &self.row_limit {
+			Some(limit) => prefix + &limit_rows(request_text, limit),
+			None => prefix + request_text,
+		}
+	}
+}
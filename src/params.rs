@@ -0,0 +1,240 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::cell::Cell;
+
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::temporal::{Date, Time, Timestamp};
+use crate::value::encode_base64;
+
+// The JSON value type of bind values, for code generated by #[derive(ToParams)], which cannot
+// assume that the application depends on serde_json
+pub use serde_json::Value as JsonValue;
+
+thread_local! {
+	// Set when a byte value is serialized, because its base64 string is indistinguishable from a
+	// character value once it is JSON
+	static BYTES_SERIALIZED: Cell<bool> = const { Cell::new(false) };
+}
+
+// Runs f and reports whether it serialized a byte value, Bytes or Param::Bytes
+pub(crate) fn serializing_bytes<T>(f: impl FnOnce() -> T) -> (T, bool) {
+	let outer = BYTES_SERIALIZED.replace(false);
+	let result = f();
+	let bytes = BYTES_SERIALIZED.replace(outer);
+	(result, bytes)
+}
+
+// Converts one bind value to JSON, for ToParams implementations. A bind value must serialize
+// to a JSON scalar. Serde serializes Vec<u8> and &[u8] as arrays of numbers, which the driver
+// cannot bind, so byte values are bound with Bytes or Param::Bytes instead.
+pub fn param_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, String> {
+	match serde_json::to_value(value) {
+		Ok(Value::Array(_)) => Err("Could not bind a JSON array; bind byte values as Bytes or Param::Bytes".to_string()),
+		Ok(Value::Object(_)) => Err("Could not bind a JSON object".to_string()),
+		Ok(value) => Ok(value),
+		Err(err) => Err(format!("Could not serialize bind value: {}", err)),
+	}
+}
+
+// A byte value, such as Bytes(vec![0xCA, 0xFE]) or Bytes(&data[..]), bound as a base64 string
+// the same as Param::Bytes, for tuples, Vec, and #[derive(ToParams)] rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bytes<T: AsRef<[u8]>>(pub T);
+
+impl<T: AsRef<[u8]>> Serialize for Bytes<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		BYTES_SERIALIZED.set(true);
+		serializer.serialize_str(&encode_base64(self.0.as_ref()))
+	}
+}
+
+impl<T: AsRef<[u8]>> From<Bytes<T>> for Param {
+	fn from(value: Bytes<T>) -> Self {
+		Param::Bytes(value.0.as_ref().to_vec())
+	}
+}
+
+// Largest integer magnitude that a JSON number bind value, which the driver binds as FLOAT,
+// represents exactly
+const MAX_EXACT_FLOAT_INTEGER: i64 = 1 << 53;
+
+// A typed bind value. Params serialize to the JSON bind value format that the driver expects,
+// so a row of Params is a ToParams row, and rows such as &[&[Param]] can be passed to
+// Connection::execute.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Param {
+	Null,
+	Int(i64),            // bound as a number, or as a string beyond 2^53, which a number cannot represent exactly
+	Float(f64),          // bound as a number; NaN and infinity are rejected
+	Str(String),
+	Bytes(Vec<u8>),      // bound as a base64 string, which is converted to bytes for a binary parameter
+	Decimal(String),     // decimal digits, bound as a string so that no digits are lost
+	Date(Date),
+	Time(Time),
+	Timestamp(Timestamp),
+}
+
+impl Serialize for Param {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self {
+			Param::Null => serializer.serialize_none(),
+			Param::Int(n) if (-MAX_EXACT_FLOAT_INTEGER..=MAX_EXACT_FLOAT_INTEGER).contains(n) => serializer.serialize_i64(*n),
+			Param::Int(n) => serializer.collect_str(n),
+			Param::Float(f) if !f.is_finite() => Err(S::Error::custom(format!("{} cannot be bound as a number", f))),
+			Param::Float(f) => serializer.serialize_f64(*f),
+			Param::Str(s) | Param::Decimal(s) => serializer.serialize_str(s),
+			Param::Bytes(bytes) => {
+				BYTES_SERIALIZED.set(true);
+				serializer.serialize_str(&encode_base64(bytes))
+			}
+			Param::Date(date) => date.serialize(serializer),
+			Param::Time(time) => time.serialize(serializer),
+			Param::Timestamp(timestamp) => timestamp.serialize(serializer),
+		}
+	}
+}
+
+macro_rules! param_from {
+	($($t:ty => $variant:ident),* $(,)?) => {
+		$(
+			impl From<$t> for Param {
+				fn from(value: $t) -> Self {
+					Param::$variant(value.into())
+				}
+			}
+		)*
+	};
+}
+
+param_from!(
+	i8 => Int, i16 => Int, i32 => Int, i64 => Int, u8 => Int, u16 => Int, u32 => Int,
+	f32 => Float, f64 => Float,
+	String => Str, &str => Str,
+	Vec<u8> => Bytes, &[u8] => Bytes,
+	Date => Date, Time => Time, Timestamp => Timestamp,
+);
+
+#[cfg(feature = "chrono")]
+param_from!(
+	::chrono::NaiveDate => Date, ::chrono::NaiveTime => Time, (::chrono::NaiveTime, ::chrono::FixedOffset) => Time,
+	::chrono::NaiveDateTime => Timestamp, ::chrono::DateTime<::chrono::FixedOffset> => Timestamp, ::chrono::DateTime<::chrono::Utc> => Timestamp,
+);
+
+#[cfg(feature = "time")]
+param_from!(
+	::time::Date => Date, ::time::Time => Time, (::time::Time, ::time::UtcOffset) => Time,
+	::time::PrimitiveDateTime => Timestamp, ::time::OffsetDateTime => Timestamp,
+);
+
+impl<T: Into<Param>> From<Option<T>> for Param {
+	fn from(value: Option<T>) -> Self {
+		value.map_or(Param::Null, Into::into)
+	}
+}
+
+// A row of bind values, in the order of the question-mark parameter markers. Implemented for
+// tuples, Vec, and arrays of serializable values, and, with the derive cargo feature, by
+// #[derive(ToParams)] for a struct, in field declaration order.
+pub trait ToParams {
+	fn to_params(&self) -> Result<Vec<Value>, String>;
+}
+
+impl<T: ToParams + ?Sized> ToParams for &T {
+	fn to_params(&self) -> Result<Vec<Value>, String> {
+		(**self).to_params()
+	}
+}
+
+impl<T: Serialize> ToParams for [T] {
+	fn to_params(&self) -> Result<Vec<Value>, String> {
+		self.iter().map(param_value).collect()
+	}
+}
+
+impl<T: Serialize> ToParams for Vec<T> {
+	fn to_params(&self) -> Result<Vec<Value>, String> {
+		self.as_slice().to_params()
+	}
+}
+
+impl<T: Serialize, const N: usize> ToParams for [T; N] {
+	fn to_params(&self) -> Result<Vec<Value>, String> {
+		self.as_slice().to_params()
+	}
+}
+
+macro_rules! tuple_to_params {
+	($($name:ident)+) => {
+		impl<$($name: Serialize),+> ToParams for ($($name,)+) {
+			#[allow(non_snake_case)]
+			fn to_params(&self) -> Result<Vec<Value>, String> {
+				let ($($name,)+) = self;
+				Ok(vec![$(param_value($name)?),+])
+			}
+		}
+	};
+}
+
+tuple_to_params!(A);
+tuple_to_params!(A B);
+tuple_to_params!(A B C);
+tuple_to_params!(A B C D);
+tuple_to_params!(A B C D E);
+tuple_to_params!(A B C D E F);
+tuple_to_params!(A B C D E F G);
+tuple_to_params!(A B C D E F G H);
+tuple_to_params!(A B C D E F G H I);
+tuple_to_params!(A B C D E F G H I J);
+tuple_to_params!(A B C D E F G H I J K);
+tuple_to_params!(A B C D E F G H I J K L);
+
+// Bind values accepted by Connection::execute: the JSON bind values string passed to
+// rustgo_create_rows_wrapper, such as "null" or "[[1,\"a\"]]", or a slice, Vec, or array of
+// ToParams rows, which is serialized to that JSON format
+pub trait BindValues {
+	fn to_bind_json(&self) -> Result<String, String>;
+}
+
+// Serializes bind values and reports whether any of them is a byte value, whose parameter marker
+// is converted from base64 if the parameter is binary. A JSON bind values string reports none.
+pub(crate) fn bind_json<P: BindValues + ?Sized>(bind_values: &P) -> Result<(String, bool), String> {
+	let (json, bytes) = serializing_bytes(|| bind_values.to_bind_json());
+	Ok((json?, bytes))
+}
+
+impl BindValues for str {
+	fn to_bind_json(&self) -> Result<String, String> {
+		Ok(self.to_string())
+	}
+}
+
+impl BindValues for String {
+	fn to_bind_json(&self) -> Result<String, String> {
+		Ok(self.clone())
+	}
+}
+
+impl<R: ToParams> BindValues for [R] {
+	fn to_bind_json(&self) -> Result<String, String> {
+		if self.is_empty() {
+			return Err("No rows of bind values were supplied".to_string());
+		}
+		let rows: Vec<Value> = self.iter().map(|row| row.to_params().map(Value::Array)).collect::<Result<_, _>>()?;
+		Ok(Value::Array(rows).to_string())
+	}
+}
+
+impl<R: ToParams> BindValues for Vec<R> {
+	fn to_bind_json(&self) -> Result<String, String> {
+		self.as_slice().to_bind_json()
+	}
+}
+
+impl<R: ToParams, const N: usize> BindValues for [R; N] {
+	fn to_bind_json(&self) -> Result<String, String> {
+		self.as_slice().to_bind_json()
+	}
+}
@@ -0,0 +1,519 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::connect::TIMEOUT_ERROR_PREFIX;
+use crate::connect_params::ReloadableConnectParams;
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::logging::SharedLog;
+use crate::profile::SessionProfile;
+use crate::settings::SessionSettings;
+
+// Default maximum number of connections a pool opens
+pub const DEFAULT_POOL_MAX_SIZE: usize = 10;
+
+// Default time to wait for a connection before acquire fails
+pub const DEFAULT_POOL_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Request executed to check that an idle connection still works before it is handed out
+pub const DEFAULT_VALIDATION_QUERY: &str = "select 1";
+
+// Request that the driver answers from its session state without sending a request to the
+// database, which detects a closed connection but not a database that stopped responding
+pub const NATIVE_SQL_VALIDATION: &str = "{fn teradata_nativesql}{fn teradata_session_number}";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolOptions {
+	pub max_size: usize,                      // connections open at once, idle and in use
+	pub min_idle: usize,                      // idle connections opened by Pool::new and Pool::maintain
+	pub acquire_timeout: Duration,            // wait for a connection, including the time to open one
+	pub validation_query: Option<String>,     // executed on an idle connection before it is handed out
+	pub idle_timeout: Option<Duration>,       // idle connections unused for longer are closed by maintain
+	pub reset_on_checkin: bool,               // restore the session settings captured when the connection was opened
+	pub keepalive_interval: Option<Duration>, // idle connections without a request for longer are sent DEFAULT_VALIDATION_QUERY
+}
+
+impl Default for PoolOptions {
+	fn default() -> Self {
+		PoolOptions {
+			max_size: DEFAULT_POOL_MAX_SIZE,
+			min_idle: 0,
+			acquire_timeout: DEFAULT_POOL_ACQUIRE_TIMEOUT,
+			validation_query: Some(DEFAULT_VALIDATION_QUERY.to_string()),
+			idle_timeout: None,
+			reset_on_checkin: true,
+			keepalive_interval: None,
+		}
+	}
+}
+
+impl PoolOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn max_size(mut self, max_size: usize) -> Self {
+		self.max_size = max_size;
+		self
+	}
+
+	pub fn min_idle(mut self, min_idle: usize) -> Self {
+		self.min_idle = min_idle;
+		self
+	}
+
+	pub fn acquire_timeout(mut self, timeout: Duration) -> Self {
+		self.acquire_timeout = timeout;
+		self
+	}
+
+	// Sets the request that validates an idle connection, or None to hand out idle connections
+	// without validating them
+	pub fn validation_query(mut self, request_text: Option<&str>) -> Self {
+		self.validation_query = request_text.map(str::to_string);
+		self
+	}
+
+	pub fn idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+		self.idle_timeout = timeout;
+		self
+	}
+
+	pub fn reset_on_checkin(mut self, reset: bool) -> Self {
+		self.reset_on_checkin = reset;
+		self
+	}
+
+	// Sets the interval of the keepalive thread, which sends a request on idle connections so that
+	// firewalls and NAT devices do not drop long-idle sessions, or None to start no keepalive thread
+	pub fn keepalive_interval(mut self, interval: Option<Duration>) -> Self {
+		self.keepalive_interval = interval;
+		self
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStatus {
+	pub open: usize,    // connections open, idle and in use, including connections being opened
+	pub idle: usize,
+	pub in_use: usize,
+	pub max_size: usize,
+}
+
+// A connection owned by the pool, with what is needed to return it to a clean state
+#[derive(Debug)]
+struct PoolEntry {
+	conn: Connection,
+	profile: SessionProfile,   // session profile it was opened with, the default for acquire
+	generation: u64,           // generation of the connection parameters it was opened with
+	settings: SessionSettings, // session settings captured when it was opened
+	idle_since: Instant,
+	last_request: Instant,     // when a request was last sent, by a borrower or by the keepalive thread
+}
+
+#[derive(Debug, Default)]
+struct PoolState {
+	idle: HashMap<SessionProfile, Vec<PoolEntry>>, // by session profile, most recently returned last
+	open: usize,
+	closed: bool,
+}
+
+impl PoolState {
+	fn idle_count(&self) -> usize {
+		self.idle.values().map(Vec::len).sum()
+	}
+
+	fn take_idle(&mut self, profile: &SessionProfile) -> Option<PoolEntry> {
+		self.idle.get_mut(profile).and_then(Vec::pop)
+	}
+
+	// Takes the least recently returned idle connection of any other profile, so that its slot
+	// can be used to open a connection with the profile
+	fn take_idle_except(&mut self, profile: &SessionProfile) -> Option<PoolEntry> {
+		self.idle.iter_mut()
+			.filter(|(idle_profile, idle)| *idle_profile != profile && !idle.is_empty())
+			.min_by_key(|(_, idle)| idle[0].idle_since)
+			.map(|(_, idle)| idle.remove(0))
+	}
+
+	fn take_all_idle(&mut self) -> Vec<PoolEntry> {
+		self.idle.drain().flat_map(|(_, idle)| idle).collect()
+	}
+}
+
+#[derive(Debug)]
+struct PoolShared {
+	params: ReloadableConnectParams,
+	options: PoolOptions,
+	state: Mutex<PoolState>,
+	available: Condvar,                           // signaled when a connection is returned or a slot is freed
+	keepalive_stop: Arc<KeepaliveStop>,
+	shared_log: Mutex<Option<(u64, SharedLog)>>, // log bitmask of the connection parameters, with their generation
+}
+
+// Stops the keepalive thread when the pool is closed or dropped
+#[derive(Debug, Default)]
+struct KeepaliveStop {
+	stopped: Mutex<bool>,
+	signal: Condvar,
+}
+
+impl KeepaliveStop {
+	fn stop(&self) {
+		*self.stopped.lock().unwrap() = true;
+		self.signal.notify_all();
+	}
+
+	// Waits for the interval, returning false if the keepalive thread was stopped meanwhile
+	fn wait(&self, interval: Duration) -> bool {
+		let stopped = self.stopped.lock().unwrap();
+		let (stopped, _) = self.signal.wait_timeout_while(stopped, interval, |stopped| !*stopped).unwrap();
+		!*stopped
+	}
+}
+
+impl Drop for PoolShared {
+	fn drop(&mut self) {
+		self.keepalive_stop.stop();
+	}
+}
+
+impl PoolShared {
+	// Opens a connection in a slot already counted in state.open, freeing the slot on failure.
+	// A connection with a session profile other than the default is opened with
+	// Connection::open_with_profile, which does not limit the time to connect.
+	fn open_entry(&self, profile: &SessionProfile, timeout: Duration) -> Result<PoolEntry, TeradataError> {
+		let (params, generation) = self.params.current();
+		let opened = if *profile == SessionProfile::default() {
+			let params_json = params.to_json();
+			self.shared_log(&params_json, generation).and_then(|shared_log| Connection::open_with_log_and_timeout(&params_json, &shared_log, timeout))
+		} else {
+			Connection::open_with_profile(&params, profile)
+		};
+		let result = opened.and_then(|conn| {
+			let settings = conn.snapshot_settings()?;
+			let now = Instant::now();
+			Ok(PoolEntry { conn, profile: profile.clone(), generation, settings, idle_since: now, last_request: now })
+		});
+		if result.is_err() {
+			self.release_slot();
+		}
+		result
+	}
+
+	// Returns the log bitmask shared by the connections opened with a generation of the
+	// connection parameters, which are parsed for it only once per generation
+	fn shared_log(&self, params_json: &str, generation: u64) -> Result<SharedLog, TeradataError> {
+		let mut shared_log = self.shared_log.lock().unwrap();
+		match *shared_log {
+			Some((log_generation, log)) if log_generation == generation => Ok(log),
+			_ => {
+				let log = SharedLog::parse(params_json)?;
+				*shared_log = Some((generation, log));
+				Ok(log)
+			}
+		}
+	}
+
+	// Closes a connection that leaves the pool
+	fn discard(&self, entry: PoolEntry) {
+		let _ = entry.conn.close();
+		self.release_slot();
+	}
+
+	fn release_slot(&self) {
+		self.state.lock().unwrap().open -= 1;
+		self.available.notify_one();
+	}
+
+	// Whether an idle connection can be handed out
+	fn is_usable(&self, entry: &PoolEntry) -> bool {
+		if !self.params.is_current(entry.generation) {
+			return false;
+		}
+		match &self.options.validation_query {
+			Some(request_text) => entry.conn.execute(request_text, "null").is_ok(),
+			None => true,
+		}
+	}
+
+	// Returns a connection to the idle connections of its profile, or closes it if the pool is
+	// closed or the connection parameters were replaced
+	fn return_idle(&self, entry: PoolEntry) {
+		let mut state = self.state.lock().unwrap();
+		if state.closed || !self.params.is_current(entry.generation) {
+			drop(state);
+			self.discard(entry);
+			return;
+		}
+		let now = Instant::now();
+		state.idle.entry(entry.profile.clone()).or_default().push(PoolEntry { idle_since: now, last_request: now, ..entry });
+		drop(state);
+		self.available.notify_one();
+	}
+
+	// Sends DEFAULT_VALIDATION_QUERY on each idle connection without a request for the interval,
+	// one connection at a time so that the others remain available to acquire. A connection that
+	// fails the request is closed.
+	fn keep_alive(&self, interval: Duration) {
+		loop {
+			let entry = {
+				let mut state = self.state.lock().unwrap();
+				if state.closed {
+					return;
+				}
+				let due = state.idle.values_mut().find_map(|idle| {
+					idle.iter().position(|entry| entry.last_request.elapsed() >= interval).map(|index| idle.remove(index))
+				});
+				match due {
+					Some(entry) => entry,
+					None => return,
+				}
+			};
+			if entry.conn.execute(DEFAULT_VALIDATION_QUERY, "null").is_err() {
+				self.discard(entry);
+				continue;
+			}
+			let mut state = self.state.lock().unwrap();
+			if state.closed {
+				drop(state);
+				self.discard(entry);
+				return;
+			}
+			// The keepalive request does not count as use, so the idle_timeout is unaffected; the
+			// least recently returned connections are at the front
+			state.idle.entry(entry.profile.clone()).or_default().insert(0, PoolEntry { last_request: Instant::now(), ..entry });
+			drop(state);
+			self.available.notify_one();
+		}
+	}
+
+	// Returns a borrowed connection to the pool. A connection returned with autocommit off may
+	// hold uncommitted work, which is rolled back before any settings are restored so that it is
+	// neither committed by the restore nor passed to the next borrower. Any proxy user is ended
+	// whether or not reset_on_checkin is set, so the next borrower never acts as the previous
+	// borrower's end user, and any workload set with set_workload is cleared.
+	fn checkin(&self, entry: PoolEntry) {
+		let rolled_back = match entry.conn.autocommit() {
+			Ok(true) => true,
+			Ok(false) => entry.conn.rollback().is_ok(),
+			Err(_) => false,
+		};
+		if !rolled_back || entry.conn.clear_proxy_user().is_err() {
+			self.discard(entry);
+			return;
+		}
+		entry.conn.clear_workload();
+		if self.options.reset_on_checkin && entry.conn.restore_settings(&entry.settings).is_err() {
+			self.discard(entry);
+			return;
+		}
+		self.return_idle(entry);
+	}
+}
+
+// A thread-safe pool of connections. acquire returns a PooledConnection, which dereferences to
+// a Connection and returns it to the pool when dropped. Cloning a pool shares its connections.
+#[derive(Debug, Clone)]
+pub struct Pool {
+	shared: Arc<PoolShared>,
+}
+
+impl Pool {
+	// Creates a pool and opens min_idle connections. Connections are opened with the current
+	// connection parameters; replacing the parameters closes older connections as they are
+	// returned or found idle.
+	pub fn new<P: Into<ReloadableConnectParams>>(params: P, options: PoolOptions) -> Result<Pool, TeradataError> {
+		if options.max_size == 0 {
+			return Err(TeradataError::InvalidArgument("Pool max_size must be greater than zero".to_string()));
+		}
+		if options.min_idle > options.max_size {
+			return Err(TeradataError::InvalidArgument(format!("Pool min_idle {} exceeds max_size {}", options.min_idle, options.max_size)));
+		}
+		let keepalive_interval = options.keepalive_interval;
+		let shared = PoolShared { params: params.into(), options, state: Mutex::new(PoolState::default()), available: Condvar::new(), keepalive_stop: Arc::default(), shared_log: Mutex::new(None) };
+		let pool = Pool { shared: Arc::new(shared) };
+		pool.maintain()?;
+		if let Some(interval) = keepalive_interval {
+			pool.start_keepalive(interval);
+		}
+		Ok(pool)
+	}
+
+	// Starts the thread that keeps idle connections alive. It holds only a weak reference to the
+	// pool and stops when the pool is closed or dropped.
+	fn start_keepalive(&self, interval: Duration) {
+		let pool: Weak<PoolShared> = Arc::downgrade(&self.shared);
+		let stop = Arc::clone(&self.shared.keepalive_stop);
+		thread::spawn(move || {
+			while stop.wait(interval) {
+				match pool.upgrade() {
+					Some(shared) => shared.keep_alive(interval),
+					None => return,
+				}
+			}
+		});
+	}
+
+	pub fn options(&self) -> &PoolOptions {
+		&self.shared.options
+	}
+
+	pub fn params(&self) -> &ReloadableConnectParams {
+		&self.shared.params
+	}
+
+	// Returns an idle connection that passes validation, or opens a new one if fewer than
+	// max_size are open, waiting at most the acquire_timeout for a connection to be returned.
+	// Idle connections that fail validation are closed and replaced. The same as
+	// acquire_with_profile with the default SessionProfile.
+	pub fn acquire(&self) -> Result<PooledConnection, TeradataError> {
+		self.acquire_with_profile(&SessionProfile::default())
+	}
+
+	// Returns an idle connection opened with the session profile, or opens a new one with
+	// Connection::open_with_profile. When max_size connections are open and none is idle with
+	// the profile, the least recently returned idle connection of another profile is closed to
+	// make room; otherwise it waits at most the acquire_timeout for a connection to be returned.
+	pub fn acquire_with_profile(&self, profile: &SessionProfile) -> Result<PooledConnection, TeradataError> {
+		let shared = &self.shared;
+		let timeout = shared.options.acquire_timeout;
+		let deadline = Instant::now() + timeout;
+		let mut state = shared.state.lock().unwrap();
+		loop {
+			if state.closed {
+				return Err(TeradataError::InvalidArgument("The pool is closed".to_string()));
+			}
+			if let Some(entry) = state.take_idle(profile) {
+				drop(state);
+				if shared.is_usable(&entry) {
+					return Ok(PooledConnection { shared: Arc::clone(shared), entry: Some(entry) });
+				}
+				shared.discard(entry);
+			} else if state.open < shared.options.max_size {
+				state.open += 1;
+				drop(state);
+				let entry = shared.open_entry(profile, deadline.saturating_duration_since(Instant::now()))?;
+				return Ok(PooledConnection { shared: Arc::clone(shared), entry: Some(entry) });
+			} else if let Some(other) = state.take_idle_except(profile) {
+				// The slot of the closed connection is kept for the new one
+				drop(state);
+				let _ = other.conn.close();
+				let entry = shared.open_entry(profile, deadline.saturating_duration_since(Instant::now()))?;
+				return Ok(PooledConnection { shared: Arc::clone(shared), entry: Some(entry) });
+			} else {
+				let remaining = deadline.saturating_duration_since(Instant::now());
+				if remaining.is_zero() {
+					return Err(TeradataError::Timeout(format!("{} no pooled connection became available within {:?}", TIMEOUT_ERROR_PREFIX, timeout)));
+				}
+				state = shared.available.wait_timeout(state, remaining).unwrap().0;
+				continue;
+			}
+			state = shared.state.lock().unwrap();
+		}
+	}
+
+	// Closes idle connections that are unused for longer than the idle_timeout or were opened
+	// with replaced connection parameters, then opens connections with the default profile until
+	// min_idle are idle
+	pub fn maintain(&self) -> Result<(), TeradataError> {
+		let shared = &self.shared;
+		let expired = {
+			let mut state = shared.state.lock().unwrap();
+			let now = Instant::now();
+			let mut expired = Vec::new();
+			for idle in state.idle.values_mut() {
+				let (stale, kept): (Vec<PoolEntry>, Vec<PoolEntry>) = idle.drain(..).partition(|entry| {
+					!shared.params.is_current(entry.generation) || shared.options.idle_timeout.is_some_and(|timeout| now.duration_since(entry.idle_since) > timeout)
+				});
+				*idle = kept;
+				expired.extend(stale);
+			}
+			state.idle.retain(|_, idle| !idle.is_empty());
+			expired
+		};
+		for entry in expired {
+			shared.discard(entry);
+		}
+
+		loop {
+			{
+				let mut state = shared.state.lock().unwrap();
+				if state.closed || state.idle_count() >= shared.options.min_idle || state.open >= shared.options.max_size {
+					return Ok(());
+				}
+				state.open += 1;
+			}
+			let entry = shared.open_entry(&SessionProfile::default(), shared.options.acquire_timeout)?;
+			shared.return_idle(entry);
+		}
+	}
+
+	pub fn status(&self) -> PoolStatus {
+		let state = self.shared.state.lock().unwrap();
+		let idle = state.idle_count();
+		PoolStatus { open: state.open, idle, in_use: state.open - idle, max_size: self.shared.options.max_size }
+	}
+
+	// Closes the idle connections and stops handing out connections. Connections in use are
+	// closed when they are returned.
+	pub fn close(&self) {
+		let idle = {
+			let mut state = self.shared.state.lock().unwrap();
+			state.closed = true;
+			state.take_all_idle()
+		};
+		for entry in idle {
+			self.shared.discard(entry);
+		}
+		self.shared.keepalive_stop.stop();
+		self.shared.available.notify_all();
+	}
+}
+
+// A connection borrowed from a Pool, returned to the pool when dropped. Before it is returned,
+// uncommitted work is rolled back, any proxy user set with set_proxy_user is ended, and, with
+// reset_on_checkin, the session settings captured when the connection was opened are restored;
+// a connection that cannot be rolled back, cleared, or restored is closed instead.
+#[derive(Debug)]
+pub struct PooledConnection {
+	shared: Arc<PoolShared>,
+	entry: Option<PoolEntry>,
+}
+
+impl PooledConnection {
+	// Removes the connection from the pool, which can then open another connection in its place
+	pub fn detach(mut self) -> Connection {
+		let entry = self.entry.take().unwrap();
+		self.shared.release_slot();
+		entry.conn
+	}
+
+	// Closes the connection instead of returning it to the pool, such as after an error that
+	// leaves the session in an unknown state
+	pub fn discard(mut self) {
+		let entry = self.entry.take().unwrap();
+		self.shared.discard(entry);
+	}
+}
+
+impl Deref for PooledConnection {
+	type Target = Connection;
+
+	fn deref(&self) -> &Connection {
+		&self.entry.as_ref().unwrap().conn
+	}
+}
+
+impl Drop for PooledConnection {
+	fn drop(&mut self) {
+		if let Some(entry) = self.entry.take() {
+			self.shared.checkin(entry);
+		}
+	}
+}
@@ -0,0 +1,74 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::connect::create_connection;
+use crate::connect_params::ConnectParams;
+use crate::error::TeradataError;
+use crate::raw::go_close_connection_wrapper;
+use crate::sql::quote_literal;
+use crate::execute_simple_request;
+
+// Session settings that distinguish otherwise interchangeable connections. Connections are
+// established with the profile already in effect, so a connection that Pool::acquire_with_profile
+// takes from the profile's idle connections needs no SET statements before use. The driver always uses the UTF8 session
+// character set, so the character set is not part of a profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SessionProfile {
+	pub database: Option<String>,   // default database
+	pub query_band: Option<String>, // session query band, such as "Workload=Reports;"
+	pub tmode: Option<String>,      // transaction mode, "ANSI" or "TERA"
+}
+
+impl SessionProfile {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn database(mut self, database: &str) -> Self {
+		self.database = Some(database.to_string());
+		self
+	}
+
+	pub fn query_band(mut self, query_band: &str) -> Self {
+		self.query_band = Some(query_band.to_string());
+		self
+	}
+
+	pub fn tmode(mut self, tmode: &str) -> Self {
+		self.tmode = Some(tmode.to_string());
+		self
+	}
+
+	// Returns the connection parameters with the profile's logon-time settings applied
+	pub fn apply_to_params(&self, params: &ConnectParams) -> ConnectParams {
+		let mut params = params.clone();
+		if let Some(database) = &self.database {
+			params = params.database(database);
+		}
+		if let Some(tmode) = &self.tmode {
+			params = params.set("tmode", tmode);
+		}
+		params
+	}
+}
+
+// Establishes a connection with the session profile in effect. The database and transaction
+// mode are specified as connection parameters, and the query band is set for the session
+// immediately after logon. The connection is closed if the query band cannot be set.
+pub fn connect_with_profile(
+	params: &ConnectParams,
+	profile: &SessionProfile,
+) -> Result<(u64, u64), TeradataError> {
+
+	let (u_log, conn_handle) = create_connection(&profile.apply_to_params(params).to_json())?;
+
+	if let Some(query_band) = &profile.query_band {
+		let request_text = format!("set query_band = {} for session", quote_literal(query_band));
+		if let Err(err) = execute_simple_request(u_log, conn_handle, &request_text) {
+			let _ = go_close_connection_wrapper(u_log, conn_handle);
+			return Err(err.map_message(|message| format!("Could not set session query band: {}", message)));
+		}
+	}
+
+	Ok((u_log, conn_handle))
+
+} // end connect_with_profile
@@ -0,0 +1,84 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::execute_simple_request;
+use crate::settings::{get_session_query_band, set_session_query_band};
+use crate::sql::{quote_literal, validate_query_band_value};
+
+// Makes the session act on behalf of an end user through a trusted session. The connecting
+// user must have been granted CONNECT THROUGH for the proxy user. The proxy user, and the
+// role if specified, remain in effect until clear_proxy_user is called.
+pub fn set_proxy_user(
+	u_log: u64,
+	conn_handle: u64,
+	user: &str,
+	role: Option<&str>,
+) -> Result<(), TeradataError> {
+
+	validate_query_band_value("Proxy user", user)?;
+	let mut query_band = format!("PROXYUSER={};", user.trim());
+	if let Some(role) = role {
+		validate_query_band_value("Proxy role", role)?;
+		query_band.push_str(&format!("PROXYROLE={};", role.trim()));
+	}
+
+	execute_simple_request(u_log, conn_handle, &format!("set query_band = {} update for session", quote_literal(&query_band)))
+
+} // end set_proxy_user
+
+// Removes the PROXYUSER and PROXYROLE pairs from the session query band, ending any proxy user
+// and role. Other session query band pairs are preserved.
+pub fn clear_proxy_user(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), TeradataError> {
+
+	let remaining = get_session_query_band(u_log, conn_handle)?.map(|session| without_proxy_pairs(&session)).unwrap_or_default();
+	set_session_query_band(u_log, conn_handle, Some(remaining.as_str()).filter(|pairs| !pairs.is_empty()))
+
+} // end clear_proxy_user
+
+// Returns the pairs of a query band other than PROXYUSER and PROXYROLE, such as "a=1;" from
+// "PROXYUSER=u1;a=1;"
+fn without_proxy_pairs(query_band: &str) -> String {
+	query_band
+		.split(';')
+		.map(str::trim)
+		.filter(|pair| !pair.is_empty())
+		.filter(|pair| {
+			let name = pair.split('=').next().unwrap_or_default().trim();
+			!name.eq_ignore_ascii_case("PROXYUSER") && !name.eq_ignore_ascii_case("PROXYROLE")
+		})
+		.map(|pair| format!("{};", pair))
+		.collect()
+}
+
+// Acts on behalf of an end user until the guard is ended or dropped, so that a connection
+// returned to a pool never keeps the previous end user's identity. Errors from the implicit
+// reset on drop are ignored; call end() to observe them. The guard borrows the Connection, so
+// the connection cannot be closed while the proxy user is in effect.
+pub struct ProxyUserGuard<'conn> {
+	conn: &'conn Connection,
+	active: bool,
+}
+
+impl<'conn> ProxyUserGuard<'conn> {
+	pub fn begin(conn: &'conn Connection, user: &str, role: Option<&str>) -> Result<ProxyUserGuard<'conn>, TeradataError> {
+		set_proxy_user(conn.u_log(), conn.conn_handle(), user, role)?;
+		Ok(ProxyUserGuard { conn, active: true })
+	}
+
+	pub fn end(mut self) -> Result<(), TeradataError> {
+		self.active = false;
+		clear_proxy_user(self.conn.u_log(), self.conn.conn_handle())
+	}
+}
+
+impl Drop for ProxyUserGuard<'_> {
+	fn drop(&mut self) {
+		if self.active {
+			let _ = clear_proxy_user(self.conn.u_log(), self.conn.conn_handle());
+		}
+	}
+}
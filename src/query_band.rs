@@ -0,0 +1,186 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+
+use crate::client::{set_client_attributes, ClientAttributes};
+use crate::correlation::set_correlation_id;
+use crate::error::TeradataError;
+use crate::sql::validate_query_band_value;
+
+// Query band name under which the host of the application is recorded
+pub const CLIENT_HOST_NAME: &str = "ClientHost";
+
+// Query band pairs that every new session records when automatic query bands are enabled, so
+// that DBQL attributes each request to the application without code at every call site
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutoQueryBand {
+	pub application_name: String,
+	pub host: Option<String>, // recorded as ClientHost; None uses the local host name, if it can be determined
+	pub trace_ids: bool,      // record the calling thread's trace ID, set with set_trace_id, as CorrelationId before each request
+}
+
+impl AutoQueryBand {
+	pub fn new(application_name: &str) -> Self {
+		AutoQueryBand { application_name: application_name.to_string(), ..Self::default() }
+	}
+
+	pub fn host(mut self, host: &str) -> Self {
+		self.host = Some(host.to_string());
+		self
+	}
+
+	pub fn trace_ids(mut self, enabled: bool) -> Self {
+		self.trace_ids = enabled;
+		self
+	}
+
+	fn attributes(&self) -> ClientAttributes {
+		let attributes = ClientAttributes::new().application_name(&self.application_name);
+		match self.host.clone().or_else(local_host_name) {
+			Some(host) => attributes.attribute(CLIENT_HOST_NAME, &host),
+			None => attributes,
+		}
+	}
+}
+
+static CONFIG: RwLock<Option<AutoQueryBand>> = RwLock::new(None);
+
+// Whether CONFIG has trace_ids enabled, checked before every request without taking the lock
+static TRACE_IDS: AtomicBool = AtomicBool::new(false);
+
+// Trace ID last recorded in the session query band of each connection, by conn_handle
+static RECORDED_TRACE_IDS: Mutex<Option<HashMap<u64, String>>> = Mutex::new(None);
+
+thread_local! {
+	static TRACE_ID: RefCell<Option<String>> = const { RefCell::new(None) };
+	static RECORDING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Returns the host name from the HOSTNAME or COMPUTERNAME environment variable, or /etc/hostname
+fn local_host_name() -> Option<String> {
+	["HOSTNAME", "COMPUTERNAME"].iter()
+		.filter_map(|name| env::var(name).ok())
+		.chain(fs::read_to_string("/etc/hostname").ok())
+		.map(|host| host.trim().to_string())
+		.find(|host| !host.is_empty())
+}
+
+// Enables automatic query bands for connections created afterward by any function or type of
+// this crate. The application name and host are validated first.
+pub fn enable_auto_query_band(
+	config: AutoQueryBand,
+) -> Result<(), TeradataError> {
+
+	validate_query_band_value("Application name", &config.application_name)?;
+	if config.application_name.trim().is_empty() {
+		return Err(TeradataError::InvalidArgument("Application name is empty".to_string()));
+	}
+	config.attributes().query_band()?;
+
+	TRACE_IDS.store(config.trace_ids, Ordering::SeqCst);
+	*CONFIG.write().unwrap() = Some(config);
+	Ok(())
+
+} // end enable_auto_query_band
+
+// Disables automatic query bands. Sessions keep the query band pairs already recorded.
+pub fn disable_auto_query_band() {
+	TRACE_IDS.store(false, Ordering::SeqCst);
+	*CONFIG.write().unwrap() = None;
+}
+
+pub fn auto_query_band() -> Option<AutoQueryBand> {
+	CONFIG.read().unwrap().clone()
+}
+
+// Sets the trace ID of the calling thread, such as the trace ID of the operation it is serving,
+// or clears it with None. With trace_ids enabled, requests record it as CorrelationId. After the
+// trace ID is cleared, the session keeps the last one recorded until another is recorded.
+pub fn set_trace_id(
+	trace_id: Option<&str>,
+) -> Result<(), TeradataError> {
+
+	if let Some(trace_id) = trace_id {
+		validate_query_band_value("Trace id", trace_id)?;
+	}
+	TRACE_ID.with(|current| *current.borrow_mut() = trace_id.map(|id| id.trim().to_string()));
+	Ok(())
+
+} // end set_trace_id
+
+pub fn trace_id() -> Option<String> {
+	TRACE_ID.with(|current| current.borrow().clone())
+}
+
+// Records the automatic query band pairs for a new session
+pub(crate) fn on_connect(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), TeradataError> {
+
+	let attributes = match CONFIG.read().unwrap().as_ref() {
+		Some(config) => config.attributes(),
+		None => {
+			return Ok(());
+		}
+	};
+
+	recording(|| set_client_attributes(u_log, conn_handle, &attributes))
+
+} // end on_connect
+
+// Records the calling thread's trace ID before a request of the Connection API, unless the
+// connection already has it. Requests made through the raw wrappers are not affected.
+pub(crate) fn before_request(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), TeradataError> {
+
+	// The request that records the trace ID must not record it again
+	if !TRACE_IDS.load(Ordering::Relaxed) || RECORDING.with(Cell::get) {
+		return Ok(());
+	}
+	let trace_id = match trace_id() {
+		Some(trace_id) => trace_id,
+		None => {
+			return Ok(());
+		}
+	};
+
+	if let Some(recorded) = RECORDED_TRACE_IDS.lock().unwrap().as_ref() && recorded.get(&conn_handle) == Some(&trace_id) {
+		return Ok(());
+	}
+	recording(|| set_correlation_id(u_log, conn_handle, &trace_id))?;
+	RECORDED_TRACE_IDS.lock().unwrap().get_or_insert_with(HashMap::new).insert(conn_handle, trace_id);
+	Ok(())
+
+} // end before_request
+
+// Forgets the trace ID recorded for a connection, after its session query band is replaced or
+// the connection is closed
+pub(crate) fn forget(conn_handle: u64) {
+	if let Some(recorded) = RECORDED_TRACE_IDS.lock().unwrap().as_mut() {
+		recorded.remove(&conn_handle);
+	}
+}
+
+// Clears RECORDING when dropped, so that a panic while recording does not leave the calling
+// thread unable to record trace IDs
+struct RecordingGuard;
+
+impl Drop for RecordingGuard {
+	fn drop(&mut self) {
+		RECORDING.with(|flag| flag.set(false));
+	}
+}
+
+fn recording<T>(f: impl FnOnce() -> T) -> T {
+	RECORDING.with(|flag| flag.set(true));
+	let _guard = RecordingGuard;
+	f()
+}
@@ -0,0 +1,55 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Connection manager for the r2d2 connection pool, so that Connection can be used by
+// applications built on r2d2.
+
+use crate::connect_params::ReloadableConnectParams;
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::pool::NATIVE_SQL_VALIDATION;
+
+// Opens connections for an r2d2::Pool<TeradataConnectionManager>. Connections are opened with
+// the current connection parameters, so replacing the parameters of a ReloadableConnectParams
+// affects the connections that the pool opens afterward.
+#[derive(Debug, Clone)]
+pub struct TeradataConnectionManager {
+	params: ReloadableConnectParams,
+	validation_query: Option<String>, // executed by is_valid instead of NATIVE_SQL_VALIDATION
+}
+
+impl TeradataConnectionManager {
+	pub fn new<P: Into<ReloadableConnectParams>>(params: P) -> Self {
+		TeradataConnectionManager { params: params.into(), validation_query: None }
+	}
+
+	// Validates connections with a request to the database, such as "select 1", which also
+	// detects a database that no longer responds, at the cost of a round trip to the database
+	pub fn validation_query(mut self, request_text: &str) -> Self {
+		self.validation_query = Some(request_text.to_string());
+		self
+	}
+
+	pub fn params(&self) -> &ReloadableConnectParams {
+		&self.params
+	}
+}
+
+impl ::r2d2::ManageConnection for TeradataConnectionManager {
+	type Connection = Connection;
+	type Error = TeradataError;
+
+	fn connect(&self) -> Result<Connection, TeradataError> {
+		let (params, _) = self.params.current();
+		Connection::open(&params.to_json())
+	}
+
+	fn is_valid(&self, conn: &mut Connection) -> Result<(), TeradataError> {
+		let request_text = self.validation_query.as_deref().unwrap_or(NATIVE_SQL_VALIDATION);
+		conn.execute(request_text, "null").map(|_| ())
+	}
+
+	// A broken connection cannot be detected without a request, which is_valid performs
+	fn has_broken(&self, _conn: &mut Connection) -> bool {
+		false
+	}
+}
@@ -0,0 +1,479 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Raw wrappers for the GoSQL Driver's exported functions, and the handle-based functions built
+// on them. These functions operate on the u64 connection and rows handles returned by the
+// driver, and the caller is responsible for closing every handle. They are public for
+// applications that need direct control over the driver; most applications should use
+// Connection and Rows instead, which offer the same features as methods.
+
+use std::collections::BTreeMap;
+use std::ffi::{CString, CStr};
+use std::os::raw::{c_char, c_ulonglong, c_ushort};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use libloading::{Library, Symbol};
+
+pub use crate::batch::execute_many;
+pub use crate::client::{create_connection_with_client_attributes, set_client_attributes};
+pub use crate::columns::column_formats;
+pub use crate::connect::{create_connection, create_connection_with_log, create_connection_with_log_and_timeout, create_connection_with_timeout};
+pub use crate::correlation::set_correlation_id;
+pub use crate::ddl::{execute_ddl, get_warnings};
+pub use crate::explain::explain;
+pub use crate::export::{export_csv, export_ndjson};
+pub use crate::help::{help_column, help_index, help_session, help_table};
+pub use crate::introspect::{native_sql, show_qualified};
+pub use crate::logging::{driver_log_level, set_driver_log_level};
+pub use crate::macros::exec_macro;
+pub use crate::profile::connect_with_profile;
+pub use crate::proxy::{clear_proxy_user, set_proxy_user};
+pub use crate::redrive::{connection_endpoint, execute_detecting_redrive, redrive_status};
+pub use crate::result::{fetch_result_sets, fetch_result_sets_with_options, fetch_selected_result_sets, prefetch_metadata};
+pub use crate::retry::execute_with_retry;
+pub use crate::row::{fetch_row, fetch_row_columns};
+pub use crate::row_hash::fetch_hashed_row;
+pub use crate::session::{current_database, server_time, session_info, set_database, set_time_zone, time_zone};
+pub use crate::settings::{restore_settings, snapshot_settings};
+pub use crate::spool::fetch_spooled;
+pub use crate::statement::parameter_metadata;
+pub use crate::tee::tee_rows;
+pub use crate::transaction::{autocommit, commit, rollback, set_autocommit};
+pub use crate::udt::describe_udt;
+pub use crate::value::fetch_typed_row;
+pub use crate::version::driver_version;
+
+// Function pointer types matching the C function signatures
+
+pub(crate) type GoCombineJSON = unsafe extern "C" fn(
+	json1: *const c_char,
+	json2: *const c_char,
+	error: *mut *mut c_char,
+	combined: *mut *mut c_char,
+);
+
+pub(crate) type GoParseParams = unsafe extern "C" fn(
+	params: *const c_char,
+	error: *mut *mut c_char,
+	log: *mut c_ulonglong,
+);
+
+pub(crate) type GoCreateConnection = unsafe extern "C" fn(
+	log: c_ulonglong,
+	version: *const c_char,
+	params: *const c_char,
+	error: *mut *mut c_char,
+	conn_handle: *mut c_ulonglong,
+);
+
+pub(crate) type GoCloseConnection = unsafe extern "C" fn(
+	log: c_ulonglong,
+	conn_handle: c_ulonglong,
+	error: *mut *mut c_char,
+);
+
+pub(crate) type GoCancelRequest = unsafe extern "C" fn(
+	log: c_ulonglong,
+	conn_handle: c_ulonglong,
+	error: *mut *mut c_char,
+);
+
+pub(crate) type RustGoCreateRows = unsafe extern "C" fn(
+	log: c_ulonglong,
+	conn_handle: c_ulonglong,
+	request_text: *const c_char,
+	bind_values: *const c_char,
+	error: *mut *mut c_char,
+	rows_handle: *mut c_ulonglong,
+);
+
+pub(crate) type RustGoResultMetaData = unsafe extern "C" fn(
+	log: c_ulonglong,
+	rows_handle: c_ulonglong,
+	error: *mut *mut c_char,
+	activity_count: *mut c_ulonglong,
+	activity_type: *mut c_ushort,
+	activity_name: *mut *mut c_char,
+	column_metadata: *mut *mut c_char,
+);
+
+pub(crate) type RustGoFetchRow = unsafe extern "C" fn(
+	log: c_ulonglong,
+	rows_handle: c_ulonglong,
+	error: *mut *mut c_char,
+	column_values: *mut *mut c_char,
+);
+
+pub(crate) type GoNextResult = unsafe extern "C" fn(
+	log: c_ulonglong,
+	rows_handle: c_ulonglong,
+	error: *mut *mut c_char,
+	avail: *mut c_char,
+);
+
+pub(crate) type GoCloseRows = unsafe extern "C" fn(
+	log: c_ulonglong,
+	rows_handle: c_ulonglong,
+	error: *mut *mut c_char,
+);
+
+pub(crate) type GoFreePointer = unsafe extern "C" fn(
+	log: c_ulonglong,
+	ptr: *mut c_char,
+);
+
+pub(crate) static GOSIDE_LIBRARY: OnceLock<Arc<Library>> = OnceLock::new();
+
+pub(crate) static GO_PARSE_PARAMS: OnceLock<Symbol<'static, GoParseParams>> = OnceLock::new();
+pub(crate) static GO_CREATE_CONNECTION: OnceLock<Symbol<'static, GoCreateConnection>> = OnceLock::new();
+pub(crate) static GO_CLOSE_CONNECTION: OnceLock<Symbol<'static, GoCloseConnection>> = OnceLock::new();
+pub(crate) static RUSTGO_CREATE_ROWS: OnceLock<Symbol<'static, RustGoCreateRows>> = OnceLock::new();
+pub(crate) static RUSTGO_RESULT_METADATA: OnceLock<Symbol<'static, RustGoResultMetaData>> = OnceLock::new();
+pub(crate) static RUSTGO_FETCH_ROW: OnceLock<Symbol<'static, RustGoFetchRow>> = OnceLock::new();
+pub(crate) static GO_NEXT_RESULT: OnceLock<Symbol<'static, GoNextResult>> = OnceLock::new();
+pub(crate) static GO_CLOSE_ROWS: OnceLock<Symbol<'static, GoCloseRows>> = OnceLock::new();
+pub(crate) static GO_FREE_POINTER: OnceLock<Symbol<'static, GoFreePointer>> = OnceLock::new();
+
+// Functions that older driver libraries may not export. They are resolved on first use instead
+// of by load_driver, and None records that the loaded library does not export the function.
+static GO_COMBINE_JSON: OnceLock<Option<Symbol<'static, GoCombineJSON>>> = OnceLock::new();
+static GO_CANCEL_REQUEST: OnceLock<Option<Symbol<'static, GoCancelRequest>>> = OnceLock::new();
+
+// Resolves an optional function of the loaded driver library. Returns None if the library is not
+// loaded or does not export the function.
+fn optional_symbol<T>(symbol: &'static OnceLock<Option<Symbol<'static, T>>>, name: &str) -> Option<&'static Symbol<'static, T>> {
+	let library = GOSIDE_LIBRARY.get()?;
+	symbol.get_or_init(|| unsafe { library.get::<T>(name.as_bytes()) }.ok()).as_ref()
+}
+
+pub(crate) fn go_combine_json_symbol() -> Option<&'static Symbol<'static, GoCombineJSON>> {
+	optional_symbol(&GO_COMBINE_JSON, "goCombineJSON")
+}
+
+pub(crate) fn go_cancel_request_symbol() -> Option<&'static Symbol<'static, GoCancelRequest>> {
+	optional_symbol(&GO_CANCEL_REQUEST, "goCancelRequest")
+}
+
+// Connection and rows handles created by the wrappers below and not yet closed, for shutdown
+#[derive(Debug, Default)]
+pub(crate) struct OpenHandles {
+	pub(crate) connections: BTreeMap<u64, u64>, // conn_handle to u_log
+	pub(crate) rows: BTreeMap<u64, (u64, u64)>,  // rows_handle to (u_log, conn_handle)
+}
+
+static OPEN_HANDLES: Mutex<OpenHandles> = Mutex::new(OpenHandles { connections: BTreeMap::new(), rows: BTreeMap::new() });
+
+// Set by shutdown; every wrapper fails afterward, so that stale handles are never passed to the driver
+static SHUT_DOWN: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn open_handles() -> &'static Mutex<OpenHandles> {
+	&OPEN_HANDLES
+}
+
+pub(crate) fn set_shut_down() {
+	SHUT_DOWN.store(true, Ordering::SeqCst);
+}
+
+pub(crate) fn is_shut_down() -> bool {
+	SHUT_DOWN.load(Ordering::SeqCst)
+}
+
+fn ensure_running() -> Result<(), String> {
+	if is_shut_down() {
+		return Err("The driver has been shut down".to_string());
+	}
+	Ok(())
+}
+
+fn unsupported(function: &str) -> String {
+	format!("The loaded driver library does not export {}; a newer driver library is required", function)
+}
+
+// Rust wrapper for goCombineJSON, which merges two connection parameter JSON strings; parameters
+// in json2 replace parameters of the same name in json1
+pub fn go_combine_json_wrapper(
+	json1: &str,
+	json2: &str,
+) -> Result<String, String> {
+	ensure_running()?;
+	let go_combine_json = go_combine_json_symbol().ok_or_else(|| unsupported("goCombineJSON"))?;
+	let c_json1 = CString::new(json1).unwrap();
+	let c_json2 = CString::new(json2).unwrap();
+	let mut error: *mut c_char = ptr::null_mut();
+	let mut combined: *mut c_char = ptr::null_mut();
+	unsafe {
+		go_combine_json(
+			c_json1.as_ptr(),
+			c_json2.as_ptr(),
+			&mut error,
+			&mut combined,
+		);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(0, error);
+			return Err(err_str);
+		}
+		let combined_str = CStr::from_ptr(combined).to_string_lossy().into_owned();
+		go_free_pointer_wrapper(0, combined);
+		Ok(combined_str)
+	}
+}
+
+// Rust wrapper for goParseParams
+pub(crate) fn go_parse_params_wrapper(
+	params: &str,
+) -> Result<u64, String> {
+	ensure_running()?;
+	let c_params = CString::new(params).unwrap();
+	let mut error: *mut c_char = ptr::null_mut();
+	let mut u_log: u64 = 0;
+	unsafe {
+		GO_PARSE_PARAMS.get().unwrap()(
+			c_params.as_ptr(),
+			&mut error,
+			&mut u_log,
+		);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		Ok(u_log)
+	}
+}
+
+// Rust wrapper for goCreateConnection
+pub(crate) fn go_create_connection_wrapper(
+	u_log: u64,
+	version: &str,
+	params: &str,
+) -> Result<u64, String> {
+	ensure_running()?;
+	let c_version = CString::new(version).unwrap();
+	let c_params = CString::new(params).unwrap();
+	let mut error: *mut c_char = ptr::null_mut();
+	let mut conn_handle: u64 = 0;
+	unsafe {
+		GO_CREATE_CONNECTION.get().unwrap()(
+			u_log,
+			c_version.as_ptr(),
+			c_params.as_ptr(),
+			&mut error,
+			&mut conn_handle,
+		);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		OPEN_HANDLES.lock().unwrap().connections.insert(conn_handle, u_log);
+		Ok(conn_handle)
+	}
+}
+
+// Rust wrapper for goCloseConnection
+pub fn go_close_connection_wrapper(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), String> {
+	ensure_running()?;
+	close_connection(u_log, conn_handle)
+}
+
+// Closes a connection handle without checking for shutdown, for shutdown itself. The handle and
+// the rows handles of the connection are no longer tracked, even if the driver reports an error.
+pub(crate) fn close_connection(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), String> {
+	{
+		let mut handles = OPEN_HANDLES.lock().unwrap();
+		handles.connections.remove(&conn_handle);
+		handles.rows.retain(|_, (_, conn)| *conn != conn_handle);
+	}
+	crate::query_band::forget(conn_handle);
+	let mut error: *mut c_char = ptr::null_mut();
+	unsafe {
+		GO_CLOSE_CONNECTION.get().unwrap()(u_log, conn_handle, &mut error);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		Ok(())
+	}
+}
+
+// Rust wrapper for goCancelRequest
+pub fn go_cancel_request_wrapper(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), String> {
+	ensure_running()?;
+	let go_cancel_request = go_cancel_request_symbol().ok_or_else(|| unsupported("goCancelRequest"))?;
+	let mut error: *mut c_char = ptr::null_mut();
+	unsafe {
+		go_cancel_request(u_log, conn_handle, &mut error);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		Ok(())
+	}
+}
+
+// Rust wrapper for rustgoCreateRows
+pub fn rustgo_create_rows_wrapper(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+) -> Result<u64, String> {
+	ensure_running()?;
+	let c_request_text = CString::new(request_text).unwrap();
+	let c_bind_values = CString::new(bind_values).unwrap();
+	let mut error: *mut c_char = ptr::null_mut();
+	let mut rows_handle: u64 = 0;
+	unsafe {
+		RUSTGO_CREATE_ROWS.get().unwrap()(
+			u_log,
+			conn_handle,
+			c_request_text.as_ptr(),
+			c_bind_values.as_ptr(),
+			&mut error,
+			&mut rows_handle,
+		);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		OPEN_HANDLES.lock().unwrap().rows.insert(rows_handle, (u_log, conn_handle));
+		Ok(rows_handle)
+	}
+}
+
+// Rust wrapper for rustgoResultMetaData
+pub fn rustgo_result_metadata_wrapper(
+	u_log: u64,
+	rows_handle: u64,
+) -> Result<(u64, u16, String, String), String> {
+	ensure_running()?;
+	let mut error: *mut c_char = ptr::null_mut();
+	let mut activity_count: u64 = 0;
+	let mut activity_type: u16 = 0;
+	let mut activity_name: *mut c_char = ptr::null_mut();
+	let mut column_metadata: *mut c_char = ptr::null_mut();
+	unsafe {
+		RUSTGO_RESULT_METADATA.get().unwrap()(
+			u_log,
+			rows_handle,
+			&mut error,
+			&mut activity_count,
+			&mut activity_type,
+			&mut activity_name,
+			&mut column_metadata,
+		);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		let activity_name_str = CStr::from_ptr(activity_name).to_string_lossy().into_owned();
+		let column_metadata_str = CStr::from_ptr(column_metadata).to_string_lossy().into_owned();
+		go_free_pointer_wrapper(u_log, activity_name);
+		go_free_pointer_wrapper(u_log, column_metadata);
+		Ok((activity_count, activity_type, activity_name_str, column_metadata_str))
+	}
+}
+
+// Rust wrapper for rustgoFetchRow
+pub fn rustgo_fetch_row_wrapper(
+	u_log: u64,
+	rows_handle: u64,
+) -> Result<Option<String>, String> {
+	ensure_running()?;
+	let mut error: *mut c_char = ptr::null_mut();
+	let mut column_values: *mut c_char = ptr::null_mut();
+	unsafe {
+		RUSTGO_FETCH_ROW.get().unwrap()(
+			u_log,
+			rows_handle,
+			&mut error,
+			&mut column_values,
+		);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		if column_values.is_null() {
+			// No more rows to fetch
+			return Ok(None);
+		}
+		let column_values_str = CStr::from_ptr(column_values).to_string_lossy().into_owned();
+		go_free_pointer_wrapper(u_log, column_values);
+		Ok(Some(column_values_str))
+	}
+}
+
+// Rust wrapper for goNextResult
+pub fn go_next_result_wrapper(
+	u_log: u64,
+	rows_handle: u64,
+) -> Result<bool, String> {
+	ensure_running()?;
+	let mut error: *mut c_char = ptr::null_mut();
+	let mut avail: c_char = 0;
+	unsafe {
+		GO_NEXT_RESULT.get().unwrap()(
+			u_log,
+			rows_handle,
+			&mut error,
+			&mut avail,
+		);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		Ok(avail == 'Y' as c_char)
+	}
+}
+
+// Rust wrapper for goCloseRows
+pub fn go_close_rows_wrapper(
+	u_log: u64,
+	rows_handle: u64,
+) -> Result<(), String> {
+	ensure_running()?;
+	close_rows(u_log, rows_handle)
+}
+
+// Closes a rows handle without checking for shutdown, for shutdown itself. The handle is no
+// longer tracked, even if the driver reports an error.
+pub(crate) fn close_rows(
+	u_log: u64,
+	rows_handle: u64,
+) -> Result<(), String> {
+	OPEN_HANDLES.lock().unwrap().rows.remove(&rows_handle);
+	let mut error: *mut c_char = ptr::null_mut();
+	unsafe {
+		GO_CLOSE_ROWS.get().unwrap()(u_log, rows_handle, &mut error);
+		if !error.is_null() {
+			let err_str = CStr::from_ptr(error).to_string_lossy().into_owned();
+			go_free_pointer_wrapper(u_log, error);
+			return Err(err_str);
+		}
+		Ok(())
+	}
+}
+
+// Rust wrapper for goFreePointer
+pub(crate) fn go_free_pointer_wrapper(
+	u_log: u64,
+	ptr: *mut c_char
+) {
+	unsafe { GO_FREE_POINTER.get().unwrap()(u_log, ptr); }
+}
@@ -0,0 +1,73 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::result::{fetch_result_sets, ResultSet};
+use crate::session::provide;
+
+// Whether Recoverable Network Protocol and Redrive are in effect for a connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedriveStatus {
+	pub redrive_active: bool,
+	pub rnp_active: bool,
+}
+
+pub fn redrive_status(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<RedriveStatus, TeradataError> {
+
+	Ok(RedriveStatus {
+		redrive_active: provide(u_log, conn_handle, "redrive_active")? == "true",
+		rnp_active: provide(u_log, conn_handle, "rnp_active")? == "true",
+	})
+
+} // end redrive_status
+
+// Identifies the TCP socket currently used by a connection. The driver reconnects with a new
+// socket after a communication failure, so a changed endpoint after a request that succeeded
+// indicates that the request was transparently redriven.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionEndpoint {
+	pub local_address: String,
+	pub local_port: String,
+}
+
+pub fn connection_endpoint(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<ConnectionEndpoint, TeradataError> {
+
+	Ok(ConnectionEndpoint {
+		local_address: provide(u_log, conn_handle, "local_address")?,
+		local_port: provide(u_log, conn_handle, "local_port")?,
+	})
+
+} // end connection_endpoint
+
+impl ConnectionEndpoint {
+	// Returns true if the connection has reconnected since this endpoint was captured
+	pub fn reconnected_since(
+		&self,
+		conn: &Connection,
+	) -> Result<bool, TeradataError> {
+
+		Ok(connection_endpoint(conn.u_log(), conn.conn_handle())? != *self)
+
+	} // end reconnected_since
+}
+
+// Executes a request and reports whether it was transparently redriven after a reconnect
+pub fn execute_detecting_redrive(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+) -> Result<(Vec<ResultSet>, bool), TeradataError> {
+
+	let before = connection_endpoint(u_log, conn_handle)?;
+	let results = fetch_result_sets(u_log, conn_handle, request_text, bind_values)?;
+	let redriven = connection_endpoint(u_log, conn_handle)? != before;
+	Ok((results, redriven))
+
+} // end execute_detecting_redrive
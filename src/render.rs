@@ -0,0 +1,141 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::export::{render, rendered_text, ValueFormat};
+use crate::result::{ColumnInfo, ResultSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableOptions {
+	pub max_rows: Option<usize>, // rows after this many are left out and counted in a closing note
+	pub format: ValueFormat,     // rendering of NULLs, dates, numbers, and flags; NULL renders as an empty cell by default
+}
+
+// Whether values of the column are right-aligned, as numbers are
+fn is_numeric(column: &ColumnInfo) -> bool {
+	matches!(column.type_name.trim().to_uppercase().as_str(), "BYTEINT" | "SMALLINT" | "INTEGER" | "BIGINT" | "FLOAT" | "REAL" | "DOUBLE PRECISION" | "DECIMAL" | "NUMBER")
+}
+
+// The text of each cell of the rows to render
+struct TableCells {
+	columns: Vec<ColumnInfo>,
+	rows: Vec<Vec<String>>,
+	omitted: usize, // rows left out because of max_rows
+}
+
+fn table_cells(result: &ResultSet, options: &TableOptions) -> Result<TableCells, String> {
+	let columns = result.columns()?;
+	if columns.is_empty() {
+		return Err(format!("The {} result has no columns to render", result.activity_name));
+	}
+	let shown = options.max_rows.map_or(result.rows.len(), |max_rows| max_rows.min(result.rows.len()));
+	let mut cells = Vec::with_capacity(shown);
+	for row in result.iter_rows().take(shown) {
+		let row = row?;
+		if row.len() != columns.len() {
+			return Err(format!("Row has {} columns but {} columns were described", row.len(), columns.len()));
+		}
+		let mut texts = Vec::with_capacity(columns.len());
+		for (index, column) in columns.iter().enumerate() {
+			let json = row.column_json(index).unwrap_or("null");
+			texts.push(rendered_text(render(json, column, &options.format)?)?);
+		}
+		cells.push(texts);
+	}
+	Ok(TableCells { columns, rows: cells, omitted: result.rows.len() - shown })
+}
+
+fn omitted_note(omitted: usize) -> String {
+	format!("{} more row{} not shown", omitted, if omitted == 1 { "" } else { "s" })
+}
+
+// Escapes text for HTML element content and attribute values. Line breaks become <br>.
+fn escape_html(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	let mut chars = text.chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&#39;"),
+			'\r' if chars.peek() == Some(&'\n') => {}
+			'\r' | '\n' => escaped.push_str("<br>"),
+			_ => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+// Escapes text for a Markdown table cell, so that values are shown literally instead of being
+// formatted or splitting the cell. Line breaks become <br>, which GitHub-flavored Markdown
+// renders within table cells.
+fn escape_markdown(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	let mut chars = text.chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' | '|' | '`' | '*' | '_' | '~' | '[' | ']' | '<' | '>' | '#' => {
+				escaped.push('\\');
+				escaped.push(c);
+			}
+			'&' => escaped.push_str("&amp;"),
+			'\r' if chars.peek() == Some(&'\n') => {}
+			'\r' | '\n' => escaped.push_str("<br>"),
+			_ => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+// Renders the rows of a result as an HTML table with a header row of column names. Numeric
+// columns are right-aligned, and column names and values are escaped.
+pub fn render_html(
+	result: &ResultSet,
+	options: &TableOptions,
+) -> Result<String, String> {
+
+	let TableCells { columns, rows: cells, omitted } = table_cells(result, options)?;
+	let align = |column: &ColumnInfo| if is_numeric(column) { " style=\"text-align: right\"" } else { "" };
+
+	let mut html = String::from("<table>\n<thead>\n<tr>");
+	for column in &columns {
+		html.push_str(&format!("<th{}>{}</th>", align(column), escape_html(&column.name)));
+	}
+	html.push_str("</tr>\n</thead>\n<tbody>\n");
+	for texts in &cells {
+		html.push_str("<tr>");
+		for (column, text) in columns.iter().zip(texts) {
+			html.push_str(&format!("<td{}>{}</td>", align(column), escape_html(text)));
+		}
+		html.push_str("</tr>\n");
+	}
+	html.push_str("</tbody>\n");
+	if omitted > 0 {
+		html.push_str(&format!("<tfoot>\n<tr><td colspan=\"{}\">{}</td></tr>\n</tfoot>\n", columns.len(), omitted_note(omitted)));
+	}
+	html.push_str("</table>\n");
+	Ok(html)
+
+} // end render_html
+
+// Renders the rows of a result as a GitHub-flavored Markdown table with a header row of column
+// names. Numeric columns are right-aligned, and column names and values are escaped.
+pub fn render_markdown(
+	result: &ResultSet,
+	options: &TableOptions,
+) -> Result<String, String> {
+
+	let TableCells { columns, rows: cells, omitted } = table_cells(result, options)?;
+	let line = |texts: Vec<String>| format!("| {} |\n", texts.join(" | "));
+
+	let mut markdown = line(columns.iter().map(|column| escape_markdown(&column.name)).collect());
+	markdown.push_str(&line(columns.iter().map(|column| if is_numeric(column) { "---:" } else { "---" }.to_string()).collect()));
+	for texts in &cells {
+		markdown.push_str(&line(texts.iter().map(|text| escape_markdown(text)).collect()));
+	}
+	if omitted > 0 {
+		markdown.push_str(&format!("\n_{}_\n", omitted_note(omitted)));
+	}
+	Ok(markdown)
+
+} // end render_markdown
@@ -0,0 +1,444 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::activity::{ActivityCategory, ActivityType};
+use crate::cancel::{CancelTimer, CANCEL_GRACE};
+use crate::connect::TIMEOUT_ERROR_PREFIX;
+use crate::correlation::set_correlation_id;
+use crate::error::TeradataError;
+use crate::escape::{Escape, Rpo};
+use crate::options::{check_deadline, LobMode, QueryOptions};
+use crate::row::{ColumnIndex, Row};
+use crate::statement::StatementInfo;
+use crate::raw::{go_close_rows_wrapper, go_next_result_wrapper, rustgo_create_rows_wrapper, rustgo_fetch_row_wrapper, rustgo_result_metadata_wrapper};
+
+// A result set whose metadata and rows have been fetched into memory
+#[derive(Debug, Clone, Default)]
+pub struct ResultSet {
+	pub activity_count: u64,
+	pub activity_type: u16,
+	pub activity_name: String,
+	pub column_metadata: String,        // JSON object as returned by rustgo_result_metadata_wrapper
+	pub rows: Vec<String>,              // JSON array of column values for each row
+	pub lob_mode: LobMode,              // how BLOB and CLOB column values were returned
+	pub correlation_id: Option<String>, // correlation ID recorded in the query band for the request, if any
+	pub stats: ResultStats,             // rows and bytes fetched for this result
+}
+
+// Counters of the rows fetched for one result, so that applications can attribute memory and
+// network usage to specific requests in their own telemetry
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResultStats {
+	pub rows_fetched: u64,
+	pub bytes_decoded: u64,        // bytes of row JSON received from the driver
+	pub peak_buffered_rows: usize, // most rows of the result held in memory at once
+}
+
+impl ResultStats {
+	// Counters of a result whose rows are all held in memory
+	pub(crate) fn of_buffered_rows(rows: &[String]) -> ResultStats {
+		ResultStats { rows_fetched: rows.len() as u64, bytes_decoded: rows.iter().map(|row| row.len() as u64).sum(), peak_buffered_rows: rows.len() }
+	}
+}
+
+// Metadata of one result of a request, without rows
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResultMetadata {
+	pub activity_count: u64, // zero for metadata returned by prefetch_metadata
+	pub activity_type: u16,
+	pub activity_name: String,
+	pub column_metadata: String, // JSON object as returned by rustgo_result_metadata_wrapper
+}
+
+impl ResultMetadata {
+	pub fn activity(&self) -> ActivityType {
+		ActivityType::new(self.activity_type, &self.activity_name)
+	}
+
+	pub fn columns(&self) -> Result<Vec<ColumnInfo>, TeradataError> {
+		parse_column_info(&self.column_metadata)
+	}
+
+	pub fn column_names(&self) -> Result<Vec<String>, TeradataError> {
+		parse_column_names(&self.column_metadata)
+	}
+
+	// Whether the statement's result will have columns to fetch
+	pub fn is_row_returning(&self) -> bool {
+		match self.column_names() {
+			Ok(names) => !names.is_empty(),
+			Err(_) => self.activity().category() == ActivityCategory::Query,
+		}
+	}
+}
+
+// Returns the column names from a column metadata JSON object
+pub(crate) fn parse_column_names(column_metadata: &str) -> Result<Vec<String>, TeradataError> {
+	let metadata: serde_json::Value = match serde_json::from_str(column_metadata) {
+		Ok(metadata) => metadata,
+		Err(err) => {
+			return Err(TeradataError::Conversion(format!("Could not parse column metadata JSON: {}", err)));
+		}
+	};
+	match metadata.get("ColumnName") {
+		Some(serde_json::Value::Array(names)) => Ok(names.iter().map(|n| n.as_str().unwrap_or_default().to_string()).collect()),
+		Some(serde_json::Value::Null) | None => Ok(Vec::new()),
+		Some(_) => Err(TeradataError::Conversion("Column metadata ColumnName is not an array".to_string())),
+	}
+}
+
+// Returns the maximum byte count of each column from a column metadata JSON object
+pub(crate) fn parse_max_byte_counts(column_metadata: &str) -> Result<Vec<u64>, TeradataError> {
+	let metadata: serde_json::Value = match serde_json::from_str(column_metadata) {
+		Ok(metadata) => metadata,
+		Err(err) => {
+			return Err(TeradataError::Conversion(format!("Could not parse column metadata JSON: {}", err)));
+		}
+	};
+	match metadata.get("MaxByteCount") {
+		Some(serde_json::Value::Array(counts)) => Ok(counts.iter().map(|n| n.as_u64().unwrap_or_default()).collect()),
+		Some(serde_json::Value::Null) | None => Ok(Vec::new()),
+		Some(_) => Err(TeradataError::Conversion("Column metadata MaxByteCount is not an array".to_string())),
+	}
+}
+
+// Description of one result column from the column metadata
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnInfo {
+	pub name: String,
+	pub type_name: String, // database type name, such as "INTEGER" or "TIMESTAMP WITH TIME ZONE"
+	pub max_byte_count: u64,
+	pub nullable: bool,
+	pub precision: u64,
+	pub scale: u64,
+}
+
+// Returns the description of each column from a column metadata JSON object
+pub fn parse_column_info(column_metadata: &str) -> Result<Vec<ColumnInfo>, TeradataError> {
+	let metadata: serde_json::Value = match serde_json::from_str(column_metadata) {
+		Ok(metadata) => metadata,
+		Err(err) => {
+			return Err(TeradataError::Conversion(format!("Could not parse column metadata JSON: {}", err)));
+		}
+	};
+	let array = |key: &str| -> Result<Vec<serde_json::Value>, TeradataError> {
+		match metadata.get(key) {
+			Some(serde_json::Value::Array(values)) => Ok(values.clone()),
+			Some(serde_json::Value::Null) | None => Ok(Vec::new()),
+			Some(_) => Err(TeradataError::Conversion(format!("Column metadata {} is not an array", key))),
+		}
+	};
+	let names = array("ColumnName")?;
+	let type_names = array("TypeName")?;
+	let max_byte_counts = array("MaxByteCount")?;
+	let nullables = array("Nullable")?;
+	let precisions = array("Precision")?;
+	let scales = array("Scale")?;
+
+	Ok(names.iter().enumerate().map(|(i, name)| ColumnInfo {
+		name: name.as_str().unwrap_or_default().to_string(),
+		type_name: type_names.get(i).and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+		max_byte_count: max_byte_counts.get(i).and_then(|v| v.as_u64()).unwrap_or_default(),
+		nullable: nullables.get(i).and_then(|v| v.as_bool()).unwrap_or(true),
+		precision: precisions.get(i).and_then(|v| v.as_u64()).unwrap_or_default(),
+		scale: scales.get(i).and_then(|v| v.as_u64()).unwrap_or_default(),
+	}).collect())
+}
+
+// Rows larger than this require the database's large row (1 MB row) support
+pub const TRADITIONAL_MAX_ROW_BYTES: u64 = 65535;
+
+impl ResultSet {
+	// Returns the description of each column from the column metadata
+	pub fn columns(&self) -> Result<Vec<ColumnInfo>, TeradataError> {
+		parse_column_info(&self.column_metadata)
+	}
+
+	// Returns the column names from the column metadata
+	pub fn column_names(&self) -> Result<Vec<String>, TeradataError> {
+		parse_column_names(&self.column_metadata)
+	}
+
+	// Returns the maximum size of a row of this result, the sum of the columns' maximum byte counts
+	pub fn max_row_bytes(&self) -> Result<u64, TeradataError> {
+		Ok(parse_max_byte_counts(&self.column_metadata)?.iter().sum())
+	}
+
+	// Whether rows of this result can exceed the traditional 64 KB row size, which requires
+	// the database's large row support. The driver negotiates large row support when it
+	// connects, and fetched rows have no size limit on the Rust side.
+	pub fn is_large_row(&self) -> Result<bool, TeradataError> {
+		Ok(self.max_row_bytes()? > TRADITIONAL_MAX_ROW_BYTES)
+	}
+
+	pub fn activity(&self) -> ActivityType {
+		ActivityType::new(self.activity_type, &self.activity_name)
+	}
+
+	// Whether the result has columns to fetch, as opposed to only an activity count
+	pub fn is_row_returning(&self) -> bool {
+		match self.column_names() {
+			Ok(names) => !names.is_empty(),
+			Err(_) => self.activity().category() == ActivityCategory::Query,
+		}
+	}
+
+	// Returns a fetched row as a Row, whose column values are decoded only when accessed
+	pub fn row(&self, index: usize) -> Option<Result<Row, TeradataError>> {
+		let names = self.names();
+		self.rows.get(index).map(|json| Ok(Row::from_json(json.clone())?.with_names(names)))
+	}
+
+	// Returns every fetched row as a Row. The column names are indexed once and shared by the
+	// rows, for Row::get_by_name.
+	pub fn iter_rows(&self) -> impl Iterator<Item = Result<Row, TeradataError>> + '_ {
+		let names = self.names();
+		self.rows.iter().map(move |json| Ok(Row::from_json(json.clone())?.with_names(names.clone())))
+	}
+
+	// Rows of a result whose column metadata cannot be parsed are still returned, without names
+	fn names(&self) -> Option<Arc<ColumnIndex>> {
+		self.column_names().ok().map(|names| Arc::new(ColumnIndex::new(&names)))
+	}
+
+	// Returns the zero-based index of the column with the specified name, ignoring case
+	pub fn column_index(&self, name: &str) -> Result<Option<usize>, TeradataError> {
+		Ok(self.column_names()?.iter().position(|n| n.eq_ignore_ascii_case(name)))
+	}
+
+	// Returns the statement metadata if this is a fake result set, which precedes each real
+	// result when fake result sets are enabled, or None for a real result
+	pub fn statement_info(&self) -> Result<Option<StatementInfo>, TeradataError> {
+		match self.rows.first() {
+			Some(row_json) => StatementInfo::parse(&self.column_metadata, row_json),
+			None => Ok(None),
+		}
+	}
+}
+
+// Fetches the metadata and all rows of the current result of rows_handle
+pub(crate) fn fetch_result_set(
+	u_log: u64,
+	rows_handle: u64,
+	deadline: Option<Instant>,
+) -> Result<ResultSet, TeradataError> {
+
+	check_deadline(deadline, "fetching result metadata")?;
+	let (activity_count, activity_type, activity_name, column_metadata) = match rustgo_result_metadata_wrapper(u_log, rows_handle) {
+		Ok(metadata) => metadata,
+		Err(err) => {
+			return Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err));
+		}
+	};
+
+	let mut rows = Vec::new();
+	loop {
+		check_deadline(deadline, "fetching rows")?;
+		match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+			Ok(Some(row)) => rows.push(row),
+			Ok(None) => break,
+			Err(err) => {
+				return Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err));
+			}
+		}
+	}
+
+	let stats = ResultStats::of_buffered_rows(&rows);
+	Ok(ResultSet { activity_count, activity_type, activity_name, column_metadata, rows, lob_mode: LobMode::Inline, correlation_id: None, stats })
+
+} // end fetch_result_set
+
+// Executes a request and fetches every result set into memory
+pub fn fetch_result_sets(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+) -> Result<Vec<ResultSet>, TeradataError> {
+
+	fetch_result_sets_until(u_log, conn_handle, request_text, bind_values, None)
+
+} // end fetch_result_sets
+
+// Executes a request and fetches every result set into memory, giving up between phases once the deadline passes
+fn fetch_result_sets_until(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+	deadline: Option<Instant>,
+) -> Result<Vec<ResultSet>, TeradataError> {
+
+	check_deadline(deadline, "submitting the request")?;
+	let rows_handle = match rustgo_create_rows_wrapper(u_log, conn_handle, request_text, bind_values) {
+		Ok(handle) => handle,
+		Err(err) => {
+			return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
+		}
+	};
+
+	let mut results = Vec::new();
+	let outcome = loop {
+		match fetch_result_set(u_log, rows_handle, deadline) {
+			Ok(result) => results.push(result),
+			Err(err) => break Err(err),
+		}
+		if let Err(err) = check_deadline(deadline, "advancing to the next result") {
+			break Err(err);
+		}
+		match go_next_result_wrapper(u_log, rows_handle) {
+			Ok(true) => continue,
+			Ok(false) => break Ok(()),
+			Err(err) => break Err(TeradataError::from_driver("go_next_result_wrapper", err)),
+		}
+	};
+
+	if let Err(err) = go_close_rows_wrapper(u_log, rows_handle) && outcome.is_ok() {
+		return Err(TeradataError::from_driver("go_close_rows_wrapper", err));
+	}
+
+	outcome.map(|_| results)
+
+} // end fetch_result_sets_until
+
+// Executes a request with per-request options and fetches every result set into memory
+pub fn fetch_result_sets_with_options(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+	options: &QueryOptions,
+) -> Result<Vec<ResultSet>, TeradataError> {
+
+	// The client-side cancel fires at the deadline, or shortly after the timeout if that is sooner
+	let grace_deadline = options.timeout.map(|timeout| Instant::now() + timeout + CANCEL_GRACE);
+	let cancel_at = match (options.deadline, grace_deadline) {
+		(Some(deadline), Some(grace_deadline)) => Some(deadline.min(grace_deadline)),
+		(deadline, grace_deadline) => deadline.or(grace_deadline),
+	};
+	check_deadline(options.deadline, "submitting the request")?;
+	let correlation_id = match &options.correlation {
+		Some(correlation) => {
+			let correlation_id = correlation.id();
+			set_correlation_id(u_log, conn_handle, &correlation_id)?;
+			Some(correlation_id)
+		}
+		None => None,
+	};
+	let timer = cancel_at.map(|cancel_at| CancelTimer::start_for_handle(u_log, conn_handle, cancel_at));
+
+	let result = fetch_result_sets_until(u_log, conn_handle, &options.apply(request_text), bind_values, options.deadline);
+
+	let cancelled = timer.map(|timer| timer.disarm()).unwrap_or(false);
+	match result {
+		Ok(mut results) => {
+			let lob_mode = options.lob_mode.unwrap_or_default();
+			for result in &mut results {
+				result.lob_mode = lob_mode;
+				result.correlation_id = correlation_id.clone();
+			}
+			Ok(results)
+		}
+		Err(err) if cancelled => Err(TeradataError::Timeout(format!("{} request cancelled after timeout or deadline: {}", TIMEOUT_ERROR_PREFIX, err.message()))),
+		Err(err) => Err(err),
+	}
+
+} // end fetch_result_sets_with_options
+
+// Returns the metadata of every result of a request, such as a multi-statement request, in one
+// exchange with the database and without executing the request. The request is prepared with
+// the {fn teradata_rpo(S)} escape function, so clients can render result headers immediately
+// and decide which results to fetch before executing the request.
+pub fn prefetch_metadata(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<Vec<ResultMetadata>, TeradataError> {
+
+	let rows_handle = match rustgo_create_rows_wrapper(u_log, conn_handle, &Escape::Rpo(Rpo::Prepare).apply(request_text), "null") {
+		Ok(handle) => handle,
+		Err(err) => {
+			return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
+		}
+	};
+
+	let mut metadata = Vec::new();
+	let outcome = loop {
+		match rustgo_result_metadata_wrapper(u_log, rows_handle) {
+			Ok((_, activity_type, activity_name, column_metadata)) => metadata.push(ResultMetadata { activity_count: 0, activity_type, activity_name, column_metadata }),
+			Err(err) => break Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err)),
+		}
+		match go_next_result_wrapper(u_log, rows_handle) {
+			Ok(true) => continue,
+			Ok(false) => break Ok(()),
+			Err(err) => break Err(TeradataError::from_driver("go_next_result_wrapper", err)),
+		}
+	};
+
+	if let Err(err) = go_close_rows_wrapper(u_log, rows_handle) && outcome.is_ok() {
+		return Err(TeradataError::from_driver("go_close_rows_wrapper", err));
+	}
+
+	outcome.map(|_| metadata)
+
+} // end prefetch_metadata
+
+// Executes a request and fetches only the results for which select returns true, given the
+// zero-based result index and the result metadata. The rows of other results are not fetched;
+// the driver skips them when advancing to the next result. Returns one entry per result,
+// None for each skipped result.
+pub fn fetch_selected_result_sets<F: FnMut(usize, &ResultMetadata) -> bool>(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+	mut select: F,
+) -> Result<Vec<Option<ResultSet>>, TeradataError> {
+
+	let rows_handle = match rustgo_create_rows_wrapper(u_log, conn_handle, request_text, bind_values) {
+		Ok(handle) => handle,
+		Err(err) => {
+			return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
+		}
+	};
+
+	let mut results = Vec::new();
+	let outcome = loop {
+		let metadata = match rustgo_result_metadata_wrapper(u_log, rows_handle) {
+			Ok((activity_count, activity_type, activity_name, column_metadata)) => ResultMetadata { activity_count, activity_type, activity_name, column_metadata },
+			Err(err) => break Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err)),
+		};
+		if select(results.len(), &metadata) {
+			let mut rows = Vec::new();
+			let fetched = loop {
+				match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+					Ok(Some(row)) => rows.push(row),
+					Ok(None) => break Ok(()),
+					Err(err) => break Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err)),
+				}
+			};
+			if let Err(err) = fetched {
+				break Err(err);
+			}
+			let ResultMetadata { activity_count, activity_type, activity_name, column_metadata } = metadata;
+			let stats = ResultStats::of_buffered_rows(&rows);
+			results.push(Some(ResultSet { activity_count, activity_type, activity_name, column_metadata, rows, lob_mode: LobMode::Inline, correlation_id: None, stats }));
+		} else {
+			results.push(None);
+		}
+		match go_next_result_wrapper(u_log, rows_handle) {
+			Ok(true) => continue,
+			Ok(false) => break Ok(()),
+			Err(err) => break Err(TeradataError::from_driver("go_next_result_wrapper", err)),
+		}
+	};
+
+	if let Err(err) = go_close_rows_wrapper(u_log, rows_handle) && outcome.is_ok() {
+		return Err(TeradataError::from_driver("go_close_rows_wrapper", err));
+	}
+
+	outcome.map(|_| results)
+
+} // end fetch_selected_result_sets
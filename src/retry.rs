@@ -0,0 +1,146 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::error::TeradataError;
+use crate::execute_simple_request;
+use crate::redrive::redrive_status;
+use crate::result::{fetch_result_sets, ResultSet};
+use crate::settings::{get_session_query_band, set_session_query_band};
+use crate::sql::{quote_literal, validate_query_band_value};
+
+// Whether a statement can safely be executed more than once. Only statements explicitly marked
+// Idempotent are ever retried; a retried INSERT that had already been applied would insert
+// duplicate rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Idempotency {
+	#[default]
+	NotIdempotent,
+	Idempotent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+	pub max_attempts: u32,               // total attempts, including the first
+	pub backoff: Duration,               // wait before the second attempt, doubled for each later attempt
+	pub retryable_error_codes: Vec<u32>, // database error codes worth retrying, such as 2631 for a deadlock
+	pub retry_connection_failures: bool, // retry communication failures, such as a reset socket, when the session is recoverable
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		RetryPolicy {
+			max_attempts: 3,
+			backoff: Duration::from_millis(200),
+			retryable_error_codes: vec![2631],
+			retry_connection_failures: false,
+		}
+	}
+}
+
+// Returns the database error code from a driver error message, such as 2631 from
+// "[Teradata Database] [Error 2631] Transaction ABORTed due to deadlock."
+pub(crate) fn error_code(message: &str) -> Option<u32> {
+	static ERROR_CODE: OnceLock<Regex> = OnceLock::new();
+	let re = ERROR_CODE.get_or_init(|| Regex::new(r"\[Error (\d+)\]").unwrap());
+	re.captures(message).and_then(|caps| caps[1].parse().ok())
+}
+
+// Whether a driver error message reports a communication failure, such as a reset or refused
+// socket, rather than a failure of the request itself
+pub(crate) fn is_transport_error(message: &str) -> bool {
+	static TRANSPORT_ERROR: OnceLock<Regex> = OnceLock::new();
+	let re = TRANSPORT_ERROR.get_or_init(|| Regex::new(r"(?i)connection reset|broken pipe|connection refused|connection aborted|unexpected EOF|i/o timeout|network is unreachable|no route to host|(read|write|dial) tcp").unwrap());
+	error_code(message).is_none() && re.is_match(message)
+}
+
+impl RetryPolicy {
+	// Whether an error from a previous attempt is worth retrying under this policy. An error
+	// without a database error code is retried only if retry_connection_failures is set and the
+	// error is a communication failure; argument and conversion errors are never retried.
+	pub fn is_retryable(&self, message: &str) -> bool {
+		match error_code(message) {
+			Some(code) => self.retryable_error_codes.contains(&code),
+			None => self.retry_connection_failures && is_transport_error(message),
+		}
+	}
+}
+
+// Whether the driver can reconnect the session under the same connection handle after a
+// communication failure, which requires Recoverable Network Protocol. Without it, the session
+// is gone and retrying on the handle cannot succeed.
+fn is_recoverable(
+	u_log: u64,
+	conn_handle: u64,
+) -> bool {
+
+	redrive_status(u_log, conn_handle).is_ok_and(|status| status.rnp_active)
+
+} // end is_recoverable
+
+// Executes a request, retrying failed attempts only if the request is marked Idempotent and the
+// error is retryable under the policy. A communication failure is retried only when the session
+// is recoverable. When a request id is supplied, it is recorded in the session query band as
+// ClientRequestId before the first attempt, so that every attempt can be traced in DBQL, and
+// the session query band is restored afterward. Returns the results and the number of attempts
+// made.
+pub fn execute_with_retry(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+	idempotency: Idempotency,
+	request_id: Option<&str>,
+	policy: &RetryPolicy,
+) -> Result<(Vec<ResultSet>, u32), TeradataError> {
+
+	let Some(request_id) = request_id else {
+		return execute_attempts(u_log, conn_handle, request_text, bind_values, idempotency, policy);
+	};
+
+	validate_query_band_value("Request id", request_id)?;
+	let previous = get_session_query_band(u_log, conn_handle)?;
+	let query_band = format!("ClientRequestId={};", request_id.trim());
+	execute_simple_request(u_log, conn_handle, &format!("set query_band = {} update for session", quote_literal(&query_band)))?;
+
+	let result = execute_attempts(u_log, conn_handle, request_text, bind_values, idempotency, policy);
+	match set_session_query_band(u_log, conn_handle, previous.as_deref()) {
+		Ok(()) => result,
+		Err(err) if result.is_ok() => Err(err.map_message(|message| format!("Request succeeded, but the session query band could not be restored: {}", message))),
+		Err(_) => result,
+	}
+
+} // end execute_with_retry
+
+fn execute_attempts(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+	bind_values: &str,
+	idempotency: Idempotency,
+	policy: &RetryPolicy,
+) -> Result<(Vec<ResultSet>, u32), TeradataError> {
+
+	let max_attempts = if idempotency == Idempotency::Idempotent { policy.max_attempts.max(1) } else { 1 };
+	let mut backoff = policy.backoff;
+	let mut attempt = 1;
+	loop {
+		match fetch_result_sets(u_log, conn_handle, request_text, bind_values) {
+			Ok(results) => return Ok((results, attempt)),
+			Err(err) if attempt < max_attempts && policy.is_retryable(err.message()) && (!is_transport_error(err.message()) || is_recoverable(u_log, conn_handle)) => {
+				thread::sleep(backoff);
+				backoff *= 2;
+				attempt += 1;
+			}
+			Err(err) if idempotency == Idempotency::NotIdempotent && policy.is_retryable(err.message()) => {
+				return Err(err.map_message(|message| format!("{} (not retried because the request is not marked idempotent)", message)));
+			}
+			Err(err) => return Err(err),
+		}
+	}
+
+} // end execute_attempts
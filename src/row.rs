@@ -0,0 +1,174 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::raw::rustgo_fetch_row_wrapper;
+use crate::result::ColumnInfo;
+
+// Positions of the columns of a result by name, matched ignoring case. It is built once per
+// result from the column metadata and shared by the rows fetched from the result.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnIndex {
+	positions: HashMap<String, usize>, // by lowercase column name
+}
+
+impl ColumnIndex {
+	// When several columns have the same name, the name refers to the first of them
+	pub fn new<S: AsRef<str>>(names: &[S]) -> ColumnIndex {
+		let mut positions = HashMap::with_capacity(names.len());
+		for (index, name) in names.iter().enumerate() {
+			positions.entry(name.as_ref().to_ascii_lowercase()).or_insert(index);
+		}
+		ColumnIndex { positions }
+	}
+
+	pub fn from_columns(columns: &[ColumnInfo]) -> ColumnIndex {
+		ColumnIndex::new(&columns.iter().map(|column| column.name.as_str()).collect::<Vec<_>>())
+	}
+
+	// Returns the zero-based position of the column with a name, ignoring case
+	pub fn position(&self, name: &str) -> Option<usize> {
+		self.positions.get(&name.to_ascii_lowercase()).copied()
+	}
+}
+
+// A fetched row that keeps the JSON text returned by the driver and decodes
+// individual column values only when they are requested
+#[derive(Debug, Clone)]
+pub struct Row {
+	json: String,
+	columns: Vec<Range<usize>>,      // byte range of each column value within json
+	names: Option<Arc<ColumnIndex>>, // column names of the result, for get_by_name
+}
+
+impl Row {
+	pub fn from_json(json: String) -> Result<Row, TeradataError> {
+		let columns = {
+			let raw_values: Vec<&RawValue> = match serde_json::from_str(&json) {
+				Ok(values) => values,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse row JSON: {}", err)));
+				}
+			};
+			let base = json.as_ptr() as usize;
+			raw_values.iter().map(|raw| {
+				let start = raw.get().as_ptr() as usize - base;
+				start..start + raw.get().len()
+			}).collect()
+		};
+		Ok(Row { json, columns, names: None })
+	}
+
+	// Attaches the column names of the result, for get_by_name. Rows fetched with Rows or
+	// ResultSet::iter_rows already have them.
+	pub fn with_column_index(self, names: Arc<ColumnIndex>) -> Row {
+		self.with_names(Some(names))
+	}
+
+	pub(crate) fn with_names(mut self, names: Option<Arc<ColumnIndex>>) -> Row {
+		self.names = names;
+		self
+	}
+
+	// Returns the zero-based index of the column with a name, ignoring case, or None if there is
+	// no such column or the row has no column names
+	pub fn column_index(&self, name: &str) -> Option<usize> {
+		self.names.as_ref().and_then(|names| names.position(name))
+	}
+
+	// Returns the JSON array text of the whole row
+	pub fn json(&self) -> &str {
+		&self.json
+	}
+
+	pub fn len(&self) -> usize {
+		self.columns.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.columns.is_empty()
+	}
+
+	// Returns the undecoded JSON text of a column value, such as "123", "\"abc\"", or "null"
+	pub fn column_json(&self, index: usize) -> Option<&str> {
+		self.columns.get(index).map(|range| &self.json[range.clone()])
+	}
+
+	pub fn is_null(&self, index: usize) -> Option<bool> {
+		self.column_json(index).map(|s| s == "null")
+	}
+
+	// Decodes a single column value
+	pub fn column_value(&self, index: usize) -> Result<Value, TeradataError> {
+		match self.column_json(index) {
+			Some(s) => serde_json::from_str(s).map_err(|err| TeradataError::Conversion(format!("Could not parse column {} JSON: {}", index + 1, err))),
+			None => Err(TeradataError::InvalidArgument(format!("Column index {} is out of range for a row with {} columns", index, self.columns.len()))),
+		}
+	}
+
+	// Decodes a single column value directly into T from the column's JSON text, without
+	// decoding the other columns or building a serde_json::Value. Use Option<T> for columns
+	// that may be NULL.
+	pub fn get<T: DeserializeOwned>(&self, index: usize) -> Result<T, TeradataError> {
+		match self.column_json(index) {
+			Some(s) => serde_json::from_str(s).map_err(|err| TeradataError::Conversion(format!("Could not decode column {}: {}", index + 1, err))),
+			None => Err(TeradataError::InvalidArgument(format!("Column index {} is out of range for a row with {} columns", index, self.columns.len()))),
+		}
+	}
+
+	// Decodes the value of the column with a name, matched ignoring case
+	pub fn get_by_name<T: DeserializeOwned>(&self, name: &str) -> Result<T, TeradataError> {
+		if self.names.is_none() {
+			return Err(TeradataError::InvalidArgument(format!("Cannot find column {} because the row has no column names", name)));
+		}
+		match self.column_index(name).and_then(|index| self.column_json(index)) {
+			Some(s) => serde_json::from_str(s).map_err(|err| TeradataError::Conversion(format!("Could not decode column {}: {}", name, err))),
+			None => Err(TeradataError::InvalidArgument(format!("Row has no column named {}", name))),
+		}
+	}
+
+	// Decodes only the specified subset of columns, in the order given
+	pub fn column_values(&self, indexes: &[usize]) -> Result<Vec<Value>, TeradataError> {
+		indexes.iter().map(|&index| self.column_value(index)).collect()
+	}
+
+	// Decodes every column value
+	pub fn values(&self) -> Result<Vec<Value>, TeradataError> {
+		(0..self.columns.len()).map(|index| self.column_value(index)).collect()
+	}
+}
+
+// Fetches the next row as a Row, or None when there are no more rows
+pub fn fetch_row(
+	u_log: u64,
+	rows_handle: u64,
+) -> Result<Option<Row>, TeradataError> {
+
+	match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+		Ok(Some(json)) => Row::from_json(json).map(Some),
+		Ok(None) => Ok(None),
+		Err(err) => Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err)),
+	}
+
+} // end fetch_row
+
+// Fetches the next row and decodes only the specified columns
+pub fn fetch_row_columns(
+	u_log: u64,
+	rows_handle: u64,
+	indexes: &[usize],
+) -> Result<Option<Vec<Value>>, TeradataError> {
+
+	match fetch_row(u_log, rows_handle)? {
+		Some(row) => row.column_values(indexes).map(Some),
+		None => Ok(None),
+	}
+
+} // end fetch_row_columns
@@ -0,0 +1,172 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::error::TeradataError;
+use crate::raw::rustgo_fetch_row_wrapper;
+use crate::result::ColumnInfo;
+use crate::row::Row;
+use crate::value::Value;
+
+// 64-bit FNV-1a, chosen because its output is fixed by specification rather than by the
+// standard library, so hashes can be stored and compared across runs and releases
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+	fn write(&mut self, bytes: &[u8]) {
+		for &b in bytes {
+			self.0 ^= b as u64;
+			self.0 = self.0.wrapping_mul(FNV_PRIME);
+		}
+	}
+
+	// Writes a type tag and a length prefix before the bytes, so that adjacent values cannot run
+	// together, such as "ab","c" and "a","bc"
+	fn write_value(&mut self, tag: u8, bytes: &[u8]) {
+		self.write(&[tag]);
+		self.write(&(bytes.len() as u64).to_le_bytes());
+		self.write(bytes);
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowHashOptions {
+	pub columns: Option<Vec<String>>, // names of the columns to hash, matched ignoring case; None hashes every column
+	pub trim_trailing_spaces: bool,   // ignore trailing spaces of character values, such as CHAR padding
+	pub ignore_case: bool,            // compare character values ignoring case
+}
+
+impl Default for RowHashOptions {
+	fn default() -> Self {
+		RowHashOptions { columns: None, trim_trailing_spaces: true, ignore_case: false }
+	}
+}
+
+impl RowHashOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn columns(mut self, names: &[&str]) -> Self {
+		self.columns = Some(names.iter().map(|name| name.to_string()).collect());
+		self
+	}
+
+	pub fn trim_trailing_spaces(mut self, trim: bool) -> Self {
+		self.trim_trailing_spaces = trim;
+		self
+	}
+
+	pub fn ignore_case(mut self, ignore: bool) -> Self {
+		self.ignore_case = ignore;
+		self
+	}
+}
+
+// Returns decimal digits in a canonical form, so that numerically equal values such as 5,
+// "5.00", and "+05.0" have the same text
+pub(crate) fn canonical_number(digits: &str) -> String {
+	let digits = digits.trim();
+	let (negative, digits) = match digits.strip_prefix('-') {
+		Some(rest) => (true, rest),
+		None => (false, digits.strip_prefix('+').unwrap_or(digits)),
+	};
+	let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+	let whole = whole.trim_start_matches('0');
+	let fraction = fraction.trim_end_matches('0');
+	let mut text = String::with_capacity(digits.len() + 1);
+	if negative && !(whole.is_empty() && fraction.is_empty()) {
+		text.push('-');
+	}
+	text.push_str(if whole.is_empty() { "0" } else { whole });
+	if !fraction.is_empty() {
+		text.push('.');
+		text.push_str(fraction);
+	}
+	text
+}
+
+// Computes a stable hash of the normalized values of a row, or of a subset of its columns, for
+// table-compare and change-data-detection jobs. Values are decoded according to the column
+// types and normalized before hashing, so that equal values hash equally regardless of their
+// formatting: numbers are compared by value across integer and decimal types, times and
+// timestamps regardless of the number of fractional second digits shown, and character values
+// optionally ignoring trailing spaces and case.
+#[derive(Debug, Clone)]
+pub struct RowHasher {
+	columns: Vec<(usize, ColumnInfo)>, // index and description of each hashed column
+	options: RowHashOptions,
+}
+
+impl RowHasher {
+	// Creates a hasher for rows with the described columns, such as from ResultMetadata::columns
+	pub fn new(columns: &[ColumnInfo], options: RowHashOptions) -> Result<RowHasher, TeradataError> {
+		let selected = match &options.columns {
+			None => columns.iter().cloned().enumerate().collect(),
+			Some(names) => {
+				let mut selected = Vec::with_capacity(names.len());
+				for name in names {
+					match columns.iter().position(|column| column.name.eq_ignore_ascii_case(name)) {
+						Some(index) => selected.push((index, columns[index].clone())),
+						None => {
+							return Err(TeradataError::InvalidArgument(format!("Result has no column named {}", name)));
+						}
+					}
+				}
+				selected
+			}
+		};
+		Ok(RowHasher { columns: selected, options })
+	}
+
+	pub fn hash_row(&self, row: &Row) -> Result<u64, TeradataError> {
+		let mut hasher = Fnv1a(FNV_OFFSET_BASIS);
+		for (index, column) in &self.columns {
+			match row.get_value(*index, column).map_err(TeradataError::Conversion)? {
+				Value::Null => hasher.write_value(b'0', &[]),
+				Value::Integer(n) => hasher.write_value(b'N', n.to_string().as_bytes()),
+				Value::Decimal(digits) => hasher.write_value(b'N', canonical_number(&digits).as_bytes()),
+				Value::Float(f) => hasher.write_value(b'F', &(if f == 0.0 { 0.0f64 } else { f }).to_bits().to_le_bytes()),
+				Value::Varchar(s) => {
+					let s = if self.options.trim_trailing_spaces { s.trim_end_matches(' ') } else { &s };
+					if self.options.ignore_case {
+						hasher.write_value(b'S', s.to_lowercase().as_bytes());
+					} else {
+						hasher.write_value(b'S', s.as_bytes());
+					}
+				}
+				Value::Bytes(bytes) => hasher.write_value(b'B', &bytes),
+				Value::Date(date) => hasher.write_value(b'D', date.format("%Y-%m-%d").as_bytes()),
+				Value::Time(time) => hasher.write_value(b'T', time.format("%H:%M:%S.%f%z").as_bytes()),
+				Value::Timestamp(timestamp) => hasher.write_value(b'P', timestamp.format("%Y-%m-%d %H:%M:%S.%f%z").as_bytes()),
+				Value::Struct(value) => hasher.write_value(b'U', value.to_json().to_string().as_bytes()),
+			}
+		}
+		Ok(hasher.0)
+	}
+
+	// Hashes a row given as the JSON array text returned by rustgo_fetch_row_wrapper
+	pub fn hash_json(&self, row_json: &str) -> Result<u64, TeradataError> {
+		self.hash_row(&Row::from_json(row_json.to_string())?)
+	}
+}
+
+// Fetches the next row and computes its hash, or returns None when there are no more rows
+pub fn fetch_hashed_row(
+	u_log: u64,
+	rows_handle: u64,
+	hasher: &RowHasher,
+) -> Result<Option<(Row, u64)>, TeradataError> {
+
+	match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+		Ok(Some(json)) => {
+			let row = Row::from_json(json)?;
+			let hash = hasher.hash_row(&row)?;
+			Ok(Some((row, hash)))
+		}
+		Ok(None) => Ok(None),
+		Err(err) => Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err)),
+	}
+
+} // end fetch_hashed_row
@@ -0,0 +1,104 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::sql::{statement_words, StatementWords};
+
+// Rows randomly sampled by a SAMPLE clause
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+	Rows(u64),    // at most this many rows
+	Percent(f64), // this percentage of the rows, greater than 0 and at most 100
+}
+
+impl From<u64> for Sample {
+	fn from(rows: u64) -> Self {
+		Sample::Rows(rows)
+	}
+}
+
+impl From<f64> for Sample {
+	fn from(percent: f64) -> Self {
+		Sample::Percent(percent)
+	}
+}
+
+// How the rows of each SELECT statement of a request are limited. Teradata has no LIMIT clause,
+// and a SELECT statement cannot have both TOP and SAMPLE.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowLimit {
+	Top(u64),       // SELECT TOP n, the first n rows in ORDER BY order, or arbitrary rows without ORDER BY
+	Sample(Sample), // SAMPLE n or SAMPLE fraction, randomly chosen rows
+}
+
+// Keywords that begin the clauses that follow SAMPLE in a SELECT statement
+const AFTER_SAMPLE: &[&str] = &["ORDER", "EXPAND", "WITH"];
+
+const SET_OPERATORS: &[&str] = &["UNION", "INTERSECT", "MINUS", "EXCEPT"];
+
+impl RowLimit {
+	fn clause(&self) -> String {
+		match self {
+			RowLimit::Top(n) => format!("TOP {}", n),
+			RowLimit::Sample(Sample::Rows(n)) => format!("SAMPLE {}", n),
+			RowLimit::Sample(Sample::Percent(percent)) => format!("SAMPLE {}", percent / 100.0),
+		}
+	}
+
+	// Returns the byte offset at which the clause is inserted into a statement, or None to leave
+	// the statement unchanged
+	fn insertion_point(&self, request_text: &str, statement: &StatementWords) -> Option<usize> {
+		// The statement must be a query, optionally preceded by a LOCKING modifier or a WITH clause
+		if !statement.is(request_text, 0, &["SELECT", "SEL", "LOCKING", "LOCK", "WITH"]) {
+			return None;
+		}
+		let select = statement.find(request_text, 0, &["SELECT", "SEL"])?;
+
+		// TOP and SAMPLE apply to a single SELECT, so set operations are left unchanged, as are
+		// statements that already limit their rows
+		if statement.find(request_text, select, SET_OPERATORS).is_some() || statement.find(request_text, select, &["SAMPLE"]).is_some() {
+			return None;
+		}
+		let mut after_select = select + 1;
+		if statement.is(request_text, after_select, &["DISTINCT", "ALL"]) {
+			after_select += 1;
+		}
+		if statement.is(request_text, after_select, &["TOP"]) {
+			return None;
+		}
+
+		match self {
+			RowLimit::Top(_) => Some(statement.words[after_select - 1].range.end),
+			RowLimit::Sample(_) => match statement.find(request_text, select + 1, AFTER_SAMPLE) {
+				Some(index) => Some(statement.words[index].range.start),
+				None => Some(statement.text_end),
+			},
+		}
+	}
+}
+
+// Adds the TOP or SAMPLE clause to each SELECT statement of the request text that does not
+// already have one. Other statements, and SELECT statements with set operators, are unchanged.
+pub(crate) fn limit_rows(
+	request_text: &str,
+	limit: &RowLimit,
+) -> String {
+
+	let clause = limit.clause();
+	let mut points: Vec<usize> = statement_words(request_text).iter().filter_map(|statement| limit.insertion_point(request_text, statement)).collect();
+	points.sort_unstable();
+
+	let mut text = String::with_capacity(request_text.len() + points.len() * (clause.len() + 2));
+	let mut copied = 0;
+	for point in points {
+		text.push_str(&request_text[copied..point]);
+		match limit {
+			RowLimit::Top(_) => text.push_str(&format!(" {}", clause)),
+			RowLimit::Sample(_) if point == request_text.len() || request_text[point..].starts_with(char::is_whitespace) => text.push_str(&format!(" {}", clause)),
+			RowLimit::Sample(_) => text.push_str(&format!("{} ", clause)),
+		}
+		copied = point;
+	}
+	text.push_str(&request_text[copied..]);
+
+	text
+
+} // end limit_rows
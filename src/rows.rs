@@ -0,0 +1,326 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::export::{export_csv, export_ndjson, ExportOptions, ExportSummary};
+use crate::from_row::FromRow;
+use crate::from_sql::Coercion;
+use crate::limiter::WorkloadPermit;
+use crate::params::JsonValue;
+use crate::raw::{go_close_rows_wrapper, go_next_result_wrapper, rustgo_create_rows_wrapper, rustgo_fetch_row_wrapper, rustgo_result_metadata_wrapper};
+use crate::result::{ColumnInfo, ResultMetadata};
+use crate::row::{ColumnIndex, Row};
+use crate::row_hash::RowHasher;
+use crate::spool::{fetch_spooled, SpoolOptions, SpooledRows};
+use crate::tee::{tee_rows, RowSink};
+use crate::value::Value;
+
+// An open result cursor that owns its rows_handle and closes it when dropped. A Rows borrows
+// the Connection that executed the request, so the connection cannot be closed while the
+// cursor is open.
+#[derive(Debug)]
+pub struct Rows<'conn> {
+	conn: &'conn Connection,
+	rows_handle: u64,
+	metadata: Option<ResultMetadata>, // metadata of the current result, once retrieved
+	columns: Option<Vec<ColumnInfo>>, // column descriptions of the current result, once parsed
+	names: Option<Arc<ColumnIndex>>,  // column names of the current result, shared by its rows
+	result_index: usize,              // zero-based index of the current result
+	coercion: Coercion,               // coercion mode of fetch_as
+	failed: bool,                     // a fetch by the iterator failed, which ends the iteration
+	permit: Option<WorkloadPermit>,   // workload permit of the request, released when the cursor is closed
+	closed: bool,
+}
+
+impl<'conn> Rows<'conn> {
+	// Executes a request with a JSON bind values string, as given, and returns a cursor
+	// positioned at its first result
+	pub fn open(conn: &'conn Connection, request_text: &str, bind_values: &str) -> Result<Rows<'conn>, TeradataError> {
+		Rows::open_bound(conn, request_text, bind_values)
+	}
+
+	// Same as Connection::execute_json, returning a cursor positioned at the first result
+	pub(crate) fn open_json(conn: &'conn Connection, request_text: &str, bind_values: &str, bytes: bool) -> Result<Rows<'conn>, TeradataError> {
+		Rows::open_bound(conn, &conn.bound_request_text(request_text, bytes)?, bind_values)
+	}
+
+	// Executes request text that needs no conversion of its bind values, such as the text of a
+	// prepared request, and returns a cursor positioned at its first result
+	pub(crate) fn open_bound(conn: &'conn Connection, request_text: &str, bind_values: &str) -> Result<Rows<'conn>, TeradataError> {
+		let permit = conn.before_request()?;
+		match rustgo_create_rows_wrapper(conn.u_log(), conn.conn_handle(), request_text, bind_values) {
+			Ok(rows_handle) => {
+				let mut rows = Rows::from_handle(conn, rows_handle);
+				rows.permit = permit;
+				Ok(rows)
+			}
+			Err(err) => Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err)),
+		}
+	}
+
+	// Takes ownership of a rows_handle returned by rustgo_create_rows_wrapper for the connection;
+	// the handle is closed when the Rows is dropped
+	pub fn from_handle(conn: &'conn Connection, rows_handle: u64) -> Rows<'conn> {
+		Rows { conn, rows_handle, metadata: None, columns: None, names: None, result_index: 0, coercion: Coercion::Lenient, failed: false, permit: None, closed: false }
+	}
+
+	pub fn connection(&self) -> &'conn Connection {
+		self.conn
+	}
+
+	pub fn rows_handle(&self) -> u64 {
+		self.rows_handle
+	}
+
+	// Zero-based index of the current result among the results of the request
+	pub fn result_index(&self) -> usize {
+		self.result_index
+	}
+
+	// Returns the metadata of the current result, retrieving it from the driver only once per result
+	pub fn metadata(&mut self) -> Result<&ResultMetadata, TeradataError> {
+		if self.metadata.is_none() {
+			let (activity_count, activity_type, activity_name, column_metadata) = match rustgo_result_metadata_wrapper(self.conn.u_log(), self.rows_handle) {
+				Ok(metadata) => metadata,
+				Err(err) => {
+					return Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err));
+				}
+			};
+			self.metadata = Some(ResultMetadata { activity_count, activity_type, activity_name, column_metadata });
+		}
+		Ok(self.metadata.as_ref().unwrap())
+	}
+
+	// Fetches the next row of the current result, or None when there are no more rows. The row
+	// shares the column names of the result, for Row::get_by_name.
+	pub fn fetch(&mut self) -> Result<Option<Row>, TeradataError> {
+		match rustgo_fetch_row_wrapper(self.conn.u_log(), self.rows_handle) {
+			Ok(Some(json)) => {
+				let row = Row::from_json(json)?;
+				// A row is still returned without names if the column metadata cannot be retrieved
+				Ok(Some(row.with_names(self.column_index().ok())))
+			}
+			Ok(None) => Ok(None),
+			Err(err) => Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err)),
+		}
+	}
+
+	// Returns the column descriptions of the current result, parsed from its metadata only once
+	// per result
+	pub fn columns(&mut self) -> Result<&[ColumnInfo], TeradataError> {
+		if self.columns.is_none() {
+			self.columns = Some(self.metadata()?.columns()?);
+		}
+		Ok(self.columns.as_deref().unwrap())
+	}
+
+	// Returns the case-insensitive index of the column names of the current result, built only
+	// once per result
+	pub fn column_index(&mut self) -> Result<Arc<ColumnIndex>, TeradataError> {
+		if self.names.is_none() {
+			self.names = Some(Arc::new(ColumnIndex::from_columns(self.columns()?)));
+		}
+		Ok(Arc::clone(self.names.as_ref().unwrap()))
+	}
+
+	// Returns the column names of the current result
+	pub fn column_names(&mut self) -> Result<Vec<String>, TeradataError> {
+		Ok(self.columns()?.iter().map(|column| column.name.clone()).collect())
+	}
+
+	// Fetches the next row of the current result with every column value decoded according to
+	// the column types, or None when there are no more rows
+	pub fn fetch_values(&mut self) -> Result<Option<Vec<Value>>, TeradataError> {
+		self.columns()?;
+		match self.fetch()? {
+			Some(row) => row.typed_values(self.columns.as_deref().unwrap()).map(Some).map_err(TeradataError::Conversion),
+			None => Ok(None),
+		}
+	}
+
+	// Fetches the next row of the current result and decodes only the specified columns as JSON
+	// values, or returns None when there are no more rows
+	pub fn fetch_columns(&mut self, indexes: &[usize]) -> Result<Option<Vec<JsonValue>>, TeradataError> {
+		match self.fetch()? {
+			Some(row) => row.column_values(indexes).map(Some),
+			None => Ok(None),
+		}
+	}
+
+	pub fn coercion(&self) -> Coercion {
+		self.coercion
+	}
+
+	// Sets the coercion mode of fetch_as; Coercion::Strict fails on any conversion that loses
+	// precision instead of rounding
+	pub fn set_coercion(&mut self, coercion: Coercion) {
+		self.coercion = coercion;
+	}
+
+	// Fetches the next row of the current result as a T, such as a struct with #[derive(FromRow)],
+	// or None when there are no more rows
+	pub fn fetch_as<T: FromRow>(&mut self) -> Result<Option<T>, TeradataError> {
+		self.columns()?;
+		match self.fetch()? {
+			Some(row) => T::from_row_with(&row, self.columns.as_deref().unwrap(), self.coercion).map(Some).map_err(TeradataError::Conversion),
+			None => Ok(None),
+		}
+	}
+
+	// Fetches the next row of the current result and computes its hash, or returns None when
+	// there are no more rows
+	pub fn fetch_hashed(&mut self, hasher: &RowHasher) -> Result<Option<(Row, u64)>, TeradataError> {
+		match self.fetch()? {
+			Some(row) => {
+				let hash = hasher.hash_row(&row)?;
+				Ok(Some((row, hash)))
+			}
+			None => Ok(None),
+		}
+	}
+
+	// Fetches every remaining row of the current result
+	pub fn fetch_all(&mut self) -> Result<Vec<Row>, TeradataError> {
+		let mut rows = Vec::new();
+		while let Some(row) = self.fetch()? {
+			rows.push(row);
+		}
+		Ok(rows)
+	}
+
+	// Fetches every remaining row of the current result, failing with TeradataError::RowCount
+	// rather than exhausting memory once more than max_rows rows or max_bytes bytes of row JSON
+	// would be held
+	pub fn collect_all(&mut self, max_rows: usize, max_bytes: usize) -> Result<Vec<Row>, TeradataError> {
+		let mut rows = Vec::new();
+		let mut bytes: usize = 0;
+		while let Some(row) = self.fetch()? {
+			bytes += row.json().len();
+			if rows.len() == max_rows {
+				return Err(TeradataError::RowCount(format!("Result set exceeds the limit of {} rows", max_rows)));
+			}
+			if bytes > max_bytes {
+				return Err(TeradataError::RowCount(format!("Result set exceeds the limit of {} bytes after {} rows", max_bytes, rows.len())));
+			}
+			rows.push(row);
+		}
+		Ok(rows)
+	}
+
+	// Fetches each remaining row of the current result and passes it to the callback until the
+	// rows are exhausted or the callback returns ControlFlow::Break. Returns the break value, or
+	// None if every row was visited. The cursor is consumed, which also makes this method rather
+	// than Iterator::for_each the one that rows.for_each(...) calls.
+	pub fn for_each<B, F>(mut self, mut f: F) -> Result<Option<B>, TeradataError>
+	where
+		F: FnMut(Row) -> ControlFlow<B>,
+	{
+		while let Some(row) = self.fetch()? {
+			if let ControlFlow::Break(value) = f(row) {
+				return Ok(Some(value));
+			}
+		}
+		Ok(None)
+	}
+
+	// Fetches the only row of the current result. Returns TeradataError::RowCount if the result
+	// has no rows or more than one row.
+	pub fn fetch_one(&mut self) -> Result<Row, TeradataError> {
+		match self.fetch_optional()? {
+			Some(row) => Ok(row),
+			None => Err(TeradataError::RowCount("Expected exactly one row, but the result has no rows".to_string())),
+		}
+	}
+
+	// Fetches the only row of the current result, or None if the result has no rows. Returns
+	// TeradataError::RowCount if the result has more than one row.
+	pub fn fetch_optional(&mut self) -> Result<Option<Row>, TeradataError> {
+		let row = self.fetch()?;
+		if row.is_some() && self.fetch()?.is_some() {
+			return Err(TeradataError::RowCount("Expected at most one row, but the result has more than one row".to_string()));
+		}
+		Ok(row)
+	}
+
+	// Fetches every remaining row of the current result once and writes each row to every sink,
+	// the same as tee_rows. Returns the number of rows fetched.
+	pub fn tee(&mut self, sinks: &mut [&mut dyn RowSink], buffer_rows: usize) -> Result<u64, TeradataError> {
+		tee_rows(self.conn.u_log(), self.rows_handle, sinks, buffer_rows)
+	}
+
+	// Fetches every remaining row of the current result, spooling rows to disk beyond the
+	// memory limit of the options
+	pub fn fetch_spooled(&mut self, options: &SpoolOptions) -> Result<SpooledRows, TeradataError> {
+		fetch_spooled(self.conn.u_log(), self.rows_handle, options)
+	}
+
+	// Writes every remaining row of the current result to the writer as CSV, and returns the
+	// writer with a summary of the export
+	pub fn export_csv<W: Write>(&mut self, writer: W, options: ExportOptions) -> Result<(ExportSummary, W), TeradataError> {
+		export_csv(self.conn.u_log(), self.rows_handle, writer, options)
+	}
+
+	// Same as export_csv, writing newline-delimited JSON
+	pub fn export_ndjson<W: Write>(&mut self, writer: W, options: ExportOptions) -> Result<(ExportSummary, W), TeradataError> {
+		export_ndjson(self.conn.u_log(), self.rows_handle, writer, options)
+	}
+
+	// Advances to the next result. Any unfetched rows of the current result are skipped without
+	// being fetched, so calling next_result right away skips a result that is not needed.
+	// Returns false when there are no more results.
+	pub fn next_result(&mut self) -> Result<bool, TeradataError> {
+		self.metadata = None;
+		self.columns = None;
+		self.names = None;
+		self.failed = false;
+		match go_next_result_wrapper(self.conn.u_log(), self.rows_handle) {
+			Ok(avail) => {
+				if avail {
+					self.result_index += 1;
+				}
+				Ok(avail)
+			}
+			Err(err) => Err(TeradataError::from_driver("go_next_result_wrapper", err)),
+		}
+	}
+
+	// Closes the cursor, returning any error that dropping the Rows would ignore
+	pub fn close(mut self) -> Result<(), TeradataError> {
+		self.closed = true;
+		match go_close_rows_wrapper(self.conn.u_log(), self.rows_handle) {
+			Ok(()) => Ok(()),
+			Err(err) => Err(TeradataError::from_driver("go_close_rows_wrapper", err)),
+		}
+	}
+}
+
+// Iterates over the remaining rows of the current result. The iteration ends after the last row
+// or after the first error; call next_result to continue with the next result.
+impl Iterator for Rows<'_> {
+	type Item = Result<Row, TeradataError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.failed {
+			return None;
+		}
+		match self.fetch() {
+			Ok(row) => row.map(Ok),
+			Err(err) => {
+				self.failed = true;
+				Some(Err(err))
+			}
+		}
+	}
+}
+
+impl Drop for Rows<'_> {
+	fn drop(&mut self) {
+		if !self.closed {
+			let _ = go_close_rows_wrapper(self.conn.u_log(), self.rows_handle);
+		}
+	}
+}
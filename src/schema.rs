@@ -0,0 +1,123 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::fmt;
+
+use crate::result::ColumnInfo;
+
+// A column of an expected schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedColumn {
+	pub name: String,
+	pub type_name: String, // database type name, such as "INTEGER" or "VARCHAR"
+}
+
+// The columns that a job expects a result or table to have, compared with actual result
+// columns by diff_schema. Column names and type names are compared ignoring case.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedSchema {
+	pub columns: Vec<ExpectedColumn>,
+}
+
+impl ExpectedSchema {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn column(mut self, name: &str, type_name: &str) -> Self {
+		self.columns.push(ExpectedColumn { name: name.to_string(), type_name: type_name.to_string() });
+		self
+	}
+
+	// Records the columns of a result as the expected schema, such as from a known-good run
+	pub fn from_columns(columns: &[ColumnInfo]) -> Self {
+		ExpectedSchema {
+			columns: columns.iter().map(|c| ExpectedColumn { name: c.name.clone(), type_name: c.type_name.clone() }).collect(),
+		}
+	}
+
+	// Returns an error describing every difference, or Ok if the columns match the expected schema
+	pub fn check(&self, actual: &[ColumnInfo]) -> Result<(), String> {
+		let diff = diff_schema(actual, self);
+		if diff.is_empty() {
+			Ok(())
+		} else {
+			Err(format!("Schema drift detected: {}", diff))
+		}
+	}
+}
+
+// A column whose type differs from the expected schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetypedColumn {
+	pub name: String,
+	pub expected_type: String,
+	pub actual_type: String,
+}
+
+// Differences between actual result columns and an expected schema. Column order is not compared.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+	pub added: Vec<ColumnInfo>,       // actual columns that are not in the expected schema
+	pub removed: Vec<ExpectedColumn>, // expected columns that are missing from the actual columns
+	pub retyped: Vec<RetypedColumn>,  // columns present in both with different type names
+}
+
+impl SchemaDiff {
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty() && self.retyped.is_empty()
+	}
+}
+
+impl fmt::Display for SchemaDiff {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_empty() {
+			return write!(f, "no differences");
+		}
+		let mut parts = Vec::new();
+		if !self.added.is_empty() {
+			parts.push(format!("added columns {}", self.added.iter().map(|c| format!("{} {}", c.name, c.type_name)).collect::<Vec<_>>().join(", ")));
+		}
+		if !self.removed.is_empty() {
+			parts.push(format!("removed columns {}", self.removed.iter().map(|c| format!("{} {}", c.name, c.type_name)).collect::<Vec<_>>().join(", ")));
+		}
+		if !self.retyped.is_empty() {
+			parts.push(format!("retyped columns {}", self.retyped.iter().map(|c| format!("{} {} -> {}", c.name, c.expected_type, c.actual_type)).collect::<Vec<_>>().join(", ")));
+		}
+		write!(f, "{}", parts.join("; "))
+	}
+}
+
+fn same_type(a: &str, b: &str) -> bool {
+	a.trim().eq_ignore_ascii_case(b.trim())
+}
+
+// Compares actual result columns, such as from ResultSet::columns or ResultMetadata::columns,
+// with an expected schema and reports added, removed, and retyped columns
+pub fn diff_schema(
+	actual: &[ColumnInfo],
+	expected: &ExpectedSchema,
+) -> SchemaDiff {
+
+	let mut diff = SchemaDiff::default();
+
+	for column in actual {
+		match expected.columns.iter().find(|e| e.name.eq_ignore_ascii_case(&column.name)) {
+			Some(e) if !same_type(&e.type_name, &column.type_name) => diff.retyped.push(RetypedColumn {
+				name: column.name.clone(),
+				expected_type: e.type_name.clone(),
+				actual_type: column.type_name.clone(),
+			}),
+			Some(_) => {}
+			None => diff.added.push(column.clone()),
+		}
+	}
+
+	for e in &expected.columns {
+		if !actual.iter().any(|column| column.name.eq_ignore_ascii_case(&e.name)) {
+			diff.removed.push(e.clone());
+		}
+	}
+
+	diff
+
+} // end diff_schema
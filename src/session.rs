@@ -0,0 +1,244 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::fmt;
+
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::result::fetch_result_sets;
+use crate::sql::{quote_identifier, quote_literal};
+use crate::temporal::Timestamp;
+use crate::{execute_simple_request, fetch_first_row};
+
+// Returns the first column of the first row as a string, or an error if the request returned no rows
+pub(crate) fn query_scalar_string(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<Option<String>, TeradataError> {
+
+	let row = match fetch_first_row(u_log, conn_handle, request_text, "null")? {
+		Some(row) => row,
+		None => {
+			return Err(TeradataError::RowCount(format!("No rows returned by {}", request_text)));
+		}
+	};
+
+	match row.into_iter().next() {
+		Some(serde_json::Value::Null) => Ok(None),
+		Some(serde_json::Value::String(s)) => Ok(Some(s)),
+		Some(other) => Ok(Some(other.to_string())),
+		None => Err(TeradataError::Conversion(format!("No columns returned by {}", request_text))),
+	}
+
+} // end query_scalar_string
+
+// Returns locally-cached connection information from a teradata_provide connection function
+pub(crate) fn provide(
+	u_log: u64,
+	conn_handle: u64,
+	name: &str,
+) -> Result<String, TeradataError> {
+
+	let request_text = Escape::Provide(name.to_string()).native();
+	Ok(query_scalar_string(u_log, conn_handle, &request_text)?.unwrap_or_default())
+
+} // end provide
+
+pub fn server_time(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<Timestamp, TeradataError> {
+
+	match query_scalar_string(u_log, conn_handle, "select current_timestamp")? {
+		Some(s) => s.parse::<Timestamp>().map_err(TeradataError::Conversion),
+		None => Err(TeradataError::Conversion("CURRENT_TIMESTAMP returned NULL".to_string())),
+	}
+
+} // end server_time
+
+// Makes a database the session's default database, using the DATABASE statement
+pub fn set_database(
+	u_log: u64,
+	conn_handle: u64,
+	database: &str,
+) -> Result<(), TeradataError> {
+
+	if database.trim().is_empty() {
+		return Err(TeradataError::InvalidArgument("Database name is empty".to_string()));
+	}
+
+	execute_simple_request(u_log, conn_handle, &format!("database {}", quote_identifier(database.trim())))
+
+} // end set_database
+
+// Returns the session's default database, using SELECT DATABASE
+pub fn current_database(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<String, TeradataError> {
+
+	match query_scalar_string(u_log, conn_handle, "select database")? {
+		Some(s) => Ok(s.trim().to_string()),
+		None => Err(TeradataError::Conversion("DATABASE returned NULL".to_string())),
+	}
+
+} // end current_database
+
+// Session time zone for set_time_zone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeZone {
+	Named(String),    // time zone string, such as "America Central" or "GMT"
+	Offset(i16),      // displacement from UTC in minutes
+	Local,            // system default time zone
+	User,             // user's default time zone
+}
+
+impl fmt::Display for TimeZone {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TimeZone::Named(name) => write!(f, "{}", quote_literal(name)),
+			TimeZone::Offset(minutes) => {
+				let sign = if *minutes < 0 { '-' } else { '+' };
+				let abs = minutes.unsigned_abs();
+				write!(f, "INTERVAL '{}{:02}:{:02}' HOUR TO MINUTE", sign, abs / 60, abs % 60)
+			}
+			TimeZone::Local => write!(f, "LOCAL"),
+			TimeZone::User => write!(f, "USER"),
+		}
+	}
+}
+
+impl From<&str> for TimeZone {
+	fn from(name: &str) -> Self {
+		TimeZone::Named(name.to_string())
+	}
+}
+
+// Session time zone reported by time_zone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionTimeZone {
+	pub offset_minutes: i16,  // current displacement from UTC in minutes
+	pub name: Option<String>, // time zone string, if the session uses a named time zone
+}
+
+pub fn set_time_zone<Z: Into<TimeZone>>(
+	u_log: u64,
+	conn_handle: u64,
+	zone: Z,
+) -> Result<(), TeradataError> {
+
+	let zone = zone.into();
+	if let TimeZone::Offset(minutes) = zone && !(-12 * 60..=14 * 60).contains(&minutes) {
+		return Err(TeradataError::InvalidArgument(format!("Time zone offset {} minutes is out of range", minutes)));
+	}
+
+	execute_simple_request(u_log, conn_handle, &format!("SET TIME ZONE {}", zone))
+
+} // end set_time_zone
+
+pub fn time_zone(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<SessionTimeZone, TeradataError> {
+
+	let offset_minutes = match query_scalar_string(u_log, conn_handle, "select extract(timezone_hour from current_timestamp) * 60 + extract(timezone_minute from current_timestamp)")? {
+		Some(s) => match s.trim().parse::<i16>() {
+			Ok(minutes) => minutes,
+			Err(_) => {
+				return Err(TeradataError::Conversion(format!("Could not parse time zone offset {}", s)));
+			}
+		},
+		None => 0,
+	};
+
+	// HELP SESSION reports the time zone string for a named time zone
+	let mut name = None;
+	if let Some(result) = fetch_result_sets(u_log, conn_handle, "help session", "null")?.first()
+		&& let Some(index) = result.column_index("Session Time Zone String")?
+		&& let Some(row) = result.rows.first()
+	{
+		let values: Vec<serde_json::Value> = serde_json::from_str(row).map_err(|err| TeradataError::Conversion(format!("Could not parse row JSON: {}", err)))?;
+		if let Some(serde_json::Value::String(s)) = values.get(index) && !s.trim().is_empty() {
+			name = Some(s.trim().to_string());
+		}
+	}
+
+	Ok(SessionTimeZone { offset_minutes, name })
+
+} // end time_zone
+
+// Transaction mode of a session, as set by the tmode connection parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionMode {
+	Ansi,
+	Tera,
+}
+
+// Attributes that identify a session, for diagnostics and for code that must assert its context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionInfo {
+	pub session_number: u64,
+	pub user: String,
+	pub current_database: String,
+	pub character_set: String,          // session character set name, such as "UTF8"
+	pub character_set_code: u16,        // session character set code, such as 191 for UTF8
+	pub transaction_mode: TransactionMode,
+}
+
+// Returns the session number, user, and default database, using a single SELECT SESSION, USER,
+// DATABASE round trip, and the character set and transaction mode from locally-cached
+// teradata_provide connection functions
+pub fn session_info(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<SessionInfo, TeradataError> {
+
+	let request_text = "select session, user, database";
+	let values = match fetch_first_row(u_log, conn_handle, request_text, "null")? {
+		Some(values) if values.len() == 3 => values,
+		Some(values) => {
+			return Err(TeradataError::Conversion(format!("{} returned {} columns", request_text, values.len())));
+		}
+		None => {
+			return Err(TeradataError::RowCount(format!("No rows returned by {}", request_text)));
+		}
+	};
+
+	let text = |index: usize| match &values[index] {
+		serde_json::Value::String(s) => s.trim().to_string(),
+		other => other.to_string(),
+	};
+
+	let session_number = match text(0).parse::<u64>() {
+		Ok(n) => n,
+		Err(_) => {
+			return Err(TeradataError::Conversion(format!("Could not parse session number {}", text(0))));
+		}
+	};
+
+	let code = provide(u_log, conn_handle, "session_charset_code")?;
+	let character_set_code = match code.trim().parse::<u16>() {
+		Ok(n) => n,
+		Err(_) => {
+			return Err(TeradataError::Conversion(format!("Could not parse session character set code {}", code)));
+		}
+	};
+
+	let transaction_mode = match provide(u_log, conn_handle, "transaction_mode")?.trim() {
+		"ANSI" => TransactionMode::Ansi,
+		"TERA" => TransactionMode::Tera,
+		other => {
+			return Err(TeradataError::Conversion(format!("Could not parse transaction mode {}", other)));
+		}
+	};
+
+	Ok(SessionInfo {
+		session_number,
+		user: text(1),
+		current_database: text(2),
+		character_set: provide(u_log, conn_handle, "session_charset_name")?.trim().to_string(),
+		character_set_code,
+		transaction_mode,
+	})
+
+} // end session_info
@@ -0,0 +1,90 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::error::TeradataError;
+use crate::query_band::forget;
+use crate::session::{current_database, query_scalar_string, set_database, set_time_zone, time_zone, SessionTimeZone, TimeZone};
+use crate::sql::quote_literal;
+use crate::transaction::{autocommit, set_autocommit};
+use crate::execute_simple_request;
+
+// Session settings captured by snapshot_settings and reapplied by restore_settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionSettings {
+	pub autocommit: bool,
+	pub query_band: Option<String>, // session query band, such as "Workload=Reports;", or None if not set
+	pub time_zone: SessionTimeZone,
+	pub database: String,           // default database
+}
+
+// Extracts the session portion of GetQueryBand output, such as "a=1;" from "=T> b=2; =S> a=1;"
+pub(crate) fn session_query_band(query_band: &str) -> Option<String> {
+	let start = query_band.find("=S>")? + 3;
+	let rest = &query_band[start..];
+	let end = ["=T>", "=P>"].iter().filter_map(|marker| rest.find(marker)).min().unwrap_or(rest.len());
+	let session = rest[..end].trim();
+	if session.is_empty() { None } else { Some(session.to_string()) }
+}
+
+// Returns the session query band pairs, such as "a=1;b=2;", or None if there are none
+pub(crate) fn get_session_query_band(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<Option<String>, TeradataError> {
+
+	Ok(query_scalar_string(u_log, conn_handle, "select GetQueryBand()")?.as_deref().and_then(session_query_band))
+
+} // end get_session_query_band
+
+// Replaces the session query band with the specified pairs, or removes it for None
+pub(crate) fn set_session_query_band(
+	u_log: u64,
+	conn_handle: u64,
+	query_band: Option<&str>,
+) -> Result<(), TeradataError> {
+
+	let request_text = match query_band {
+		Some(query_band) => format!("set query_band = {} for session", quote_literal(query_band)),
+		None => "set query_band = none for session".to_string(),
+	};
+	execute_simple_request(u_log, conn_handle, &request_text)?;
+	forget(conn_handle);
+	Ok(())
+
+} // end set_session_query_band
+
+// Captures the autocommit mode, session query band, time zone, and default database
+pub fn snapshot_settings(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<SessionSettings, TeradataError> {
+
+	let autocommit = autocommit(u_log, conn_handle)?;
+	let query_band = get_session_query_band(u_log, conn_handle)?;
+	let time_zone = time_zone(u_log, conn_handle)?;
+	let database = current_database(u_log, conn_handle)?;
+
+	Ok(SessionSettings { autocommit, query_band, time_zone, database })
+
+} // end snapshot_settings
+
+// Reapplies settings captured by snapshot_settings, undoing whatever changes were made to them
+// since. The autocommit mode is restored last, after the other settings are reapplied.
+pub fn restore_settings(
+	u_log: u64,
+	conn_handle: u64,
+	settings: &SessionSettings,
+) -> Result<(), TeradataError> {
+
+	set_database(u_log, conn_handle, &settings.database)?;
+
+	let zone = match &settings.time_zone.name {
+		Some(name) => TimeZone::Named(name.clone()),
+		None => TimeZone::Offset(settings.time_zone.offset_minutes),
+	};
+	set_time_zone(u_log, conn_handle, zone)?;
+
+	set_session_query_band(u_log, conn_handle, settings.query_band.as_deref())?;
+
+	set_autocommit(u_log, conn_handle, settings.autocommit)
+
+} // end restore_settings
@@ -0,0 +1,75 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::error::TeradataError;
+use crate::raw::{close_connection, close_rows, is_shut_down, open_handles, set_shut_down, GOSIDE_LIBRARY};
+
+// What shutdown does with connection and rows handles that are still open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShutdownMode {
+	RequireClosed, // fail without shutting down if any handle is still open
+	ForceClose,    // close every open handle, reporting a warning for each
+}
+
+// Outcome of shutdown
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+	pub connections_closed: Vec<u64>, // connection handles that were still open and were closed by shutdown
+	pub rows_closed: Vec<u64>,        // rows handles that were still open and were closed by shutdown
+	pub warnings: Vec<String>,        // one for each handle closed by shutdown, and for each error closing one
+}
+
+impl ShutdownReport {
+	// Whether every handle had already been closed by the application
+	pub fn is_clean(&self) -> bool {
+		self.warnings.is_empty()
+	}
+}
+
+// Retires the driver library for embedders that need deterministic teardown, such as plugins
+// and test harnesses. Open rows handles are closed before their connections. Afterward every
+// function that calls into the driver fails, including the implicit close when a Connection or
+// Rows is dropped, so that no stale handle reaches the driver. The library stays mapped into the
+// process, because the Go runtime it contains cannot be unloaded, and load_driver cannot load it
+// again. Call shutdown after every other thread has stopped using the driver.
+pub fn shutdown(
+	mode: ShutdownMode,
+) -> Result<ShutdownReport, TeradataError> {
+
+	if GOSIDE_LIBRARY.get().is_none() {
+		return Err(TeradataError::DriverLoad("The driver library is not loaded; call load_driver first".to_string()));
+	}
+	if is_shut_down() {
+		return Err(TeradataError::InvalidArgument("The driver has already been shut down".to_string()));
+	}
+
+	let (connections, rows) = {
+		let handles = open_handles().lock().unwrap();
+		let connections: Vec<(u64, u64)> = handles.connections.iter().map(|(&conn_handle, &u_log)| (conn_handle, u_log)).collect();
+		let rows: Vec<(u64, u64)> = handles.rows.iter().map(|(&rows_handle, &(u_log, _))| (rows_handle, u_log)).collect();
+		if mode == ShutdownMode::RequireClosed && !(connections.is_empty() && rows.is_empty()) {
+			return Err(TeradataError::InvalidArgument(format!("Cannot shut down with {} open connections and {} open rows handles", connections.len(), rows.len())));
+		}
+		// Set while the handles are locked, so that no handle is created after the snapshot
+		set_shut_down();
+		(connections, rows)
+	};
+
+	let mut report = ShutdownReport::default();
+	for (rows_handle, u_log) in rows {
+		report.warnings.push(format!("Rows handle {} was still open and was closed", rows_handle));
+		if let Err(err) = close_rows(u_log, rows_handle) {
+			report.warnings.push(format!("Could not close rows handle {}: {}", rows_handle, err));
+		}
+		report.rows_closed.push(rows_handle);
+	}
+	for (conn_handle, u_log) in connections {
+		report.warnings.push(format!("Connection handle {} was still open and was closed", conn_handle));
+		if let Err(err) = close_connection(u_log, conn_handle) {
+			report.warnings.push(format!("Could not close connection handle {}: {}", conn_handle, err));
+		}
+		report.connections_closed.push(conn_handle);
+	}
+
+	Ok(report)
+
+} // end shutdown
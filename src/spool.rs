@@ -0,0 +1,201 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::TeradataError;
+use crate::raw::rustgo_fetch_row_wrapper;
+use crate::result::ResultStats;
+
+// Default amount of row JSON held in memory before rows are spooled to disk
+pub const DEFAULT_SPOOL_MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+static SPOOL_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone)]
+pub struct SpoolOptions {
+	pub memory_limit_bytes: usize, // rows beyond this many bytes of JSON are written to the spool file
+	pub directory: Option<PathBuf>, // directory for the spool file; defaults to std::env::temp_dir()
+}
+
+impl Default for SpoolOptions {
+	fn default() -> Self {
+		SpoolOptions { memory_limit_bytes: DEFAULT_SPOOL_MEMORY_LIMIT, directory: None }
+	}
+}
+
+// Rows of a result set held in memory up to a threshold, with the remainder spooled to a
+// temporary file. The spool file is deleted when the SpooledRows is dropped.
+#[derive(Debug)]
+pub struct SpooledRows {
+	memory_rows: Vec<String>,
+	memory_bytes: usize,
+	spool_path: Option<PathBuf>,
+	spool_writer: Option<BufWriter<File>>,
+	spooled_row_count: usize,
+	spooled_bytes: u64,
+}
+
+impl SpooledRows {
+	fn new() -> Self {
+		SpooledRows {
+			memory_rows: Vec::new(),
+			memory_bytes: 0,
+			spool_path: None,
+			spool_writer: None,
+			spooled_row_count: 0,
+			spooled_bytes: 0,
+		}
+	}
+
+	fn push(&mut self, row: String, options: &SpoolOptions) -> Result<(), TeradataError> {
+		if self.spool_writer.is_none() && self.memory_bytes + row.len() <= options.memory_limit_bytes {
+			self.memory_bytes += row.len();
+			self.memory_rows.push(row);
+			return Ok(());
+		}
+
+		if self.spool_writer.is_none() {
+			let dir = options.directory.clone().unwrap_or_else(env::temp_dir);
+			let path = dir.join(format!("teradatarustapi-spool-{}-{}.jsonl", process::id(), SPOOL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)));
+			let file = match File::create(&path) {
+				Ok(file) => file,
+				Err(err) => {
+					return Err(TeradataError::Io(format!("Could not create spool file {}: {}", path.display(), err)));
+				}
+			};
+			self.spool_path = Some(path);
+			self.spool_writer = Some(BufWriter::new(file));
+		}
+
+		// Row JSON never contains a raw line break, so one row is written per line
+		let writer = self.spool_writer.as_mut().unwrap();
+		if let Err(err) = writer.write_all(row.as_bytes()).and_then(|_| writer.write_all(b"\n")) {
+			return Err(TeradataError::Io(format!("Could not write spool file: {}", err)));
+		}
+		self.spooled_row_count += 1;
+		self.spooled_bytes += row.len() as u64 + 1;
+		Ok(())
+	}
+
+	fn finish(&mut self) -> Result<(), TeradataError> {
+		if let Some(writer) = self.spool_writer.as_mut() && let Err(err) = writer.flush() {
+			return Err(TeradataError::Io(format!("Could not flush spool file: {}", err)));
+		}
+		Ok(())
+	}
+
+	pub fn len(&self) -> usize {
+		self.memory_rows.len() + self.spooled_row_count
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	// Whether any rows were spooled to disk
+	pub fn is_spooled(&self) -> bool {
+		self.spooled_row_count > 0
+	}
+
+	pub fn spool_path(&self) -> Option<&Path> {
+		self.spool_path.as_deref()
+	}
+
+	pub fn spooled_bytes(&self) -> u64 {
+		self.spooled_bytes
+	}
+
+	// Counters of the fetched rows, where only the rows kept in memory count as buffered. The
+	// bytes do not include the line breaks of the spool file.
+	pub fn stats(&self) -> ResultStats {
+		ResultStats {
+			rows_fetched: self.len() as u64,
+			bytes_decoded: self.memory_bytes as u64 + self.spooled_bytes - self.spooled_row_count as u64,
+			peak_buffered_rows: self.memory_rows.len(),
+		}
+	}
+
+	// Iterates over all rows, reading spooled rows back from disk. May be called repeatedly.
+	pub fn iter(&self) -> SpooledRowsIter<'_> {
+		SpooledRowsIter { memory: self.memory_rows.iter(), spool_path: self.spool_path.as_deref(), lines: None, remaining_spooled: self.spooled_row_count }
+	}
+}
+
+impl Drop for SpooledRows {
+	fn drop(&mut self) {
+		self.spool_writer = None;
+		if let Some(path) = self.spool_path.take() {
+			let _ = fs::remove_file(path);
+		}
+	}
+}
+
+pub struct SpooledRowsIter<'a> {
+	memory: std::slice::Iter<'a, String>,
+	spool_path: Option<&'a Path>,
+	lines: Option<Lines<BufReader<File>>>,
+	remaining_spooled: usize,
+}
+
+impl Iterator for SpooledRowsIter<'_> {
+	type Item = Result<String, TeradataError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(row) = self.memory.next() {
+			return Some(Ok(row.clone()));
+		}
+		if self.remaining_spooled == 0 {
+			return None;
+		}
+		if self.lines.is_none() {
+			let path = self.spool_path?;
+			match File::open(path) {
+				Ok(file) => self.lines = Some(BufReader::new(file).lines()),
+				Err(err) => {
+					self.remaining_spooled = 0;
+					return Some(Err(TeradataError::Io(format!("Could not open spool file {}: {}", path.display(), err))));
+				}
+			}
+		}
+		self.remaining_spooled -= 1;
+		match self.lines.as_mut()?.next() {
+			Some(Ok(line)) => Some(Ok(line)),
+			Some(Err(err)) => {
+				self.remaining_spooled = 0;
+				Some(Err(TeradataError::Io(format!("Could not read spool file: {}", err))))
+			}
+			None => {
+				self.remaining_spooled = 0;
+				Some(Err(TeradataError::Io("Spool file ended unexpectedly".to_string())))
+			}
+		}
+	}
+}
+
+// Fetches all remaining rows of the current result, spooling rows to disk beyond the memory limit
+pub fn fetch_spooled(
+	u_log: u64,
+	rows_handle: u64,
+	options: &SpoolOptions,
+) -> Result<SpooledRows, TeradataError> {
+
+	let mut rows = SpooledRows::new();
+	loop {
+		match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+			Ok(Some(row)) => rows.push(row, options)?,
+			Ok(None) => break,
+			Err(err) => {
+				return Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err));
+			}
+		}
+	}
+	rows.finish()?;
+
+	Ok(rows)
+
+} // end fetch_spooled
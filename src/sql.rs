@@ -0,0 +1,226 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::error::TeradataError;
+
+// Returns a SQL character literal for the string, doubling any embedded single-quote characters
+pub fn quote_literal(s: &str) -> String {
+	format!("'{}'", s.replace('\'', "''"))
+}
+
+// Returns a double-quoted SQL identifier, doubling any embedded double-quote characters.
+// A qualified name such as db.table must be quoted one part at a time; see quote_qualified_name.
+pub fn quote_identifier(s: &str) -> String {
+	format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+// Quotes each dot-separated part of an unquoted qualified name such as db.table
+pub fn quote_qualified_name(s: &str) -> String {
+	s.split('.').map(quote_identifier).collect::<Vec<_>>().join(".")
+}
+
+// Query band values cannot contain the pair and name separators
+pub(crate) fn validate_query_band_value(name: &str, value: &str) -> Result<(), TeradataError> {
+	if value.trim().is_empty() {
+		return Err(TeradataError::InvalidArgument(format!("{} must not be empty", name)));
+	}
+	if value.contains(';') || value.contains('=') {
+		return Err(TeradataError::InvalidArgument(format!("{} must not contain ';' or '='", name)));
+	}
+	Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+	Code,          // ordinary request text
+	Literal,       // 'character literal'
+	Identifier,    // "quoted identifier"
+	LineComment,   // -- to end of line
+	BlockComment,  // /* ... */
+}
+
+// Splits request text into (token, byte range) spans. Question marks and colons are only
+// significant within Code spans.
+fn tokenize(request_text: &str) -> Vec<(Token, std::ops::Range<usize>)> {
+	let bytes = request_text.as_bytes();
+	let mut spans = Vec::new();
+	let mut start = 0;
+	let mut i = 0;
+
+	let close = |spans: &mut Vec<(Token, std::ops::Range<usize>)>, token: Token, from: usize, to: usize| {
+		if from < to {
+			spans.push((token, from..to));
+		}
+	};
+
+	while i < bytes.len() {
+		let (token, end) = match bytes[i] {
+			b'\'' | b'"' => {
+				let quote = bytes[i];
+				let mut j = i + 1;
+				let end = loop {
+					match bytes.get(j) {
+						// A doubled quote character is an escaped quote within the literal
+						Some(&b) if b == quote && bytes.get(j + 1) == Some(&quote) => j += 2,
+						Some(&b) if b == quote => break j + 1,
+						Some(_) => j += 1,
+						None => break j,
+					}
+				};
+				(if quote == b'\'' { Token::Literal } else { Token::Identifier }, end)
+			}
+			b'-' if bytes.get(i + 1) == Some(&b'-') => {
+				let end = request_text[i..].find('\n').map(|n| i + n).unwrap_or(bytes.len());
+				(Token::LineComment, end)
+			}
+			b'/' if bytes.get(i + 1) == Some(&b'*') => {
+				let end = request_text[i + 2..].find("*/").map(|n| i + 2 + n + 2).unwrap_or(bytes.len());
+				(Token::BlockComment, end)
+			}
+			_ => {
+				i += 1;
+				continue;
+			}
+		};
+		close(&mut spans, Token::Code, start, i);
+		close(&mut spans, token, i, end);
+		start = end;
+		i = end;
+	}
+	close(&mut spans, Token::Code, start, bytes.len());
+
+	spans
+}
+
+// Returns the byte offset of each question-mark parameter marker in the request text,
+// ignoring question marks within character literals, quoted identifiers, and comments
+pub fn parameter_markers(request_text: &str) -> Vec<usize> {
+	tokenize(request_text).into_iter()
+		.filter(|(token, _)| *token == Token::Code)
+		.flat_map(|(_, range)| request_text[range.clone()].match_indices('?').map(move |(n, _)| range.start + n).collect::<Vec<_>>())
+		.collect()
+}
+
+pub fn count_parameters(request_text: &str) -> usize {
+	parameter_markers(request_text).len()
+}
+
+// Verifies that the number of bind values matches the number of parameter markers
+pub fn validate_parameter_count(request_text: &str, value_count: usize) -> Result<(), TeradataError> {
+	let marker_count = count_parameters(request_text);
+	if marker_count != value_count {
+		return Err(TeradataError::InvalidArgument(format!("Request text has {} parameter markers but {} bind values were provided", marker_count, value_count)));
+	}
+	Ok(())
+}
+
+// Replaces :name parameters with question-mark parameter markers, returning the translated
+// request text and the parameter names in marker order. A name used more than once appears
+// once per occurrence. Colons within character literals, quoted identifiers, and comments
+// are left unchanged.
+pub fn translate_named_parameters(request_text: &str) -> (String, Vec<String>) {
+	let mut text = String::with_capacity(request_text.len());
+	let mut names = Vec::new();
+
+	for (token, range) in tokenize(request_text) {
+		let span = &request_text[range];
+		if token != Token::Code {
+			text.push_str(span);
+			continue;
+		}
+		let mut chars = span.char_indices().peekable();
+		while let Some((n, c)) = chars.next() {
+			let starts_name = c == ':' && chars.peek().is_some_and(|&(_, next)| next.is_alphabetic() || next == '_');
+			if !starts_name {
+				text.push(c);
+				continue;
+			}
+			let mut end = span.len();
+			while let Some(&(m, next)) = chars.peek() {
+				if !(next.is_alphanumeric() || next == '_' || next == '$' || next == '#') {
+					end = m;
+					break;
+				}
+				chars.next();
+			}
+			names.push(span[n + 1..end].to_string());
+			text.push('?');
+		}
+	}
+
+	(text, names)
+}
+
+// A word of request text outside character literals, quoted identifiers, and comments, such as a
+// keyword, with the parenthesis depth at which it appears within its statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Word {
+	pub(crate) range: std::ops::Range<usize>,
+	pub(crate) depth: usize,
+}
+
+// One statement of a request, split at semicolons outside literals, quoted identifiers, and comments
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct StatementWords {
+	pub(crate) words: Vec<Word>,
+	pub(crate) text_end: usize, // byte offset just past the last character of the statement that is not whitespace or a comment
+}
+
+impl StatementWords {
+	// Returns the index of the first word at depth zero, at or after from, that matches one of
+	// the keywords, ignoring case
+	pub(crate) fn find(&self, request_text: &str, from: usize, keywords: &[&str]) -> Option<usize> {
+		(from..self.words.len()).find(|&i| self.words[i].depth == 0 && self.is(request_text, i, keywords))
+	}
+
+	pub(crate) fn is(&self, request_text: &str, index: usize, keywords: &[&str]) -> bool {
+		self.words.get(index).is_some_and(|word| keywords.iter().any(|k| request_text[word.range.clone()].eq_ignore_ascii_case(k)))
+	}
+}
+
+// Splits request text into statements and the words of each
+pub(crate) fn statement_words(request_text: &str) -> Vec<StatementWords> {
+	let mut statements = Vec::new();
+	let mut current = StatementWords::default();
+	let mut depth: usize = 0;
+
+	for (token, range) in tokenize(request_text) {
+		match token {
+			Token::Code => (),
+			Token::Literal | Token::Identifier => {
+				current.text_end = range.end;
+				continue;
+			}
+			Token::LineComment | Token::BlockComment => continue,
+		}
+		let span = &request_text[range.clone()];
+		let mut word_start: Option<usize> = None;
+		for (n, c) in span.char_indices() {
+			let offset = range.start + n;
+			let is_word_char = c.is_alphanumeric() || c == '_' || c == '$' || c == '#';
+			if let Some(start) = word_start && !is_word_char {
+				current.words.push(Word { range: start..offset, depth });
+				word_start = None;
+			}
+			match c {
+				';' => {
+					statements.push(std::mem::take(&mut current));
+					depth = 0;
+					continue;
+				}
+				'(' => depth += 1,
+				')' => depth = depth.saturating_sub(1),
+				_ if is_word_char && word_start.is_none() => word_start = Some(offset),
+				_ => (),
+			}
+			if !c.is_whitespace() {
+				current.text_end = offset + c.len_utf8();
+			}
+		}
+		if let Some(start) = word_start {
+			current.words.push(Word { range: start..range.end, depth });
+		}
+	}
+	statements.push(current);
+
+	statements
+}
@@ -0,0 +1,366 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::sync::Arc;
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::escape::{self, Escape, Rpo};
+use crate::params::{BindValues, ToParams};
+use crate::raw::{go_close_rows_wrapper, go_next_result_wrapper, rustgo_create_rows_wrapper, rustgo_fetch_row_wrapper, rustgo_result_metadata_wrapper};
+use crate::activity::ActivityType;
+use crate::result::{parse_column_info, parse_column_names, ColumnInfo, ResultMetadata};
+use crate::rows::Rows;
+use crate::sql::parameter_markers;
+use crate::value::Value;
+
+// Name of the fake result set column that holds the parameter metadata of the request
+const PARAMETER_METADATA_COLUMN: &str = "ParameterMetadata";
+
+// Description of one parameter marker of a prepared request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParameterInfo {
+	pub position: usize,     // one-based position of the parameter marker in the request
+	pub name: String,        // parameter name, which is empty for ? parameter markers
+	pub type_name: String,   // database type name inferred for the parameter, such as "INTEGER"
+	pub max_byte_count: u64,
+	pub nullable: bool,
+	pub precision: u64,
+	pub scale: u64,
+}
+
+impl ParameterInfo {
+	// Describes the parameter as a column, for decoding a value with Value::decode
+	fn as_column(&self) -> ColumnInfo {
+		let name = if self.name.is_empty() { format!("parameter {}", self.position) } else { format!("parameter {} ({})", self.position, self.name) };
+		ColumnInfo { name, type_name: self.type_name.clone(), max_byte_count: self.max_byte_count, nullable: self.nullable, precision: self.precision, scale: self.scale }
+	}
+
+	// Whether the parameter takes a BYTE, VARBYTE, or BLOB value, which is bound as a base64
+	// string and converted to bytes in the request text
+	pub fn is_binary(&self) -> bool {
+		matches!(self.type_name.trim().to_uppercase().as_str(), "BYTE" | "VARBYTE" | "BLOB")
+	}
+
+	// Checks that a bind value can be converted to the parameter's type, returning an error
+	// message that names the parameter and its type
+	pub fn check(&self, value: &serde_json::Value) -> Result<(), TeradataError> {
+		let column = self.as_column();
+		if value.is_null() {
+			if !self.nullable {
+				return Err(TeradataError::Conversion(format!("Could not convert {}: {} NOT NULL does not accept NULL", column.name, self.type_name)));
+			}
+			return Ok(());
+		}
+		// Value::decode names the column, which here is the parameter
+		Value::decode(&value.to_string(), &column).map(|_| ()).map_err(|err| TeradataError::Conversion(err.replacen("column parameter", "parameter", 1)))
+	}
+}
+
+// Returns the value of the first of the keys, which are alternative names of a metadata field
+fn metadata_field(object: &serde_json::Value, keys: &[&str]) -> Option<serde_json::Value> {
+	keys.iter().find_map(|key| object.get(*key).filter(|v| !v.is_null()).cloned())
+}
+
+// Builds the description of one parameter from a function that looks up its metadata fields
+fn parameter_info(position: usize, field: impl Fn(&[&str]) -> Option<serde_json::Value>) -> ParameterInfo {
+	ParameterInfo {
+		position,
+		name: field(&["ParameterName", "ColumnName", "Name"]).and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default(),
+		type_name: field(&["TypeName"]).and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default(),
+		max_byte_count: field(&["MaxByteCount"]).and_then(|v| v.as_u64()).unwrap_or_default(),
+		nullable: field(&["Nullable", "MayBeNull"]).and_then(|v| v.as_bool()).unwrap_or(true),
+		precision: field(&["Precision"]).and_then(|v| v.as_u64()).unwrap_or_default(),
+		scale: field(&["Scale"]).and_then(|v| v.as_u64()).unwrap_or_default(),
+	}
+}
+
+// Returns the description of each parameter from parameter metadata JSON, which is either an
+// object of arrays like column metadata, or an array of objects
+fn parse_parameter_info(parameter_metadata: &serde_json::Value) -> Result<Vec<ParameterInfo>, TeradataError> {
+	match parameter_metadata {
+		serde_json::Value::Null => Ok(Vec::new()),
+		serde_json::Value::Array(parameters) => Ok(parameters.iter().enumerate().map(|(i, parameter)| {
+			parameter_info(i + 1, |keys| metadata_field(parameter, keys))
+		}).collect()),
+		serde_json::Value::Object(_) => {
+			let count = match metadata_field(parameter_metadata, &["TypeName"]) {
+				Some(serde_json::Value::Array(type_names)) => type_names.len(),
+				Some(_) => return Err(TeradataError::Conversion("Parameter metadata TypeName is not an array".to_string())),
+				None => 0,
+			};
+			Ok((0..count).map(|i| {
+				parameter_info(i + 1, |keys| metadata_field(parameter_metadata, keys).and_then(|values| values.get(i).cloned()))
+			}).collect())
+		}
+		_ => Err(TeradataError::Conversion("Parameter metadata is not an object or an array".to_string())),
+	}
+}
+
+// Returns the description of each column from column metadata JSON, which is either an object
+// of arrays, as returned by rustgo_result_metadata_wrapper, or an array of objects
+fn parse_column_metadata(column_metadata: &serde_json::Value) -> Result<Vec<ColumnInfo>, TeradataError> {
+	match column_metadata {
+		serde_json::Value::Null => Ok(Vec::new()),
+		serde_json::Value::Object(_) => parse_column_info(&column_metadata.to_string()),
+		serde_json::Value::Array(columns) => Ok(columns.iter().map(|column| {
+			let info = parameter_info(0, |keys| metadata_field(column, keys));
+			ColumnInfo { name: info.name, type_name: info.type_name, max_byte_count: info.max_byte_count, nullable: info.nullable, precision: info.precision, scale: info.scale }
+		}).collect()),
+		_ => Err(TeradataError::Conversion("Column metadata is not an object or an array".to_string())),
+	}
+}
+
+// Returns a metadata cell of a fake result set row, which holds JSON either as a string or as a
+// JSON value
+fn metadata_json(cell: Option<&serde_json::Value>, what: &str) -> Result<serde_json::Value, TeradataError> {
+	match cell {
+		Some(serde_json::Value::String(text)) if !text.trim().is_empty() => serde_json::from_str(text).map_err(|err| TeradataError::Conversion(format!("Could not parse {} JSON: {}", what, err))),
+		Some(serde_json::Value::String(_)) | None => Ok(serde_json::Value::Null),
+		Some(metadata) => Ok(metadata.clone()),
+	}
+}
+
+// Statement metadata from the fake result set that precedes each real result when the
+// {fn teradata_fake_result_sets} escape function or the fake_result_sets connection parameter
+// is used. Fields that the driver does not report are None or empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatementInfo {
+	pub native_sql: Option<String>,     // request text after escape processing
+	pub activity_count: Option<u64>,
+	pub activity_type: Option<u16>,
+	pub activity_name: Option<String>,
+	pub columns: Vec<ColumnInfo>,       // columns of the real result that follows
+	pub parameters: Vec<ParameterInfo>, // parameter markers of the request
+}
+
+impl StatementInfo {
+	pub fn activity(&self) -> Option<ActivityType> {
+		self.activity_type.map(|code| ActivityType::new(code, self.activity_name.as_deref().unwrap_or_default()))
+	}
+
+	// Parses the row of a fake result set from its column metadata and row JSON. Returns None if
+	// the result is not a fake result set, which is recognized by its ParameterMetadata column.
+	pub fn parse(column_metadata: &str, row_json: &str) -> Result<Option<StatementInfo>, TeradataError> {
+		let names = parse_column_names(column_metadata)?;
+		if !names.iter().any(|name| name.eq_ignore_ascii_case(PARAMETER_METADATA_COLUMN)) {
+			return Ok(None);
+		}
+		let row: Vec<serde_json::Value> = match serde_json::from_str(row_json) {
+			Ok(row) => row,
+			Err(err) => {
+				return Err(TeradataError::Conversion(format!("Could not parse fake result set row JSON: {}", err)));
+			}
+		};
+		let cell = |name: &str| names.iter().position(|n| n.eq_ignore_ascii_case(name)).and_then(|index| row.get(index)).filter(|v| !v.is_null());
+		let text = |name: &str| cell(name).map(|v| v.as_str().map_or_else(|| v.to_string(), str::to_string));
+		let number = |name: &str| cell(name).and_then(|v| v.as_u64().or_else(|| v.as_str().and_then(|s| s.trim().parse().ok())));
+
+		Ok(Some(StatementInfo {
+			native_sql: text("NativeSQL"),
+			activity_count: number("ActivityCount"),
+			activity_type: number("ActivityType").and_then(|code| u16::try_from(code).ok()),
+			activity_name: text("ActivityName"),
+			columns: parse_column_metadata(&metadata_json(cell("ColumnMetadata"), "column metadata")?)?,
+			parameters: parse_parameter_info(&metadata_json(cell(PARAMETER_METADATA_COLUMN), "parameter metadata")?)?,
+		}))
+	}
+}
+
+// Prepares a request without executing it, using the {fn teradata_rpo(S)} and
+// {fn teradata_fake_result_sets} escape functions, and returns the parameter metadata of the
+// request and the metadata of each of its results
+pub(crate) fn describe_request(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<(Vec<ParameterInfo>, Vec<ResultMetadata>), TeradataError> {
+
+	let request_text = escape::apply(&[Escape::Rpo(Rpo::Prepare), Escape::FakeResultSets], request_text);
+	let rows_handle = match rustgo_create_rows_wrapper(u_log, conn_handle, &request_text, "null") {
+		Ok(handle) => handle,
+		Err(err) => {
+			return Err(TeradataError::from_driver("rustgo_create_rows_wrapper", err));
+		}
+	};
+
+	let mut parameters: Option<Vec<ParameterInfo>> = None;
+	let mut results = Vec::new();
+	let outcome = loop {
+		let (activity_type, activity_name, column_metadata) = match rustgo_result_metadata_wrapper(u_log, rows_handle) {
+			Ok((_, activity_type, activity_name, column_metadata)) => (activity_type, activity_name, column_metadata),
+			Err(err) => break Err(TeradataError::from_driver("rustgo_result_metadata_wrapper", err)),
+		};
+		let fake_row = match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+			Ok(row) => row,
+			Err(err) => break Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err)),
+		};
+		// Each real result is preceded by a fake result set with one row of statement metadata;
+		// the parameters of the request are reported with the first statement
+		match fake_row.map(|row| StatementInfo::parse(&column_metadata, &row)).transpose() {
+			Ok(Some(Some(info))) => {
+				if parameters.is_none() {
+					parameters = Some(info.parameters);
+				}
+			}
+			Ok(_) => results.push(ResultMetadata { activity_count: 0, activity_type, activity_name, column_metadata }),
+			Err(err) => break Err(err),
+		}
+		match go_next_result_wrapper(u_log, rows_handle) {
+			Ok(true) => continue,
+			Ok(false) => break Ok(()),
+			Err(err) => break Err(TeradataError::from_driver("go_next_result_wrapper", err)),
+		}
+	};
+
+	if let Err(err) = go_close_rows_wrapper(u_log, rows_handle) && outcome.is_ok() {
+		return Err(TeradataError::from_driver("go_close_rows_wrapper", err));
+	}
+
+	outcome.map(|_| (parameters.unwrap_or_default(), results))
+
+} // end describe_request
+
+// Returns the description of each parameter marker of a request, with the types the database
+// infers from the request, without executing it
+pub fn parameter_metadata(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<Vec<ParameterInfo>, TeradataError> {
+
+	describe_request(u_log, conn_handle, request_text).map(|(parameters, _)| parameters)
+
+} // end parameter_metadata
+
+// Returns the request text with each ? parameter marker of a binary parameter replaced by
+// to_bytes(?, 'base64m'). The driver binds values from JSON, which has no binary type, so byte
+// values are transmitted as base64 strings and must be converted by the database. Parameters
+// that the request already converts, such as the argument of to_bytes, are inferred as
+// character parameters and are left alone.
+fn bind_binary_parameters(request_text: &str, parameters: &[ParameterInfo]) -> String {
+	let markers = parameter_markers(request_text);
+	if markers.len() != parameters.len() || !parameters.iter().any(ParameterInfo::is_binary) {
+		return request_text.to_string();
+	}
+	let mut text = String::with_capacity(request_text.len() + 20);
+	let mut copied = 0;
+	for (offset, parameter) in markers.into_iter().zip(parameters) {
+		if parameter.is_binary() {
+			text.push_str(&request_text[copied..offset]);
+			text.push_str("to_bytes(?, 'base64m')");
+			copied = offset + 1;
+		}
+	}
+	text.push_str(&request_text[copied..]);
+	text
+}
+
+// Describes a request and returns its text with the parameter markers of binary parameters
+// converted from base64, for bind values that hold byte values
+pub(crate) fn binary_request_text(
+	u_log: u64,
+	conn_handle: u64,
+	request_text: &str,
+) -> Result<String, TeradataError> {
+
+	describe_request(u_log, conn_handle, request_text).map(|(parameters, _)| bind_binary_parameters(request_text, &parameters))
+
+} // end binary_request_text
+
+// A request prepared by Connection::prepare, with the metadata of its parameters and results,
+// that can be executed repeatedly with different bind values. The driver interface has no
+// statement handles, so every execution transmits the request text; the database recognizes
+// the identical text in its request cache and reuses the parsed plan instead of parsing the
+// request again, as long as values are bound to parameter markers rather than inlined.
+#[derive(Debug)]
+pub struct Statement<'conn> {
+	conn: &'conn Connection,
+	prepared: Arc<PreparedRequest>,
+}
+
+// The metadata of a prepared request, shared by the Statements prepared from the same request
+// text through the connection's statement cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PreparedRequest {
+	request_text: String,
+	bound_text: String, // request text that is executed, with binary parameters converted from base64
+	parameters: Vec<ParameterInfo>,
+	results: Vec<ResultMetadata>,
+}
+
+impl PreparedRequest {
+	pub(crate) fn prepare(conn: &Connection, request_text: &str) -> Result<PreparedRequest, TeradataError> {
+		let (parameters, results) = describe_request(conn.u_log(), conn.conn_handle(), request_text)?;
+		let bound_text = bind_binary_parameters(request_text, &parameters);
+		Ok(PreparedRequest { request_text: request_text.to_string(), bound_text, parameters, results })
+	}
+
+	pub(crate) fn bound_text(&self) -> &str {
+		&self.bound_text
+	}
+}
+
+impl<'conn> Statement<'conn> {
+	// Prepares a request without executing it, bypassing the connection's statement cache.
+	// Values of BYTE, VARBYTE, and BLOB parameters, such as Param::Bytes or Bytes, are bound as
+	// base64 strings, and the statement converts them to bytes when it is executed, so the
+	// request text needs no to_bytes function.
+	pub fn prepare(conn: &'conn Connection, request_text: &str) -> Result<Statement<'conn>, TeradataError> {
+		Ok(Statement::from_prepared(conn, Arc::new(PreparedRequest::prepare(conn, request_text)?)))
+	}
+
+	pub(crate) fn from_prepared(conn: &'conn Connection, prepared: Arc<PreparedRequest>) -> Statement<'conn> {
+		Statement { conn, prepared }
+	}
+
+	pub fn connection(&self) -> &'conn Connection {
+		self.conn
+	}
+
+	pub fn request_text(&self) -> &str {
+		&self.prepared.request_text
+	}
+
+	// Number of parameter markers in the request
+	pub fn parameter_count(&self) -> usize {
+		self.prepared.parameters.len()
+	}
+
+	pub fn parameters(&self) -> &[ParameterInfo] {
+		&self.prepared.parameters
+	}
+
+	// Metadata of each result of the request, with an activity count of zero
+	pub fn result_metadata(&self) -> &[ResultMetadata] {
+		&self.prepared.results
+	}
+
+	// Executes the request with a set of bind values without fetching rows, the same as
+	// Connection::execute, and returns the sum of the activity counts of its results. Binding
+	// many rows at once executes the request once per row in a single round trip.
+	pub fn execute<P: BindValues + ?Sized>(&self, bind_values: &P) -> Result<u64, TeradataError> {
+		let bind_values = bind_values.to_bind_json().map_err(TeradataError::InvalidArgument)?;
+		self.conn.execute_bound(&self.prepared.bound_text, &bind_values)
+	}
+
+	// Executes the request with a set of bind values and returns a cursor over its results, the
+	// same as Connection::query
+	pub fn query<P: BindValues + ?Sized>(&self, bind_values: &P) -> Result<Rows<'conn>, TeradataError> {
+		let bind_values = bind_values.to_bind_json().map_err(TeradataError::InvalidArgument)?;
+		Rows::open_bound(self.conn, &self.prepared.bound_text, &bind_values)
+	}
+
+	// Checks a row of bind values against the parameter metadata before execution: the number
+	// of values, NULL values for parameters that are not nullable, and values that cannot be
+	// converted to the parameter's type
+	pub fn check_params<P: ToParams + ?Sized>(&self, params: &P) -> Result<(), TeradataError> {
+		let values = params.to_params().map_err(TeradataError::InvalidArgument)?;
+		if values.len() != self.prepared.parameters.len() {
+			return Err(TeradataError::InvalidArgument(format!("The request has {} parameters, but {} bind values were supplied", self.prepared.parameters.len(), values.len())));
+		}
+		for (parameter, value) in self.prepared.parameters.iter().zip(&values) {
+			parameter.check(value)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,98 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use crate::statement::PreparedRequest;
+
+// Default number of prepared requests cached by each Connection
+pub const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatementCacheStats {
+	pub entries: usize,
+	pub capacity: usize,
+	pub hits: u64,
+	pub misses: u64,
+}
+
+// Least recently used cache of prepared requests, keyed by request text
+#[derive(Debug)]
+pub(crate) struct StatementCache {
+	capacity: usize,
+	entries: HashMap<String, Arc<PreparedRequest>>,
+	order: VecDeque<String>, // least recently used first
+	hits: u64,
+	misses: u64,
+}
+
+impl StatementCache {
+	pub(crate) fn new(capacity: usize) -> Self {
+		StatementCache { capacity, entries: HashMap::new(), order: VecDeque::new(), hits: 0, misses: 0 }
+	}
+
+	// Marks a cached request as the most recently used. The cache is small, so a linear search
+	// of the order costs less than the round trip that a hit saves.
+	fn touch(&mut self, request_text: &str) {
+		if let Some(index) = self.order.iter().position(|text| text == request_text) && let Some(text) = self.order.remove(index) {
+			self.order.push_back(text);
+		}
+	}
+
+	pub(crate) fn get(&mut self, request_text: &str) -> Option<Arc<PreparedRequest>> {
+		match self.entries.get(request_text).cloned() {
+			Some(prepared) => {
+				self.hits += 1;
+				self.touch(request_text);
+				Some(prepared)
+			}
+			None => {
+				self.misses += 1;
+				None
+			}
+		}
+	}
+
+	pub(crate) fn insert(&mut self, request_text: &str, prepared: Arc<PreparedRequest>) {
+		if self.capacity == 0 {
+			return;
+		}
+		if self.entries.insert(request_text.to_string(), prepared).is_some() {
+			self.touch(request_text);
+		} else {
+			self.order.push_back(request_text.to_string());
+		}
+		self.evict();
+	}
+
+	fn evict(&mut self) {
+		while self.entries.len() > self.capacity {
+			match self.order.pop_front() {
+				Some(oldest) => {
+					self.entries.remove(&oldest);
+				}
+				None => break,
+			}
+		}
+	}
+
+	pub(crate) fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	// Changes the capacity, evicting the least recently used entries that no longer fit; zero
+	// disables the cache
+	pub(crate) fn set_capacity(&mut self, capacity: usize) {
+		self.capacity = capacity;
+		self.evict();
+	}
+
+	pub(crate) fn clear(&mut self) {
+		self.entries.clear();
+		self.order.clear();
+	}
+
+	pub(crate) fn stats(&self) -> StatementCacheStats {
+		StatementCacheStats { entries: self.entries.len(), capacity: self.capacity, hits: self.hits, misses: self.misses }
+	}
+}
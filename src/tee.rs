@@ -0,0 +1,133 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::io::Write;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use crate::error::TeradataError;
+use crate::export::{CsvWriter, NdjsonWriter};
+use crate::row::{fetch_row, Row};
+
+// Default number of rows buffered for each sink by tee_rows
+pub const DEFAULT_TEE_BUFFER_ROWS: usize = 1024;
+
+// A destination for the rows of a result, such as an export writer or an aggregate
+pub trait RowSink: Send {
+	fn write_row(&mut self, row: &Row) -> Result<(), TeradataError>;
+
+	// Called once after the last row has been written, unless the rows were not all delivered
+	// because of an error
+	fn finish(&mut self) -> Result<(), TeradataError> {
+		Ok(())
+	}
+}
+
+impl<W: Write + Send> RowSink for NdjsonWriter<W> {
+	fn write_row(&mut self, row: &Row) -> Result<(), TeradataError> {
+		NdjsonWriter::write_row(self, row.json())
+	}
+
+	fn finish(&mut self) -> Result<(), TeradataError> {
+		self.flush()
+	}
+}
+
+impl<W: Write + Send> RowSink for CsvWriter<W> {
+	fn write_row(&mut self, row: &Row) -> Result<(), TeradataError> {
+		CsvWriter::write_row(self, row.json())
+	}
+
+	fn finish(&mut self) -> Result<(), TeradataError> {
+		self.flush()
+	}
+}
+
+// A RowSink that passes each row to a closure
+pub struct FnSink<F>(pub F);
+
+impl<F: FnMut(&Row) -> Result<(), TeradataError> + Send> RowSink for FnSink<F> {
+	fn write_row(&mut self, row: &Row) -> Result<(), TeradataError> {
+		(self.0)(row)
+	}
+}
+
+// Fetches every remaining row of the current result of rows_handle once and writes each row to
+// every sink. Each sink runs on its own thread with a buffer of buffer_rows rows; fetching waits
+// while any sink's buffer is full, so the slowest sink sets the pace and memory use is bounded.
+// If fetching or any sink fails, the sinks that have not finished stop without being finished,
+// and the first error is returned. Returns the number of rows fetched.
+pub fn tee_rows(
+	u_log: u64,
+	rows_handle: u64,
+	sinks: &mut [&mut dyn RowSink],
+	buffer_rows: usize,
+) -> Result<u64, TeradataError> {
+
+	let buffer_rows = buffer_rows.max(1);
+	let aborted = AtomicBool::new(false);
+
+	thread::scope(|scope| {
+		let mut senders = Vec::with_capacity(sinks.len());
+		let mut handles = Vec::with_capacity(sinks.len());
+		for sink in sinks.iter_mut() {
+			let (sender, receiver) = mpsc::sync_channel::<Arc<Row>>(buffer_rows);
+			senders.push(sender);
+			let aborted = &aborted;
+			handles.push(scope.spawn(move || -> Result<(), TeradataError> {
+				for row in receiver {
+					if let Err(err) = sink.write_row(&row) {
+						aborted.store(true, Ordering::SeqCst);
+						return Err(err);
+					}
+				}
+				if aborted.load(Ordering::SeqCst) {
+					return Ok(());
+				}
+				sink.finish()
+			}));
+		}
+
+		let mut row_count: u64 = 0;
+		let mut fetch_error = None;
+		'fetch: while !aborted.load(Ordering::SeqCst) {
+			match fetch_row(u_log, rows_handle) {
+				Ok(Some(row)) => {
+					let row = Arc::new(row);
+					for sender in &senders {
+						// A send fails only when the sink has stopped after an error
+						if sender.send(Arc::clone(&row)).is_err() {
+							break 'fetch;
+						}
+					}
+					row_count += 1;
+				}
+				Ok(None) => break,
+				Err(err) => {
+					aborted.store(true, Ordering::SeqCst);
+					fetch_error = Some(err);
+					break;
+				}
+			}
+		}
+		drop(senders);
+
+		let mut sink_error = None;
+		for (index, handle) in handles.into_iter().enumerate() {
+			match handle.join() {
+				Ok(Ok(())) => {}
+				Ok(Err(err)) => {
+					sink_error.get_or_insert(err.map_message(|message| format!("Row sink {} failed: {}", index, message)));
+				}
+				Err(payload) => panic::resume_unwind(payload),
+			}
+		}
+
+		match (fetch_error, sink_error) {
+			(Some(err), _) | (None, Some(err)) => Err(err),
+			(None, None) => Ok(row_count),
+		}
+	})
+
+} // end tee_rows
@@ -0,0 +1,347 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+
+// DATE value in the string format returned by the driver, for example "2025-12-25"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "diesel", derive(diesel::AsExpression, diesel::FromSqlRow), diesel(sql_type = diesel::sql_types::Date))]
+pub struct Date {
+	pub year: i32,
+	pub month: u8,
+	pub day: u8,
+}
+
+// TIME or TIME WITH TIME ZONE value in the string format returned by the driver,
+// for example "11:22:33.123456" or "11:22:33.123456+11:22"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "diesel", derive(diesel::AsExpression, diesel::FromSqlRow), diesel(sql_type = diesel::sql_types::Time))]
+pub struct Time {
+	pub hour: u8,
+	pub minute: u8,
+	pub second: u8,
+	pub microsecond: u32,
+	pub fraction_digits: u8,          // number of fractional second digits, 0 through 6
+	pub offset_minutes: Option<i16>,  // time zone offset, present only for WITH TIME ZONE values
+}
+
+// TIMESTAMP or TIMESTAMP WITH TIME ZONE value in the string format returned by the driver,
+// for example "2025-12-25 11:22:33.123456" or "2025-12-25 11:22:33.123456+11:22"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "diesel", derive(diesel::AsExpression, diesel::FromSqlRow), diesel(sql_type = diesel::sql_types::Timestamp))]
+pub struct Timestamp {
+	pub year: i32,
+	pub month: u8,
+	pub day: u8,
+	pub hour: u8,
+	pub minute: u8,
+	pub second: u8,
+	pub microsecond: u32,
+	pub fraction_digits: u8,          // number of fractional second digits, 0 through 6
+	pub offset_minutes: Option<i16>,  // time zone offset, present only for WITH TIME ZONE values
+}
+
+// PERIOD(DATE), PERIOD(TIME), or PERIOD(TIMESTAMP) value, in the string format returned by the
+// driver, for example "('2025-01-01', '2025-12-31')". The period includes begin and excludes end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Period<T> {
+	pub begin: T,
+	pub end: T,
+}
+
+fn parse_number<T: FromStr>(s: &str, what: &str, value: &str) -> Result<T, String> {
+	if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+		return Err(format!("Invalid {} in \"{}\"", what, value));
+	}
+	s.parse::<T>().map_err(|_| format!("Invalid {} in \"{}\"", what, value))
+}
+
+// Parses the "+HH:MM" or "-HH:MM" time zone suffix into minutes
+fn parse_offset(s: &str, value: &str) -> Result<i16, String> {
+	let sign: i16 = match s.as_bytes().first() {
+		Some(b'+') => 1,
+		Some(b'-') => -1,
+		_ => return Err(format!("Invalid time zone in \"{}\"", value)),
+	};
+	let (hh, mm) = match s[1..].split_once(':') {
+		Some(pair) => pair,
+		None => return Err(format!("Invalid time zone in \"{}\"", value)),
+	};
+	let hours: i16 = parse_number(hh, "time zone hour", value)?;
+	let minutes: i16 = parse_number(mm, "time zone minute", value)?;
+	if hours > 14 || minutes > 59 {
+		return Err(format!("Invalid time zone in \"{}\"", value));
+	}
+	Ok(sign * (hours * 60 + minutes))
+}
+
+fn format_offset(f: &mut fmt::Formatter<'_>, offset_minutes: i16) -> fmt::Result {
+	let sign = if offset_minutes < 0 { '-' } else { '+' };
+	let abs = offset_minutes.unsigned_abs();
+	write!(f, "{}{:02}:{:02}", sign, abs / 60, abs % 60)
+}
+
+// Parses "HH:MM:SS[.ffffff][+HH:MM]"
+fn parse_time_parts(s: &str, value: &str) -> Result<Time, String> {
+	// The time zone suffix begins with the first sign character after the seconds
+	let (clock, offset) = match s.find(['+', '-']) {
+		Some(pos) => (&s[..pos], Some(parse_offset(&s[pos..], value)?)),
+		None => (s, None),
+	};
+
+	let (hms, fraction) = match clock.split_once('.') {
+		Some((hms, fraction)) => (hms, fraction),
+		None => (clock, ""),
+	};
+
+	let mut parts = hms.split(':');
+	let (hh, mm, ss) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+		(Some(hh), Some(mm), Some(ss), None) => (hh, mm, ss),
+		_ => return Err(format!("Invalid time in \"{}\"", value)),
+	};
+	let hour: u8 = parse_number(hh, "hour", value)?;
+	let minute: u8 = parse_number(mm, "minute", value)?;
+	let second: u8 = parse_number(ss, "second", value)?;
+	if hour > 23 || minute > 59 || second > 59 {
+		return Err(format!("Invalid time in \"{}\"", value));
+	}
+
+	if fraction.len() > 6 {
+		return Err(format!("Too many fractional second digits in \"{}\"", value));
+	}
+	let microsecond: u32 = if fraction.is_empty() {
+		0
+	} else {
+		parse_number::<u32>(fraction, "fractional seconds", value)? * 10u32.pow(6 - fraction.len() as u32)
+	};
+
+	Ok(Time { hour, minute, second, microsecond, fraction_digits: fraction.len() as u8, offset_minutes: offset })
+}
+
+// Parses "YYYY-MM-DD" into (year, month, day)
+fn parse_date_parts(s: &str, value: &str) -> Result<(i32, u8, u8), String> {
+	let mut parts = s.splitn(3, '-');
+	let (yyyy, mm, dd) = match (parts.next(), parts.next(), parts.next()) {
+		(Some(yyyy), Some(mm), Some(dd)) => (yyyy, mm, dd),
+		_ => return Err(format!("Invalid date in \"{}\"", value)),
+	};
+	let year: i32 = parse_number(yyyy, "year", value)?;
+	let month: u8 = parse_number(mm, "month", value)?;
+	let day: u8 = parse_number(dd, "day", value)?;
+	if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+		return Err(format!("Invalid date in \"{}\"", value));
+	}
+	Ok((year, month, day))
+}
+
+fn format_clock(f: &mut fmt::Formatter<'_>, hour: u8, minute: u8, second: u8, microsecond: u32, fraction_digits: u8, offset_minutes: Option<i16>) -> fmt::Result {
+	write!(f, "{:02}:{:02}:{:02}", hour, minute, second)?;
+	if fraction_digits > 0 {
+		let digits = format!("{:06}", microsecond);
+		write!(f, ".{}", &digits[..fraction_digits as usize])?;
+	}
+	if let Some(offset_minutes) = offset_minutes {
+		format_offset(f, offset_minutes)?;
+	}
+	Ok(())
+}
+
+impl FromStr for Date {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (year, month, day) = parse_date_parts(s.trim(), s)?;
+		Ok(Date { year, month, day })
+	}
+}
+
+impl fmt::Display for Date {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+	}
+}
+
+impl FromStr for Time {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		parse_time_parts(s.trim(), s)
+	}
+}
+
+impl fmt::Display for Time {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		format_clock(f, self.hour, self.minute, self.second, self.microsecond, self.fraction_digits, self.offset_minutes)
+	}
+}
+
+impl FromStr for Timestamp {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (date, time) = match s.trim().split_once(' ') {
+			Some(pair) => pair,
+			None => return Err(format!("Invalid timestamp \"{}\"", s)),
+		};
+		let (year, month, day) = parse_date_parts(date, s)?;
+		let t = parse_time_parts(time, s)?;
+		Ok(Timestamp {
+			year,
+			month,
+			day,
+			hour: t.hour,
+			minute: t.minute,
+			second: t.second,
+			microsecond: t.microsecond,
+			fraction_digits: t.fraction_digits,
+			offset_minutes: t.offset_minutes,
+		})
+	}
+}
+
+// Parses "('begin', 'end')"; the quotes around the elements are optional
+impl<T: FromStr<Err = String>> FromStr for Period<T> {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let inner = match s.trim().strip_prefix('(').and_then(|rest| rest.strip_suffix(')')) {
+			Some(inner) => inner,
+			None => return Err(format!("Invalid period \"{}\"", s)),
+		};
+		let (begin, end) = match inner.split_once(',') {
+			Some(pair) => pair,
+			None => return Err(format!("Invalid period \"{}\"", s)),
+		};
+		let unquote = |element: &str| {
+			let element = element.trim();
+			element.strip_prefix('\'').and_then(|rest| rest.strip_suffix('\'')).unwrap_or(element).to_string()
+		};
+		Ok(Period { begin: unquote(begin).parse()?, end: unquote(end).parse()? })
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Period<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "('{}', '{}')", self.begin, self.end)
+	}
+}
+
+impl Timestamp {
+	pub fn date(&self) -> Date {
+		Date { year: self.year, month: self.month, day: self.day }
+	}
+
+	pub fn time(&self) -> Time {
+		Time {
+			hour: self.hour,
+			minute: self.minute,
+			second: self.second,
+			microsecond: self.microsecond,
+			fraction_digits: self.fraction_digits,
+			offset_minutes: self.offset_minutes,
+		}
+	}
+}
+
+// The TIMESTAMP at midnight of the date, without a time zone
+impl From<Date> for Timestamp {
+	fn from(date: Date) -> Self {
+		Timestamp { year: date.year, month: date.month, day: date.day, hour: 0, minute: 0, second: 0, microsecond: 0, fraction_digits: 0, offset_minutes: None }
+	}
+}
+
+impl fmt::Display for Timestamp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:04}-{:02}-{:02} ", self.year, self.month, self.day)?;
+		format_clock(f, self.hour, self.minute, self.second, self.microsecond, self.fraction_digits, self.offset_minutes)
+	}
+}
+
+// Renders date and time fields with a pattern of strftime-style specifiers:
+//   %Y  four-digit year          %y  two-digit year
+//   %m  two-digit month          %d  two-digit day
+//   %H  two-digit hour           %M  two-digit minute
+//   %S  two-digit second         %f  six-digit microseconds
+//   %z  time zone offset as +HH:MM, or nothing without a time zone
+//   %%  a literal percent sign
+// Specifiers for fields that the value does not have, such as %H for a Date, render as zeros.
+fn format_pattern(pattern: &str, date: Option<Date>, time: Option<Time>) -> String {
+	let date = date.unwrap_or(Date { year: 0, month: 0, day: 0 });
+	let time = time.unwrap_or(Time { hour: 0, minute: 0, second: 0, microsecond: 0, fraction_digits: 0, offset_minutes: None });
+	let mut out = String::with_capacity(pattern.len() + 16);
+	let mut chars = pattern.chars();
+	while let Some(c) = chars.next() {
+		if c != '%' {
+			out.push(c);
+			continue;
+		}
+		match chars.next() {
+			Some('Y') => out.push_str(&format!("{:04}", date.year)),
+			Some('y') => out.push_str(&format!("{:02}", date.year.rem_euclid(100))),
+			Some('m') => out.push_str(&format!("{:02}", date.month)),
+			Some('d') => out.push_str(&format!("{:02}", date.day)),
+			Some('H') => out.push_str(&format!("{:02}", time.hour)),
+			Some('M') => out.push_str(&format!("{:02}", time.minute)),
+			Some('S') => out.push_str(&format!("{:02}", time.second)),
+			Some('f') => out.push_str(&format!("{:06}", time.microsecond)),
+			Some('z') => {
+				if let Some(offset_minutes) = time.offset_minutes {
+					let sign = if offset_minutes < 0 { '-' } else { '+' };
+					let abs = offset_minutes.unsigned_abs();
+					out.push_str(&format!("{}{:02}:{:02}", sign, abs / 60, abs % 60));
+				}
+			}
+			Some('%') => out.push('%'),
+			Some(other) => {
+				out.push('%');
+				out.push(other);
+			}
+			None => out.push('%'),
+		}
+	}
+	out
+}
+
+impl Date {
+	// Renders the date with a pattern such as "%m/%d/%Y"; see format_pattern for the specifiers
+	pub fn format(&self, pattern: &str) -> String {
+		format_pattern(pattern, Some(*self), None)
+	}
+}
+
+impl Time {
+	// Renders the time with a pattern such as "%H%M%S"; see format_pattern for the specifiers
+	pub fn format(&self, pattern: &str) -> String {
+		format_pattern(pattern, None, Some(*self))
+	}
+}
+
+impl Timestamp {
+	// Renders the timestamp with a pattern such as "%Y-%m-%dT%H:%M:%S.%f%z"; see format_pattern
+	// for the specifiers
+	pub fn format(&self, pattern: &str) -> String {
+		format_pattern(pattern, Some(self.date()), Some(self.time()))
+	}
+}
+
+// Values serialize to the string format that the driver accepts as a bind value, so that they
+// can be bound with ToParams
+impl Serialize for Date {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl Serialize for Time {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl Serialize for Timestamp {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
@@ -0,0 +1,199 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Conversions between the temporal types of this crate and the time crate, the counterpart of the
+// chrono feature for applications that use time instead. DATE, TIME, and TIMESTAMP column values
+// are decoded into time types with FromSql, and time values can be bound as Params. Teradata time
+// zones are whole minutes, and fractional seconds have at most six digits, so nanoseconds are
+// truncated to microseconds when binding.
+
+use ::time::{Month, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::from_sql::{inexact, mismatch, Coercion, FromSql};
+use crate::temporal::{Date, Time, Timestamp};
+use crate::value::Value;
+
+fn time_date(year: i32, month: u8, day: u8) -> Result<::time::Date, String> {
+	let invalid = |_| format!("{:04}-{:02}-{:02} is not a valid date", year, month, day);
+	::time::Date::from_calendar_date(year, Month::try_from(month).map_err(invalid)?, day).map_err(invalid)
+}
+
+fn time_time(hour: u8, minute: u8, second: u8, microsecond: u32) -> Result<::time::Time, String> {
+	::time::Time::from_hms_micro(hour, minute, second, microsecond).map_err(|_| format!("{:02}:{:02}:{:02}.{:06} is not a valid time", hour, minute, second, microsecond))
+}
+
+fn utc_offset(offset_minutes: i16) -> Result<UtcOffset, String> {
+	UtcOffset::from_whole_seconds(i32::from(offset_minutes) * 60).map_err(|_| format!("{} minutes is not a valid time zone offset", offset_minutes))
+}
+
+fn time_fields(time: &::time::Time, offset_minutes: Option<i16>) -> Time {
+	let microsecond = time.microsecond();
+	Time {
+		hour: time.hour(),
+		minute: time.minute(),
+		second: time.second(),
+		microsecond,
+		fraction_digits: if microsecond == 0 { 0 } else { 6 },
+		offset_minutes,
+	}
+}
+
+fn timestamp_fields(timestamp: &PrimitiveDateTime, offset_minutes: Option<i16>) -> Timestamp {
+	let time = time_fields(&timestamp.time(), offset_minutes);
+	Timestamp {
+		year: timestamp.year(),
+		month: u8::from(timestamp.month()),
+		day: timestamp.day(),
+		hour: time.hour,
+		minute: time.minute,
+		second: time.second,
+		microsecond: time.microsecond,
+		fraction_digits: time.fraction_digits,
+		offset_minutes,
+	}
+}
+
+impl TryFrom<Date> for ::time::Date {
+	type Error = String;
+
+	fn try_from(date: Date) -> Result<Self, Self::Error> {
+		time_date(date.year, date.month, date.day)
+	}
+}
+
+impl From<::time::Date> for Date {
+	fn from(date: ::time::Date) -> Self {
+		Date { year: date.year(), month: u8::from(date.month()), day: date.day() }
+	}
+}
+
+// The clock time; the time zone of a TIME WITH TIME ZONE value is dropped
+impl TryFrom<Time> for ::time::Time {
+	type Error = String;
+
+	fn try_from(time: Time) -> Result<Self, Self::Error> {
+		time_time(time.hour, time.minute, time.second, time.microsecond)
+	}
+}
+
+impl From<::time::Time> for Time {
+	fn from(time: ::time::Time) -> Self {
+		time_fields(&time, None)
+	}
+}
+
+// The clock time and time zone of a TIME WITH TIME ZONE value
+impl TryFrom<Time> for (::time::Time, UtcOffset) {
+	type Error = String;
+
+	fn try_from(time: Time) -> Result<Self, Self::Error> {
+		match time.offset_minutes {
+			Some(offset_minutes) => Ok((::time::Time::try_from(time)?, utc_offset(offset_minutes)?)),
+			None => Err(format!("TIME value {} has no time zone; use time::Time", time)),
+		}
+	}
+}
+
+// A TIME WITH TIME ZONE value. An offset that is not a whole number of minutes is truncated.
+impl From<(::time::Time, UtcOffset)> for Time {
+	fn from((time, offset): (::time::Time, UtcOffset)) -> Self {
+		time_fields(&time, Some(offset.whole_minutes()))
+	}
+}
+
+// The local date and time; the time zone of a TIMESTAMP WITH TIME ZONE value is dropped
+impl TryFrom<Timestamp> for PrimitiveDateTime {
+	type Error = String;
+
+	fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+		let date = time_date(timestamp.year, timestamp.month, timestamp.day)?;
+		Ok(PrimitiveDateTime::new(date, time_time(timestamp.hour, timestamp.minute, timestamp.second, timestamp.microsecond)?))
+	}
+}
+
+impl From<PrimitiveDateTime> for Timestamp {
+	fn from(timestamp: PrimitiveDateTime) -> Self {
+		timestamp_fields(&timestamp, None)
+	}
+}
+
+impl TryFrom<Timestamp> for OffsetDateTime {
+	type Error = String;
+
+	fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+		let offset = match timestamp.offset_minutes {
+			Some(offset_minutes) => utc_offset(offset_minutes)?,
+			None => return Err(format!("TIMESTAMP value {} has no time zone; use PrimitiveDateTime", timestamp)),
+		};
+		Ok(PrimitiveDateTime::try_from(timestamp)?.assume_offset(offset))
+	}
+}
+
+// A TIMESTAMP WITH TIME ZONE value. A value whose offset is not a whole number of minutes is
+// bound as the same instant in UTC.
+impl From<OffsetDateTime> for Timestamp {
+	fn from(timestamp: OffsetDateTime) -> Self {
+		let offset = timestamp.offset();
+		if offset.seconds_past_minute() != 0 {
+			return Timestamp::from(timestamp.to_offset(UtcOffset::UTC));
+		}
+		timestamp_fields(&PrimitiveDateTime::new(timestamp.date(), timestamp.time()), Some(offset.whole_minutes()))
+	}
+}
+
+impl FromSql for ::time::Date {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		Date::from_sql_with(value, coercion)?.try_into()
+	}
+}
+
+// Lenient coercion drops the time zone of a TIME WITH TIME ZONE value, and strict coercion
+// returns an error for it
+impl FromSql for ::time::Time {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		match value {
+			Value::Time(time) if coercion == Coercion::Strict && time.offset_minutes.is_some() => inexact(&value, "time::Time"),
+			value => Time::from_sql_with(value, coercion)?.try_into(),
+		}
+	}
+}
+
+impl FromSql for (::time::Time, UtcOffset) {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Time(time) => time.try_into(),
+			value => mismatch(&value, "(time::Time, UtcOffset)"),
+		}
+	}
+}
+
+// Lenient coercion drops the time zone of a TIMESTAMP WITH TIME ZONE value, and strict coercion
+// returns an error for it
+impl FromSql for PrimitiveDateTime {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		Self::from_sql_with(value, Coercion::Lenient)
+	}
+
+	fn from_sql_with(value: Value, coercion: Coercion) -> Result<Self, String> {
+		match value {
+			Value::Timestamp(timestamp) if coercion == Coercion::Strict && timestamp.offset_minutes.is_some() => inexact(&value, "PrimitiveDateTime"),
+			value => Timestamp::from_sql(value)?.try_into(),
+		}
+	}
+}
+
+impl FromSql for OffsetDateTime {
+	fn from_sql(value: Value) -> Result<Self, String> {
+		match value {
+			Value::Timestamp(timestamp) => timestamp.try_into(),
+			value => mismatch(&value, "OffsetDateTime"),
+		}
+	}
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::temporal::{Date, Timestamp};
+
+// DATE or TIMESTAMP value that a temporal qualifier refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemporalPoint {
+	Date(Date),
+	Timestamp(Timestamp),
+}
+
+impl TemporalPoint {
+	// Returns the value as a SQL literal, such as DATE '2025-01-01'
+	pub fn literal(&self) -> String {
+		match self {
+			TemporalPoint::Date(date) => format!("DATE '{}'", date),
+			TemporalPoint::Timestamp(timestamp) => format!("TIMESTAMP '{}'", timestamp),
+		}
+	}
+}
+
+impl From<Date> for TemporalPoint {
+	fn from(date: Date) -> Self {
+		TemporalPoint::Date(date)
+	}
+}
+
+impl From<Timestamp> for TemporalPoint {
+	fn from(timestamp: Timestamp) -> Self {
+		TemporalPoint::Timestamp(timestamp)
+	}
+}
+
+// Qualifier for the valid-time dimension of temporal tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidTime {
+	Current,                                                      // rows valid now
+	AsOf(TemporalPoint),                                          // rows valid at a point in time
+	Sequenced,                                                    // rows valid at any time, with their periods of validity
+	SequencedDuring { begin: TemporalPoint, end: TemporalPoint }, // rows valid during a period, which includes begin and excludes end
+	NonSequenced,                                                 // every row, with the valid-time column treated as an ordinary column
+}
+
+// Qualifier for the transaction-time dimension of temporal tables. The database does not
+// support sequenced transaction-time requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionTime {
+	Current,             // rows as currently recorded
+	AsOf(TemporalPoint), // rows as recorded at a point in time, for time-travel queries
+	NonSequenced,        // every row, including closed rows, with the transaction-time column treated as an ordinary column
+}
+
+impl ValidTime {
+	fn clause(&self) -> String {
+		match self {
+			ValidTime::Current => "CURRENT VALIDTIME".to_string(),
+			ValidTime::AsOf(point) => format!("VALIDTIME AS OF {}", point.literal()),
+			ValidTime::Sequenced => "SEQUENCED VALIDTIME".to_string(),
+			ValidTime::SequencedDuring { begin, end } => format!("SEQUENCED VALIDTIME PERIOD({}, {})", begin.literal(), end.literal()),
+			ValidTime::NonSequenced => "NONSEQUENCED VALIDTIME".to_string(),
+		}
+	}
+}
+
+impl TransactionTime {
+	fn clause(&self) -> String {
+		match self {
+			TransactionTime::Current => "CURRENT TRANSACTIONTIME".to_string(),
+			TransactionTime::AsOf(point) => format!("TRANSACTIONTIME AS OF {}", point.literal()),
+			TransactionTime::NonSequenced => "NONSEQUENCED TRANSACTIONTIME".to_string(),
+		}
+	}
+}
+
+// Temporal statement modifiers for requests on temporal tables, such as
+// "VALIDTIME AS OF DATE '2025-01-01' AND CURRENT TRANSACTIONTIME". A dimension without a
+// qualifier uses the session's temporal qualifier, which is CURRENT unless changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TemporalQualifier {
+	pub valid_time: Option<ValidTime>,
+	pub transaction_time: Option<TransactionTime>,
+}
+
+impl TemporalQualifier {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn valid_time(mut self, valid_time: ValidTime) -> Self {
+		self.valid_time = Some(valid_time);
+		self
+	}
+
+	pub fn transaction_time(mut self, transaction_time: TransactionTime) -> Self {
+		self.transaction_time = Some(transaction_time);
+		self
+	}
+
+	// Returns the statement modifiers, or an empty string if neither dimension is qualified
+	pub fn prefix(&self) -> String {
+		let clauses: Vec<String> = self.valid_time.iter().map(ValidTime::clause).chain(self.transaction_time.iter().map(TransactionTime::clause)).collect();
+		clauses.join(" AND ")
+	}
+
+	// Returns the request text with the statement modifiers prepended. The modifiers apply to
+	// the first statement of a multi-statement request only.
+	pub fn apply(&self, request_text: &str) -> String {
+		let prefix = self.prefix();
+		if prefix.is_empty() {
+			request_text.to_string()
+		} else {
+			format!("{} {}", prefix, request_text.trim_start())
+		}
+	}
+}
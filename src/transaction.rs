@@ -0,0 +1,124 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::ops::Deref;
+
+use crate::connection::Connection;
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::execute_simple_request;
+use crate::session;
+
+// A transaction on a Connection, begun by Connection::begin with autocommit turned off. The
+// transaction is rolled back if the guard is dropped without commit or rollback, such as on an
+// early return or a panic, and autocommit is restored to its state before begin either way.
+// Errors from the implicit rollback on drop are ignored; call rollback() to observe them. The
+// guard dereferences to the Connection, so requests are executed through it.
+#[derive(Debug)]
+pub struct Transaction<'conn> {
+	conn: &'conn Connection,
+	active: bool,
+	autocommit: bool, // autocommit state before begin, restored when the transaction ends
+}
+
+impl<'conn> Transaction<'conn> {
+	pub(crate) fn begin(conn: &'conn Connection) -> Result<Transaction<'conn>, TeradataError> {
+		if !conn.enter_transaction() {
+			return Err(TeradataError::InvalidArgument("A transaction is already in progress on the connection".to_string()));
+		}
+		let autocommit = match conn.autocommit().and_then(|previous| conn.set_autocommit(false).map(|_| previous)) {
+			Ok(previous) => previous,
+			Err(err) => {
+				conn.leave_transaction();
+				return Err(err);
+			}
+		};
+		Ok(Transaction { conn, active: true, autocommit })
+	}
+
+	pub fn connection(&self) -> &'conn Connection {
+		self.conn
+	}
+
+	// Commits the transaction and restores autocommit. If the commit fails, the transaction is
+	// rolled back.
+	pub fn commit(mut self) -> Result<(), TeradataError> {
+		self.conn.commit()?;
+		self.finish()
+	}
+
+	// Rolls back the transaction and restores autocommit
+	pub fn rollback(mut self) -> Result<(), TeradataError> {
+		let result = self.conn.rollback();
+		let finished = self.finish();
+		result.and(finished)
+	}
+
+	fn finish(&mut self) -> Result<(), TeradataError> {
+		self.active = false;
+		self.conn.leave_transaction();
+		self.conn.set_autocommit(self.autocommit)
+	}
+}
+
+impl Deref for Transaction<'_> {
+	type Target = Connection;
+
+	fn deref(&self) -> &Connection {
+		self.conn
+	}
+}
+
+impl Drop for Transaction<'_> {
+	fn drop(&mut self) {
+		if self.active {
+			let _ = self.conn.rollback();
+			let _ = self.finish();
+		}
+	}
+}
+
+pub fn commit(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), TeradataError> {
+
+	execute_simple_request(u_log, conn_handle, &Escape::Commit.to_string())
+
+} // end commit
+
+pub fn rollback(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<(), TeradataError> {
+
+	execute_simple_request(u_log, conn_handle, &Escape::Rollback.to_string())
+
+} // end rollback
+
+pub fn set_autocommit(
+	u_log: u64,
+	conn_handle: u64,
+	b: bool,
+) -> Result<(), TeradataError> {
+
+	let escape = if b { Escape::AutocommitOn } else { Escape::AutocommitOff };
+	execute_simple_request(u_log, conn_handle, &escape.native())
+
+} // end set_autocommit
+
+// Returns whether auto-commit is on, as reported by the teradata_autocommit escape function
+pub fn autocommit(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<bool, TeradataError> {
+
+	match session::query_scalar_string(u_log, conn_handle, &Escape::Autocommit.native())? {
+		Some(s) => match s.trim() {
+			"true" => Ok(true),
+			"false" => Ok(false),
+			other => Err(TeradataError::Conversion(format!("Could not parse autocommit status {}", other))),
+		},
+		None => Err(TeradataError::Conversion("Autocommit status was NULL".to_string())),
+	}
+
+} // end autocommit
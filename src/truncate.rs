@@ -0,0 +1,82 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::columns::CharacterSet;
+
+// A string shortened to fit a character column or byte-limited field, with a report of what
+// was removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Truncated {
+	pub value: String,             // the part that fits
+	pub removed: String,           // the part that was cut off, empty if the whole string fits
+	pub length: usize,             // length of the original string, in the units of the limit
+	pub untranslatable: Vec<char>, // characters that the LATIN character set cannot store
+}
+
+impl Truncated {
+	pub fn is_truncated(&self) -> bool {
+		!self.removed.is_empty()
+	}
+
+	// Whether the value can be stored without error 6706 (untranslatable character)
+	pub fn is_translatable(&self) -> bool {
+		self.untranslatable.is_empty()
+	}
+}
+
+// Characters of Windows code page 1252 outside Latin-1, which the LATIN server character set
+// stores in the range 0x80 to 0x9F
+const LATIN_EXTRAS: &str = "€‚ƒ„…†‡ˆ‰Š‹ŒŽ‘’“”•–—˜™š›œžŸ";
+
+fn is_latin(c: char) -> bool {
+	(c as u32) <= 0xFF || LATIN_EXTRAS.contains(c)
+}
+
+// Number of length units the character occupies in a column of the character set: characters
+// for LATIN, UTF-16 code units for UNICODE and GRAPHIC, and Shift-JIS bytes for the KANJI
+// character sets
+fn length_units(c: char, character_set: CharacterSet) -> usize {
+	match character_set {
+		CharacterSet::Latin => 1,
+		CharacterSet::Unicode | CharacterSet::Graphic => c.len_utf16(),
+		CharacterSet::KanjiSjis | CharacterSet::Kanji1 => {
+			// ASCII and half-width katakana are single-byte in Shift-JIS
+			if c.is_ascii() || ('\u{FF61}'..='\u{FF9F}').contains(&c) { 1 } else { 2 }
+		}
+	}
+}
+
+// Cuts the string after the longest prefix whose length, measured by units, fits in max_units,
+// never splitting a character
+fn truncate_by(value: &str, max_units: usize, units: impl Fn(char) -> usize) -> (usize, usize) {
+	let mut length = 0;
+	let mut cut = None;
+	for (offset, c) in value.char_indices() {
+		length += units(c);
+		if length > max_units && cut.is_none() {
+			cut = Some(offset);
+		}
+	}
+	(cut.unwrap_or(value.len()), length)
+}
+
+// Truncates a string to fit a CHAR(n) or VARCHAR(n) column of the server character set, where
+// max_length is n. The length of LATIN columns counts characters, and the length of UNICODE
+// columns counts UTF-16 code units, so a character outside the Basic Multilingual Plane takes
+// two. For LATIN, characters that cannot be stored are reported in untranslatable, so a batch
+// can reject or clean the value instead of failing with error 6706.
+pub fn truncate_for_column(value: &str, max_length: usize, character_set: CharacterSet) -> Truncated {
+	let (cut, length) = truncate_by(value, max_length, |c| length_units(c, character_set));
+	let untranslatable = if character_set == CharacterSet::Latin {
+		value[..cut].chars().filter(|&c| !is_latin(c)).collect()
+	} else {
+		Vec::new()
+	};
+	Truncated { value: value[..cut].to_string(), removed: value[cut..].to_string(), length, untranslatable }
+}
+
+// Truncates a string to at most max_bytes bytes of UTF-8, such as a fixed-width field of an
+// export file written in the UTF8 client character set, never splitting a character
+pub fn truncate_utf8_bytes(value: &str, max_bytes: usize) -> Truncated {
+	let (cut, length) = truncate_by(value, max_bytes, char::len_utf8);
+	Truncated { value: value[..cut].to_string(), removed: value[cut..].to_string(), length, untranslatable: Vec::new() }
+}
@@ -0,0 +1,255 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use serde_json::Value;
+
+use crate::error::TeradataError;
+use crate::fetch_first_row;
+use crate::result::fetch_result_sets;
+use crate::sql::{quote_identifier, quote_qualified_name};
+
+// User-defined types are created in the SYSUDTLIB database
+const UDT_DATABASE: &str = "SYSUDTLIB";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UdtKind {
+	Structured,
+	Distinct,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UdtAttribute {
+	pub name: String,
+	pub data_type: String,   // data type code reported by HELP TYPE, such as "CV" or "UT"
+	pub udt: Option<UdtType>, // attribute type, when the attribute is itself a structured or distinct UDT
+}
+
+// Attribute metadata for a structured or distinct UDT. A distinct UDT has no attributes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UdtType {
+	pub name: String,
+	pub kind: UdtKind,
+	pub attributes: Vec<UdtAttribute>,
+}
+
+// Value of a structured UDT, with attribute values in attribute order
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StructValue {
+	pub type_name: String,
+	pub fields: Vec<(String, UdtValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UdtValue {
+	Null,
+	Struct(StructValue),
+	Scalar(Value), // value of a distinct UDT or of a predefined-type attribute
+}
+
+impl StructValue {
+	pub fn get(&self, name: &str) -> Option<&UdtValue> {
+		self.fields.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v)
+	}
+
+	// Converts the value to a JSON object of attribute values, with nested structured values as nested objects
+	pub fn to_json(&self) -> Value {
+		Value::Object(self.fields.iter().map(|(n, v)| (n.clone(), v.to_json())).collect())
+	}
+}
+
+impl UdtValue {
+	pub fn to_json(&self) -> Value {
+		match self {
+			UdtValue::Null => Value::Null,
+			UdtValue::Struct(s) => s.to_json(),
+			UdtValue::Scalar(v) => v.clone(),
+		}
+	}
+}
+
+fn qualified_type_name(name: &str) -> String {
+	format!("{}.{}", UDT_DATABASE, quote_identifier(name))
+}
+
+impl UdtType {
+	// Number of predefined-type values that make up one value of this type
+	pub fn leaf_count(&self) -> usize {
+		match self.kind {
+			UdtKind::Distinct => 1,
+			UdtKind::Structured => self.attributes.iter().map(|a| a.udt.as_ref().map_or(1, |t| t.leaf_count())).sum(),
+		}
+	}
+
+	// Returns select-list expressions that retrieve the value of the column as predefined-type
+	// values, using observer methods for structured UDT attributes. Use decode() to assemble
+	// the retrieved values.
+	pub fn select_expressions(&self, column: &str) -> Vec<String> {
+		match self.kind {
+			// A distinct UDT value is returned as its base type by the default transform
+			UdtKind::Distinct => vec![column.to_string()],
+			UdtKind::Structured => self.attributes.iter().flat_map(|a| {
+				let observer = format!("{}.{}()", column, quote_identifier(&a.name));
+				match &a.udt {
+					Some(t) => t.select_expressions(&observer),
+					None => vec![observer],
+				}
+			}).collect(),
+		}
+	}
+
+	// Assembles the values retrieved by select_expressions into a UDT value. A structured
+	// value whose attributes are all NULL is returned as UdtValue::Null.
+	pub fn decode(&self, values: &[Value]) -> Result<UdtValue, TeradataError> {
+		if values.len() != self.leaf_count() {
+			return Err(TeradataError::InvalidArgument(format!("Type {} requires {} values, but {} were provided", self.name, self.leaf_count(), values.len())));
+		}
+		Ok(self.decode_from(&mut values.iter()))
+	}
+
+	fn decode_from<'a, I: Iterator<Item = &'a Value>>(&self, values: &mut I) -> UdtValue {
+		match self.kind {
+			UdtKind::Distinct => match values.next() {
+				Some(Value::Null) | None => UdtValue::Null,
+				Some(v) => UdtValue::Scalar(v.clone()),
+			},
+			UdtKind::Structured => {
+				let fields: Vec<(String, UdtValue)> = self.attributes.iter().map(|a| {
+					let value = match &a.udt {
+						Some(t) => t.decode_from(values),
+						None => match values.next() {
+							Some(Value::Null) | None => UdtValue::Null,
+							Some(v) => UdtValue::Scalar(v.clone()),
+						},
+					};
+					(a.name.clone(), value)
+				}).collect();
+				if fields.iter().all(|(_, v)| *v == UdtValue::Null) {
+					UdtValue::Null
+				} else {
+					UdtValue::Struct(StructValue { type_name: self.name.clone(), fields })
+				}
+			}
+		}
+	}
+
+	// Returns an expression that constructs a value of this type from question-mark parameter
+	// markers, such as NEW SYSUDTLIB."address"()."street"(?)."zip"(?). Bind the values
+	// returned by bind_values() to the markers.
+	pub fn constructor_expression(&self) -> String {
+		match self.kind {
+			UdtKind::Distinct => format!("cast(? as {})", qualified_type_name(&self.name)),
+			UdtKind::Structured => {
+				let mut expr = format!("new {}()", qualified_type_name(&self.name));
+				for a in &self.attributes {
+					let arg = match &a.udt {
+						Some(t) => t.constructor_expression(),
+						None => "?".to_string(),
+					};
+					expr.push_str(&format!(".{}({})", quote_identifier(&a.name), arg));
+				}
+				expr
+			}
+		}
+	}
+
+	// Flattens a UDT value into bind values for the markers of constructor_expression()
+	pub fn bind_values(&self, value: &UdtValue) -> Result<Vec<Value>, TeradataError> {
+		let mut values = Vec::with_capacity(self.leaf_count());
+		self.bind_values_into(value, &mut values)?;
+		Ok(values)
+	}
+
+	fn bind_values_into(&self, value: &UdtValue, values: &mut Vec<Value>) -> Result<(), TeradataError> {
+		match (self.kind, value) {
+			(UdtKind::Distinct, UdtValue::Scalar(v)) => values.push(v.clone()),
+			(UdtKind::Distinct, UdtValue::Null) => values.push(Value::Null),
+			(UdtKind::Structured, UdtValue::Struct(s)) => {
+				for a in &self.attributes {
+					let field = s.get(&a.name).unwrap_or(&UdtValue::Null);
+					match &a.udt {
+						Some(t) => t.bind_values_into(field, values)?,
+						None => values.push(field.to_json()),
+					}
+				}
+			}
+			(UdtKind::Structured, UdtValue::Null) => {
+				values.extend(std::iter::repeat_n(Value::Null, self.leaf_count()));
+			}
+			_ => {
+				return Err(TeradataError::InvalidArgument(format!("Value does not match the kind of type {}", self.name)));
+			}
+		}
+		Ok(())
+	}
+}
+
+fn column_str(row: &[Value], index: Option<usize>) -> Option<String> {
+	match index.and_then(|i| row.get(i)) {
+		Some(Value::String(s)) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+		_ => None,
+	}
+}
+
+// Returns the kind and attribute metadata of a structured or distinct UDT. Attributes that
+// are themselves UDTs are described recursively.
+pub fn describe_udt(
+	u_log: u64,
+	conn_handle: u64,
+	type_name: &str,
+) -> Result<UdtType, TeradataError> {
+
+	let kind = match fetch_first_row(u_log, conn_handle, "select TypeKind from DBC.UDTInfo where TypeName = ?", &Value::Array(vec![Value::Array(vec![Value::String(type_name.to_string())])]).to_string())? {
+		Some(row) => match row.first().and_then(|v| v.as_str()).map(|s| s.trim()) {
+			Some("S") => UdtKind::Structured,
+			Some("D") => UdtKind::Distinct,
+			Some(other) => {
+				return Err(TeradataError::Conversion(format!("Type {} has unsupported type kind {}", type_name, other)));
+			}
+			None => {
+				return Err(TeradataError::Conversion(format!("Type {} has no type kind", type_name)));
+			}
+		},
+		None => {
+			return Err(TeradataError::InvalidArgument(format!("Type {} does not exist", type_name)));
+		}
+	};
+
+	let mut udt = UdtType { name: type_name.to_string(), kind, attributes: Vec::new() };
+	if kind == UdtKind::Distinct {
+		return Ok(udt);
+	}
+
+	let request_text = format!("help type {} attribute", quote_qualified_name(&format!("{}.{}", UDT_DATABASE, type_name)));
+	for result in fetch_result_sets(u_log, conn_handle, &request_text, "null")? {
+		let name_index = match result.column_index("Attribute Name")? {
+			Some(index) => index,
+			None => continue,
+		};
+		let type_index = result.column_index("Type")?;
+		let udt_name_index = result.column_index("UDT Name")?;
+		for row in &result.rows {
+			let row: Vec<Value> = match serde_json::from_str(row) {
+				Ok(row) => row,
+				Err(err) => {
+					return Err(TeradataError::Conversion(format!("Could not parse HELP TYPE row JSON: {}", err)));
+				}
+			};
+			let name = match column_str(&row, Some(name_index)) {
+				Some(name) => name,
+				None => continue,
+			};
+			let data_type = column_str(&row, type_index).unwrap_or_default();
+			let attribute_udt = match column_str(&row, udt_name_index) {
+				Some(udt_name) => Some(describe_udt(u_log, conn_handle, &udt_name)?),
+				None => None,
+			};
+			udt.attributes.push(UdtAttribute { name, data_type, udt: attribute_udt });
+		}
+	}
+
+	if udt.attributes.is_empty() {
+		return Err(TeradataError::Conversion(format!("HELP TYPE returned no attributes for structured type {}", type_name)));
+	}
+
+	Ok(udt)
+
+} // end describe_udt
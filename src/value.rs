@@ -0,0 +1,184 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use crate::error::TeradataError;
+use crate::raw::rustgo_fetch_row_wrapper;
+use crate::result::ColumnInfo;
+use crate::row::Row;
+use crate::temporal::{Date, Time, Timestamp};
+use crate::udt::StructValue;
+
+// A column value decoded according to the column's database type
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+	Null,
+	Integer(i64),          // BYTEINT, SMALLINT, INTEGER, BIGINT
+	Float(f64),            // FLOAT, REAL, DOUBLE PRECISION
+	Decimal(String),       // DECIMAL and NUMBER, with the digits exactly as returned by the driver
+	Varchar(String),       // CHAR, VARCHAR, CLOB, JSON, XML, INTERVAL, PERIOD, and other types returned as strings
+	Bytes(Vec<u8>),        // BYTE, VARBYTE, BLOB
+	Date(Date),
+	Time(Time),            // TIME and TIME WITH TIME ZONE
+	Timestamp(Timestamp),  // TIMESTAMP and TIMESTAMP WITH TIME ZONE
+	Struct(StructValue),   // structured UDT value assembled by UdtType::decode; never produced by Value::decode
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// Encodes bytes as standard base64 with padding
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+	let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+	for chunk in bytes.chunks(3) {
+		let n = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8 | *chunk.get(2).unwrap_or(&0) as u32;
+		for i in 0..4 {
+			if i <= chunk.len() {
+				text.push(BASE64_ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+			} else {
+				text.push('=');
+			}
+		}
+	}
+	text
+}
+
+// Decodes standard base64, which the driver uses for BYTE, VARBYTE, and BLOB values
+pub(crate) fn decode_base64(s: &str) -> Option<Vec<u8>> {
+	let mut bytes = Vec::with_capacity(s.len() * 3 / 4);
+	let mut buffer: u32 = 0;
+	let mut bits = 0;
+	for c in s.trim_end_matches('=').bytes() {
+		let sextet = match c {
+			b'A'..=b'Z' => c - b'A',
+			b'a'..=b'z' => c - b'a' + 26,
+			b'0'..=b'9' => c - b'0' + 52,
+			b'+' => 62,
+			b'/' => 63,
+			_ => return None,
+		};
+		buffer = (buffer << 6) | sextet as u32;
+		bits += 6;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+		}
+	}
+	Some(bytes)
+}
+
+impl Value {
+	// Decodes a column value from its JSON text in a fetched row, such as Row::column_json,
+	// using the column's type name. Types without a dedicated variant are decoded by their
+	// JSON type.
+	pub fn decode(json: &str, column: &ColumnInfo) -> Result<Value, String> {
+		let json = json.trim();
+		if json == "null" {
+			return Ok(Value::Null);
+		}
+		let text = match json.strip_prefix('"') {
+			Some(_) => match serde_json::from_str::<String>(json) {
+				Ok(s) => s,
+				Err(err) => {
+					return Err(format!("Could not parse column {} JSON: {}", column.name, err));
+				}
+			},
+			None => json.to_string(),
+		};
+		let invalid = |what: &str, err: String| format!("Could not convert column {} value {} to {}: {}", column.name, text, what, err);
+
+		let type_name = column.type_name.trim().to_uppercase();
+		let value = match type_name.as_str() {
+			"BYTEINT" | "SMALLINT" | "INTEGER" | "BIGINT" => Value::Integer(text.trim().parse().map_err(|err: std::num::ParseIntError| invalid("an integer", err.to_string()))?),
+			"FLOAT" | "REAL" | "DOUBLE PRECISION" => Value::Float(text.trim().parse().map_err(|err: std::num::ParseFloatError| invalid("a float", err.to_string()))?),
+			"DECIMAL" | "NUMBER" => Value::Decimal(text.trim().to_string()),
+			"BYTE" | "VARBYTE" | "BLOB" => match decode_base64(text.trim()) {
+				Some(bytes) => Value::Bytes(bytes),
+				None => {
+					return Err(invalid("bytes", "invalid base64".to_string()));
+				}
+			},
+			"DATE" => Value::Date(text.parse().map_err(|err| invalid("a date", err))?),
+			"TIME" | "TIME WITH TIME ZONE" => Value::Time(text.parse().map_err(|err| invalid("a time", err))?),
+			"TIMESTAMP" | "TIMESTAMP WITH TIME ZONE" => Value::Timestamp(text.parse().map_err(|err| invalid("a timestamp", err))?),
+			_ if json.starts_with('"') => Value::Varchar(text),
+			_ => match (text.parse::<i64>(), text.parse::<f64>()) {
+				(Ok(n), _) => Value::Integer(n),
+				(_, Ok(f)) => Value::Float(f),
+				_ => Value::Varchar(text),
+			},
+		};
+		Ok(value)
+	}
+
+	pub fn is_null(&self) -> bool {
+		matches!(self, Value::Null)
+	}
+
+	pub fn as_i64(&self) -> Option<i64> {
+		match self {
+			Value::Integer(n) => Some(*n),
+			_ => None,
+		}
+	}
+
+	pub fn as_f64(&self) -> Option<f64> {
+		match self {
+			Value::Integer(n) => Some(*n as f64),
+			Value::Float(f) => Some(*f),
+			_ => None,
+		}
+	}
+
+	// Returns the text of a Varchar or Decimal value
+	pub fn as_str(&self) -> Option<&str> {
+		match self {
+			Value::Varchar(s) | Value::Decimal(s) => Some(s),
+			_ => None,
+		}
+	}
+
+	pub fn as_bytes(&self) -> Option<&[u8]> {
+		match self {
+			Value::Bytes(bytes) => Some(bytes),
+			_ => None,
+		}
+	}
+}
+
+impl From<StructValue> for Value {
+	fn from(value: StructValue) -> Self {
+		Value::Struct(value)
+	}
+}
+
+impl Row {
+	// Decodes a single column value using its column description
+	pub fn get_value(&self, index: usize, column: &ColumnInfo) -> Result<Value, String> {
+		match self.column_json(index) {
+			Some(json) => Value::decode(json, column),
+			None => Err(format!("Column index {} is out of range for a row with {} columns", index, self.len())),
+		}
+	}
+
+	// Decodes every column value using the column descriptions of the result, such as from
+	// ResultMetadata::columns
+	pub fn typed_values(&self, columns: &[ColumnInfo]) -> Result<Vec<Value>, String> {
+		if columns.len() != self.len() {
+			return Err(format!("Row has {} columns but {} column descriptions were supplied", self.len(), columns.len()));
+		}
+		columns.iter().enumerate().map(|(index, column)| self.get_value(index, column)).collect()
+	}
+}
+
+// Fetches the next row and decodes every column value, or returns None when there are no more rows
+pub fn fetch_typed_row(
+	u_log: u64,
+	rows_handle: u64,
+	columns: &[ColumnInfo],
+) -> Result<Option<Vec<Value>>, TeradataError> {
+
+	match rustgo_fetch_row_wrapper(u_log, rows_handle) {
+		Ok(Some(json)) => Row::from_json(json)?.typed_values(columns).map(Some).map_err(TeradataError::Conversion),
+		Ok(None) => Ok(None),
+		Err(err) => Err(TeradataError::from_driver("rustgo_fetch_row_wrapper", err)),
+	}
+
+} // end fetch_typed_row
@@ -0,0 +1,68 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::error::TeradataError;
+use crate::escape::Escape;
+use crate::session::query_scalar_string;
+
+static LIBRARY_EXTENSION: OnceLock<String> = OnceLock::new();
+static LIBRARY_PATH: OnceLock<PathBuf> = OnceLock::new();
+static DRIVER_VERSION: OnceLock<String> = OnceLock::new();
+
+// Records the driver library loaded by load_driver
+pub(crate) fn set_loaded_library(path: PathBuf, extension: &str) {
+	let _ = LIBRARY_PATH.set(path);
+	let _ = LIBRARY_EXTENSION.set(extension.to_string());
+}
+
+// Version information for application diagnostics
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionInfo {
+	pub crate_version: &'static str,
+	pub library_path: Option<PathBuf>,  // None until load_driver succeeds
+	pub library_flavor: Option<String>, // such as "standard", "fips", "arm", or "arm.fips"; None until load_driver succeeds
+	pub driver_version: Option<String>, // GoSQL driver version; None until driver_version has been called
+}
+
+impl VersionInfo {
+	pub fn library_path(&self) -> Option<&Path> {
+		self.library_path.as_deref()
+	}
+}
+
+// Returns the library flavor encoded in a library file name extension
+fn flavor(extension: &str) -> String {
+	let parts: Vec<&str> = extension.split('.').collect();
+	match parts.split_last() {
+		Some((_, flavor)) if !flavor.is_empty() => flavor.join("."),
+		_ => "standard".to_string(),
+	}
+}
+
+// Returns the crate version, the loaded driver library and its flavor, and the GoSQL driver
+// version if it has been obtained from a connection
+pub fn version() -> VersionInfo {
+	VersionInfo {
+		crate_version: env!("CARGO_PKG_VERSION"),
+		library_path: LIBRARY_PATH.get().cloned(),
+		library_flavor: LIBRARY_EXTENSION.get().map(|extension| flavor(extension)),
+		driver_version: DRIVER_VERSION.get().cloned(),
+	}
+} // end version
+
+// Returns the GoSQL driver version, and remembers it for subsequent calls to version()
+pub fn driver_version(
+	u_log: u64,
+	conn_handle: u64,
+) -> Result<String, TeradataError> {
+
+	if let Some(version) = DRIVER_VERSION.get() {
+		return Ok(version.clone());
+	}
+
+	let version = query_scalar_string(u_log, conn_handle, &Escape::DriverVersion.native())?.unwrap_or_default();
+	Ok(DRIVER_VERSION.get_or_init(|| version).clone())
+
+} // end driver_version
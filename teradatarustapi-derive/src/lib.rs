@@ -0,0 +1,118 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+// Derive macros re-exported by the teradatarustapi crate with its derive cargo feature
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index, LitStr};
+
+// Implements teradatarustapi::FromRow for a struct with named fields. Each field is decoded from
+// the column with the same name, matched ignoring case, or from the column named by
+// #[teradata(rename = "...")], and converted with teradatarustapi::FromSql using the coercion
+// mode passed to FromRow::from_row_with.
+#[proc_macro_derive(FromRow, attributes(teradata))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+
+	let input = parse_macro_input!(input as DeriveInput);
+	match from_row_impl(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+
+} // end derive_from_row
+
+// Implements teradatarustapi::ToParams for a struct, binding its fields in declaration order.
+// Each field type must implement serde::Serialize.
+#[proc_macro_derive(ToParams)]
+pub fn derive_to_params(input: TokenStream) -> TokenStream {
+
+	let input = parse_macro_input!(input as DeriveInput);
+	match to_params_impl(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+
+} // end derive_to_params
+
+// Returns the column name from a #[teradata(rename = "...")] attribute, if present
+fn renamed_column(field: &syn::Field) -> syn::Result<Option<String>> {
+	let mut rename = None;
+	for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("teradata")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("rename") {
+				rename = Some(meta.value()?.parse::<LitStr>()?.value());
+				Ok(())
+			} else {
+				Err(meta.error("unsupported teradata attribute; expected rename = \"...\""))
+			}
+		})?;
+	}
+	Ok(rename)
+}
+
+fn from_row_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(fields) => &fields.named,
+			_ => return Err(syn::Error::new_spanned(&input.ident, "FromRow can only be derived for a struct with named fields")),
+		},
+		_ => return Err(syn::Error::new_spanned(&input.ident, "FromRow can only be derived for a struct")),
+	};
+
+	let mut initializers = Vec::new();
+	for field in fields {
+		let ident = field.ident.as_ref().unwrap();
+		let column = match renamed_column(field)? {
+			Some(name) => name,
+			None => ident.to_string().trim_start_matches("r#").to_string(),
+		};
+		initializers.push(quote! {
+			#ident: ::teradatarustapi::column_by_name_with(row, columns, #column, coercion)?
+		});
+	}
+
+	let name = &input.ident;
+	let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::teradatarustapi::FromRow for #name #type_generics #where_clause {
+			fn from_row(row: &::teradatarustapi::Row, columns: &[::teradatarustapi::ColumnInfo]) -> ::std::result::Result<Self, ::std::string::String> {
+				Self::from_row_with(row, columns, ::teradatarustapi::Coercion::Lenient)
+			}
+
+			fn from_row_with(row: &::teradatarustapi::Row, columns: &[::teradatarustapi::ColumnInfo], coercion: ::teradatarustapi::Coercion) -> ::std::result::Result<Self, ::std::string::String> {
+				::std::result::Result::Ok(#name {
+					#(#initializers,)*
+				})
+			}
+		}
+	})
+}
+
+fn to_params_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+	let accessors: Vec<proc_macro2::TokenStream> = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(fields) => fields.named.iter().map(|field| {
+				let ident = field.ident.as_ref().unwrap();
+				quote! { self.#ident }
+			}).collect(),
+			Fields::Unnamed(fields) => (0..fields.unnamed.len()).map(|i| {
+				let index = Index::from(i);
+				quote! { self.#index }
+			}).collect(),
+			Fields::Unit => return Err(syn::Error::new_spanned(&input.ident, "ToParams cannot be derived for a struct without fields")),
+		},
+		_ => return Err(syn::Error::new_spanned(&input.ident, "ToParams can only be derived for a struct")),
+	};
+
+	let name = &input.ident;
+	let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::teradatarustapi::ToParams for #name #type_generics #where_clause {
+			fn to_params(&self) -> ::std::result::Result<::std::vec::Vec<::teradatarustapi::JsonValue>, ::std::string::String> {
+				::std::result::Result::Ok(::std::vec![
+					#(::teradatarustapi::param_value(&#accessors)?,)*
+				])
+			}
+		}
+	})
+}
@@ -0,0 +1,67 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use teradatarustapi::{combine_connect_params, ConnectParams};
+
+#[test]
+fn combines_and_replaces() {
+	assert_eq!(
+		combine_connect_params(r#"{"host":"h1","user":"u"}"#, r#"{"host":"h2","password":"p"}"#),
+		Ok(r#"{"host":"h2","user":"u","password":"p"}"#.to_string()),
+	);
+	assert_eq!(combine_connect_params("{}", "{}"), Ok("{}".to_string()));
+}
+
+#[test]
+fn escapes_values() {
+	let combined = combine_connect_params(r#"{"password":"a\"b\\c"}"#, r#"{"account":"x\ny"}"#).unwrap();
+	assert_eq!(combined, r#"{"password":"a\"b\\c","account":"x\ny"}"#);
+	let params = ConnectParams::from_json(&combined).unwrap();
+	assert_eq!(params.get("password"), Some("a\"b\\c"));
+	assert_eq!(params.get("account"), Some("x\ny"));
+}
+
+#[test]
+fn rejects_invalid_json() {
+	let err = combine_connect_params(r#"{"host":"h1""#, "{}").unwrap_err();
+	assert!(err.starts_with("Invalid connection parameters JSON: "), "{}", err);
+	assert!(combine_connect_params("{}", r#"["host"]"#).unwrap_err().starts_with("Invalid connection parameters JSON: "));
+}
+
+#[test]
+fn rejects_duplicate_names() {
+	let err = combine_connect_params(r#"{"host":"h1","host":"h2"}"#, "{}").unwrap_err();
+	assert!(err.contains(r#"duplicate connection parameter "host""#), "{}", err);
+}
+
+#[test]
+fn rejects_values_that_are_not_strings() {
+	assert_eq!(
+		combine_connect_params("{}", r#"{"dbs_port":1025}"#),
+		Err(r#"Connection parameter "dbs_port" must be a JSON string, such as "1025", not a JSON number"#.to_string()),
+	);
+	assert_eq!(
+		combine_connect_params(r#"{"cop":false}"#, "{}"),
+		Err(r#"Connection parameter "cop" must be a JSON string, such as "false", not a JSON boolean"#.to_string()),
+	);
+}
+
+#[test]
+fn rejects_type_mismatches() {
+	assert_eq!(
+		combine_connect_params(r#"{"logon_timeout":"soon"}"#, "{}"),
+		Err(r#"Connection parameter "logon_timeout" must be a quoted integer, not "soon""#.to_string()),
+	);
+	assert_eq!(
+		combine_connect_params("{}", r#"{"encryptdata":"yes"}"#),
+		Err(r#"Connection parameter "encryptdata" must be a quoted boolean, "true" or "false", not "yes""#.to_string()),
+	);
+	assert!(combine_connect_params(r#"{"dbs_port":" 1025 ","encryptdata":"TRUE"}"#, "{}").is_ok());
+}
+
+#[test]
+fn builder_round_trips() {
+	let params = ConnectParams::new().host("h1").user("u").set("tmode", "ANSI");
+	assert_eq!(ConnectParams::from_json(&params.to_json()), Ok(params.clone()));
+	assert_eq!(params.clone().remove("tmode").get("tmode"), None);
+	assert!(ConnectParams::from_json(r#"{"sessions":"two"}"#).is_err());
+}
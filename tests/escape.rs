@@ -0,0 +1,102 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use teradatarustapi::escape::{self, Escape, LobSelect, Rpo};
+use teradatarustapi::LobMode;
+
+// Every escape function with the text the driver expects for it
+fn escapes() -> Vec<(Escape, &'static str)> {
+	vec![
+		(Escape::NativeSql, "{fn teradata_nativesql}"),
+		(Escape::Autocommit, "{fn teradata_autocommit}"),
+		(Escape::AutocommitOn, "{fn teradata_autocommit_on}"),
+		(Escape::AutocommitOff, "{fn teradata_autocommit_off}"),
+		(Escape::Commit, "{fn teradata_commit}"),
+		(Escape::Rollback, "{fn teradata_rollback}"),
+		(Escape::AmpCount, "{fn teradata_amp_count}"),
+		(Escape::DatabaseVersion, "{fn teradata_database_version}"),
+		(Escape::DriverVersion, "{fn teradata_driver_version}"),
+		(Escape::SessionNumber, "{fn teradata_session_number}"),
+		(Escape::GetWarnings, "{fn teradata_get_warnings}"),
+		(Escape::GetErrors, "{fn teradata_get_errors}"),
+		(Escape::GetLogLevel, "{fn teradata_getloglevel}"),
+		(Escape::SetLogLevel(7), "{fn teradata_setloglevel(7)}"),
+		(Escape::Provide("session_charset_name".to_string()), "{fn teradata_provide(session_charset_name)}"),
+		(Escape::FakeResultSets, "{fn teradata_fake_result_sets}"),
+		(Escape::FakeResultSetsOff, "{fn teradata_fake_result_sets_off}"),
+		(Escape::LobSelect(LobSelect::Inline), "{fn teradata_lobselect(I)}"),
+		(Escape::LobSelect(LobSelect::SpoolLocator), "{fn teradata_lobselect(S)}"),
+		(Escape::LobSelect(LobSelect::TransactionLocator), "{fn teradata_lobselect(T)}"),
+		(Escape::RequestTimeout(30), "{fn teradata_request_timeout(30)}"),
+		(Escape::Rpo(Rpo::Prepare), "{fn teradata_rpo(S)}"),
+		(Escape::Rpo(Rpo::Execute), "{fn teradata_rpo(E)}"),
+		(Escape::Rpo(Rpo::Both), "{fn teradata_rpo(B)}"),
+		(Escape::TryFastLoad, "{fn teradata_try_fastload}"),
+		(Escape::RequireFastLoad, "{fn teradata_require_fastload}"),
+		(Escape::TryFastExport, "{fn teradata_try_fastexport}"),
+		(Escape::RequireFastExport, "{fn teradata_require_fastexport}"),
+		(Escape::Sessions(4), "{fn teradata_sessions(4)}"),
+	]
+}
+
+#[test]
+fn renders_driver_syntax() {
+	for (escape, text) in escapes() {
+		assert_eq!(escape.to_string(), text);
+	}
+}
+
+#[test]
+fn round_trips() {
+	for (escape, text) in escapes() {
+		assert_eq!(text.parse::<Escape>(), Ok(escape.clone()), "{}", text);
+		assert_eq!(escape.to_string().parse::<Escape>(), Ok(escape));
+	}
+}
+
+#[test]
+fn parses_ignoring_case_and_spaces() {
+	assert_eq!("{FN Teradata_RPO( s )}".parse::<Escape>(), Ok(Escape::Rpo(Rpo::Prepare)));
+	assert_eq!(" { fn  teradata_lobselect(t) } ".parse::<Escape>(), Ok(Escape::LobSelect(LobSelect::TransactionLocator)));
+}
+
+#[test]
+fn rejects_invalid_escapes() {
+	for text in [
+		"teradata_nativesql",
+		"{teradata_nativesql}",
+		"{fn left('abc',1)}",
+		"{fn teradata_unknown}",
+		"{fn teradata_rpo}",
+		"{fn teradata_rpo(X)}",
+		"{fn teradata_commit(1)}",
+		"{fn teradata_request_timeout(-1)}",
+		"{fn teradata_provide()}",
+	] {
+		assert!(text.parse::<Escape>().is_err(), "{}", text);
+	}
+}
+
+#[test]
+fn native_and_apply() {
+	assert_eq!(Escape::DriverVersion.native(), "{fn teradata_nativesql}{fn teradata_driver_version}");
+	assert_eq!(Escape::Rpo(Rpo::Prepare).apply("select 1"), "{fn teradata_rpo(S)}select 1");
+	assert_eq!(escape::apply(&[Escape::Rpo(Rpo::Prepare), Escape::FakeResultSets], "select 1"), "{fn teradata_rpo(S)}{fn teradata_fake_result_sets}select 1");
+}
+
+#[test]
+fn split_round_trips() {
+	let all: Vec<Escape> = escapes().into_iter().map(|(escape, _)| escape).collect();
+	let request_text = escape::apply(&all, " select {fn left('abc',1)}");
+	let (split, rest) = escape::split_escapes(&request_text).unwrap();
+	assert_eq!(split, all);
+	assert_eq!(rest, " select {fn left('abc',1)}");
+
+	assert_eq!(escape::split_escapes("{fn left('abc',1)}"), Ok((Vec::new(), "{fn left('abc',1)}")));
+	assert!(escape::split_escapes("{fn teradata_rpo(X)}select 1").is_err());
+}
+
+#[test]
+fn lob_modes() {
+	assert_eq!(Escape::from(LobMode::SpoolLocator).to_string(), LobMode::SpoolLocator.escape());
+	assert_eq!(Escape::from(LobMode::Disabled).to_string(), LobMode::Disabled.escape());
+}
@@ -0,0 +1,79 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use teradatarustapi::{Confidence, ExplainPlan};
+
+const PLAN: &str = "  1) First, we lock DB1.t1 in TD_MAP1 for read on a reserved RowHash to
+     prevent global deadlock.
+  2) Next, we do an all-AMPs RETRIEVE step in TD_MAP1 from DB1.t1 by way of
+     an all-rows scan with no residual conditions into Spool 1
+     (group_amps), which is built locally on the AMPs.  The size of Spool 1
+     is estimated with high confidence to be 1,234 rows (56,789 bytes).
+     The estimated time for this step is 0.03 seconds.
+  3) We execute the following steps in parallel.
+       1) We do an all-AMPs RETRIEVE step from DB1.t2 by way of an
+          all-rows scan into Spool 2, which is estimated with no
+          confidence to be 10 rows.  The estimated time for this step is
+          1:02.50 seconds.
+       2) We do an all-AMPs RETRIEVE step from DB1.t3 into Spool 3, which
+          is estimated with index join confidence to be 5 rows.
+  4) Finally, we send out an END TRANSACTION step to all AMPs involved in
+     processing the request.
+  -> The contents of Spool 1 are sent back to the user as the result of
+     statement 1.  The total estimated time is 1:02.53 seconds.
+";
+
+#[test]
+fn parses_steps() {
+	let plan = ExplainPlan::parse(PLAN);
+	let numbers: Vec<&str> = plan.steps.iter().map(|step| step.number.as_str()).collect();
+	assert_eq!(numbers, vec!["1", "2", "3", "4"]);
+	assert_eq!(plan.steps[0].text, "First, we lock DB1.t1 in TD_MAP1 for read on a reserved RowHash to prevent global deadlock.");
+	assert_eq!(plan.text, PLAN);
+}
+
+#[test]
+fn parses_estimates() {
+	let plan = ExplainPlan::parse(PLAN);
+	let step = &plan.steps[1];
+	assert_eq!(step.confidence, Some(Confidence::High));
+	assert_eq!(step.estimated_rows, Some(1234));
+	assert_eq!(step.estimated_bytes, Some(56789));
+	assert_eq!(step.estimated_seconds, Some(0.03));
+	assert_eq!(step.spools, vec![1]);
+	assert_eq!(plan.steps[0].estimated_rows, None);
+	assert_eq!(plan.steps[0].confidence, None);
+	assert_eq!(plan.total_estimated_seconds, Some(62.53));
+}
+
+#[test]
+fn parses_parallel_steps() {
+	let plan = ExplainPlan::parse(PLAN);
+	let children = &plan.steps[2].children;
+	assert_eq!(children.len(), 2);
+	assert_eq!(children[0].number, "3.1");
+	assert_eq!(children[0].confidence, Some(Confidence::No));
+	assert_eq!(children[0].estimated_rows, Some(10));
+	assert_eq!(children[0].estimated_bytes, None);
+	assert_eq!(children[0].estimated_seconds, Some(62.5));
+	assert_eq!(children[0].spools, vec![2]);
+	assert_eq!(children[1].number, "3.2");
+	assert_eq!(children[1].confidence, Some(Confidence::IndexJoin));
+	assert!(plan.steps[3].text.starts_with("Finally,"));
+	assert!(plan.steps[3].children.is_empty());
+}
+
+#[test]
+fn flattens_in_plan_order() {
+	let plan = ExplainPlan::parse(PLAN);
+	let numbers: Vec<&str> = plan.flatten().iter().map(|step| step.number.as_str()).collect();
+	assert_eq!(numbers, vec!["1", "2", "3", "3.1", "3.2", "4"]);
+	assert_eq!(plan.max_estimated_rows(), Some(1234));
+}
+
+#[test]
+fn parses_text_without_steps() {
+	let plan = ExplainPlan::parse("");
+	assert!(plan.steps.is_empty());
+	assert_eq!(plan.total_estimated_seconds, None);
+	assert_eq!(plan.max_estimated_rows(), None);
+}
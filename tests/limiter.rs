@@ -0,0 +1,31 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use std::time::Duration;
+
+use teradatarustapi::WorkloadLimiter;
+
+#[test]
+fn rejects_a_limit_of_zero() {
+	assert!(WorkloadLimiter::new().limit("reports", 0).is_err());
+	let limiter = WorkloadLimiter::new().limit("reports", 2).unwrap();
+	assert!(limiter.set_limit("reports", Some(0)).is_err());
+	assert_eq!(limiter.stats("reports").limit, Some(2));
+}
+
+#[test]
+fn times_out_when_every_permit_is_taken() {
+	let limiter = WorkloadLimiter::new().limit("reports", 1).unwrap();
+	let permit = limiter.acquire("reports", Duration::ZERO).unwrap();
+	let err = limiter.acquire("reports", Duration::from_millis(10)).unwrap_err();
+	assert!(err.is_timeout());
+	assert_eq!(limiter.stats("reports").waiting, 0);
+	drop(permit);
+	assert!(limiter.try_acquire("reports").is_some());
+}
+
+#[test]
+fn does_not_limit_tags_without_a_limit() {
+	let limiter = WorkloadLimiter::new().limit("reports", 1).unwrap();
+	let _permits: Vec<_> = (0..3).map(|_| limiter.try_acquire("oltp").unwrap()).collect();
+	assert_eq!(limiter.stats("oltp").in_flight, 3);
+}
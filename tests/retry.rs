@@ -0,0 +1,26 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use teradatarustapi::RetryPolicy;
+
+#[test]
+fn retries_listed_error_codes() {
+	let policy = RetryPolicy::default();
+	assert!(policy.is_retryable("[Teradata Database] [Error 2631] Transaction ABORTed due to deadlock."));
+	assert!(!policy.is_retryable("[Teradata Database] [Error 3807] Object 'x' does not exist."));
+}
+
+#[test]
+fn does_not_retry_connection_failures_by_default() {
+	let policy = RetryPolicy::default();
+	assert!(!policy.retry_connection_failures);
+	assert!(!policy.is_retryable("Error from rustgo_create_rows_wrapper: read tcp 10.0.0.1:50000->10.0.0.2:1025: connection reset by peer"));
+}
+
+#[test]
+fn retries_only_transport_errors_without_a_code() {
+	let policy = RetryPolicy { retry_connection_failures: true, ..RetryPolicy::default() };
+	assert!(policy.is_retryable("Error from rustgo_create_rows_wrapper: read tcp 10.0.0.1:50000->10.0.0.2:1025: connection reset by peer"));
+	assert!(policy.is_retryable("Error from rustgo_create_rows_wrapper: write: broken pipe"));
+	assert!(!policy.is_retryable("Could not parse bind values"));
+	assert!(!policy.is_retryable("Request text has 2 parameter markers but 1 bind values were provided"));
+}
@@ -0,0 +1,71 @@
+// Copyright 2025 by Teradata Corporation. All Rights Reserved.
+
+use teradatarustapi::{count_parameters, parameter_markers, translate_named_parameters, validate_parameter_count, TeradataError};
+
+#[test]
+fn finds_markers_in_code() {
+	assert_eq!(parameter_markers("select * from t where a = ? and b = ?"), vec![26, 36]);
+	assert_eq!(parameter_markers("insert into t values (?,?,?)"), vec![22, 24, 26]);
+	assert_eq!(count_parameters("select 1"), 0);
+}
+
+#[test]
+fn ignores_markers_in_literals() {
+	assert_eq!(count_parameters("select '?' from t where a = ?"), 1);
+	assert_eq!(count_parameters("select 'it''s ?' , ? from t"), 1);
+	assert_eq!(count_parameters("select '''?''' , ?"), 1);
+	assert_eq!(count_parameters("select '' , ?"), 1);
+}
+
+#[test]
+fn ignores_markers_in_quoted_identifiers() {
+	assert_eq!(count_parameters("select \"a?\" from t where b = ?"), 1);
+	assert_eq!(count_parameters("select \"a\"\"?\" , ?"), 1);
+}
+
+#[test]
+fn ignores_markers_in_comments() {
+	assert_eq!(parameter_markers("select ? -- where a = ?\n, ?"), vec![7, 26]);
+	assert_eq!(count_parameters("select /* ? */ ? /* ?? */"), 1);
+	assert_eq!(count_parameters("select ? -- ?"), 1);
+	assert_eq!(count_parameters("select 1 - ? / ?"), 2);
+}
+
+#[test]
+fn unterminated_spans_extend_to_the_end() {
+	assert_eq!(count_parameters("select ? , 'abc ?"), 1);
+	assert_eq!(count_parameters("select ? , \"abc ?"), 1);
+	assert_eq!(count_parameters("select ? /* ?"), 1);
+	assert_eq!(count_parameters("select 'it''s ?"), 0);
+}
+
+#[test]
+fn validates_parameter_count() {
+	assert!(validate_parameter_count("select ?, '?'", 1).is_ok());
+	assert_eq!(
+		validate_parameter_count("select ?, ?", 1),
+		Err(TeradataError::InvalidArgument("Request text has 2 parameter markers but 1 bind values were provided".to_string())),
+	);
+}
+
+#[test]
+fn translates_named_parameters() {
+	assert_eq!(
+		translate_named_parameters("select * from t where a = :a and b = :b_2 or c = :a"),
+		("select * from t where a = ? and b = ? or c = ?".to_string(), vec!["a".to_string(), "b_2".to_string(), "a".to_string()]),
+	);
+	assert_eq!(
+		translate_named_parameters("select :x$1, :y#"),
+		("select ?, ?".to_string(), vec!["x$1".to_string(), "y#".to_string()]),
+	);
+}
+
+#[test]
+fn leaves_colons_outside_code_and_names() {
+	let request_text = "select ':a', \":b\" -- :c\n/* :d */ from t where ts = time '10:20:30' and x = ::1 and y = :1";
+	assert_eq!(translate_named_parameters(request_text), (request_text.to_string(), Vec::new()));
+	assert_eq!(
+		translate_named_parameters("select ':a' , :b"),
+		("select ':a' , ?".to_string(), vec!["b".to_string()]),
+	);
+}